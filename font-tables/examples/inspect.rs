@@ -42,8 +42,7 @@ fn print_font_info(font: &FontRef) {
     }
     if let Some(maxp) = font.maxp() {
         print_maxp_info(&maxp);
-        let long_loca = head.index_to_loc_format() == 1;
-        if let Some(loca) = font.loca(maxp.num_glyphs(), long_loca) {
+        if let Some(loca) = font.loca() {
             let glyf = font.glyf().expect("missing glyf table");
             let mut simple_glyphs = 0;
             let mut composite_glyphs = 0;
@@ -108,6 +107,10 @@ fn print_head_info(head: &tables::head::Head) {
     println!("  upm {}", head.units_per_em);
     println!("  x/y min: {}, {}", head.x_min, head.y_min);
     println!("  x/y max: {}, {}", head.x_max, head.y_max);
+    println!("  created: {}", head.created());
+    println!("  modified: {}", head.modified());
+    println!("  flags: {:?}", head.flags());
+    println!("  mac style: {:?}", head.mac_style());
 }
 
 fn print_hhea_info(hhea: &tables::hhea::Hhea) {
@@ -192,11 +195,7 @@ fn print_gdef_info(gdef: &tables::gdef::Gdef) {
         gdef.minor_version()
     );
     if let Some(class_def) = gdef.glyph_class_def() {
-        let format = match class_def {
-            ClassDef::Format1(_) => 1,
-            ClassDef::Format2(_) => 2,
-        };
-        println!("   ClassDef format {}", format);
+        print_class_def("ClassDef", &class_def);
     }
 
     if let Some(attach_list) = gdef.attach_list() {
@@ -211,11 +210,7 @@ fn print_gdef_info(gdef: &tables::gdef::Gdef) {
     }
 
     if let Some(class_def) = gdef.mark_attach_class_def() {
-        let format = match class_def {
-            ClassDef::Format1(_) => 1,
-            ClassDef::Format2(_) => 2,
-        };
-        println!("   MarkAttach ClassDef format {}", format);
+        print_class_def("MarkAttach ClassDef", &class_def);
     }
 
     if let Some(glyph_sets) = gdef.mark_glyph_sets_def() {
@@ -225,3 +220,18 @@ fn print_gdef_info(gdef: &tables::gdef::Gdef) {
         );
     }
 }
+
+fn print_class_def(name: &str, class_def: &ClassDef) {
+    let format = match class_def {
+        ClassDef::Format1(_) => 1,
+        ClassDef::Format2(_) => 2,
+    };
+    println!("   {} format {}", name, format);
+    let mut counts = std::collections::BTreeMap::<u16, usize>::new();
+    for (_, class) in class_def.iter() {
+        *counts.entry(class).or_default() += 1;
+    }
+    for (class, count) in counts {
+        println!("    class {}: {} glyphs", class, count);
+    }
+}
@@ -0,0 +1,122 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [avar (Axis Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/avar) table
+pub struct Avar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    #[allow(dead_code)]
+    reserved: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axis_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Avar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (reserved, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (axis_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Avar {
+            major_version,
+            minor_version,
+            reserved,
+            axis_count,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Avar<'a> {
+    /// Major version number of the axis variations table — set to 1
+    /// or 2.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the axis variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Avar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [SegmentMaps](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+pub struct SegmentMaps<'a> {
+    position_map_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axis_value_maps: zerocopy::LayoutVerified<&'a [u8], [AxisValueMap]>,
+}
+
+impl<'a> font_types::FontRead<'a> for SegmentMaps<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (position_map_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_position_map_count = position_map_count.get();
+        let (axis_value_maps, bytes) =
+            zerocopy::LayoutVerified::<_, [AxisValueMap]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_position_map_count as usize,
+            )?;
+        let _ = bytes;
+        Some(SegmentMaps {
+            position_map_count,
+            axis_value_maps,
+        })
+    }
+}
+
+impl<'a> SegmentMaps<'a> {
+    /// The number of correspondence pairs for this axis.
+    pub fn position_map_count(&self) -> u16 {
+        self.position_map_count.get()
+    }
+
+    /// The array of axis value map records for this axis.
+    pub fn axis_value_maps(&self) -> &[AxisValueMap] {
+        &self.axis_value_maps
+    }
+}
+
+/// [AxisValueMap](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct AxisValueMap {
+    /// A normalized coordinate value obtained using default
+    /// normalization.
+    pub from_coordinate: BigEndian<F2Dot14>,
+    /// The modified, normalized coordinate value.
+    pub to_coordinate: BigEndian<F2Dot14>,
+}
+
+impl AxisValueMap {
+    /// A normalized coordinate value obtained using default
+    /// normalization.
+    pub fn from_coordinate(&self) -> F2Dot14 {
+        self.from_coordinate.get()
+    }
+
+    /// The modified, normalized coordinate value.
+    pub fn to_coordinate(&self) -> F2Dot14 {
+        self.to_coordinate.get()
+    }
+}
@@ -0,0 +1,152 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [CFF (Compact Font Format)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff) table header
+pub struct Cff<'a> {
+    major: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    minor: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    hdr_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    off_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Cff<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (minor, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (hdr_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (off_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Cff {
+            major,
+            minor,
+            hdr_size,
+            off_size,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Cff<'a> {
+    /// Format major version (starting at 1)
+    pub fn major(&self) -> u8 {
+        self.major.get()
+    }
+
+    /// Format minor version (starting at 0)
+    pub fn minor(&self) -> u8 {
+        self.minor.get()
+    }
+
+    /// Header size (bytes)
+    pub fn hdr_size(&self) -> u8 {
+        self.hdr_size.get()
+    }
+
+    /// Absolute offset (0) size
+    pub fn off_size(&self) -> u8 {
+        self.off_size.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Cff<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// A range in a format 1 charset
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct Charset1Range {
+    /// First glyph in range
+    pub first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first)
+    pub n_left: BigEndian<u8>,
+}
+
+impl Charset1Range {
+    /// First glyph in range
+    pub fn first(&self) -> u16 {
+        self.first.get()
+    }
+
+    /// Glyphs left in range (excluding first)
+    pub fn n_left(&self) -> u8 {
+        self.n_left.get()
+    }
+}
+
+/// A range in a format 2 charset
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct Charset2Range {
+    /// First glyph in range
+    pub first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first)
+    pub n_left: BigEndian<u16>,
+}
+
+impl Charset2Range {
+    /// First glyph in range
+    pub fn first(&self) -> u16 {
+        self.first.get()
+    }
+
+    /// Glyphs left in range (excluding first)
+    pub fn n_left(&self) -> u16 {
+        self.n_left.get()
+    }
+}
+
+/// A range in a format 1 encoding
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct EncodingRange {
+    /// First code in range
+    pub first: BigEndian<u8>,
+    /// Codes left in range (excluding first)
+    pub n_left: BigEndian<u8>,
+}
+
+impl EncodingRange {
+    /// First code in range
+    pub fn first(&self) -> u8 {
+        self.first.get()
+    }
+
+    /// Codes left in range (excluding first)
+    pub fn n_left(&self) -> u8 {
+        self.n_left.get()
+    }
+}
+
+/// An additional code for a glyph, in an encoding with supplements
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct EncodingSupplement {
+    /// Encoding
+    pub code: BigEndian<u8>,
+    /// Name
+    pub glyph: BigEndian<u16>,
+}
+
+impl EncodingSupplement {
+    /// Encoding
+    pub fn code(&self) -> u8 {
+        self.code.get()
+    }
+
+    /// Name
+    pub fn glyph(&self) -> u16 {
+        self.glyph.get()
+    }
+}
@@ -0,0 +1,64 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [CFF2 (Compact Font Format Version 2)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2) table header
+pub struct Cff2<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    header_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    top_dict_length: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Cff2<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (header_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (top_dict_length, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Cff2 {
+            major_version,
+            minor_version,
+            header_size,
+            top_dict_length,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Cff2<'a> {
+    /// Format major version (set to 2)
+    pub fn major_version(&self) -> u8 {
+        self.major_version.get()
+    }
+
+    /// Format minor version (set to 0)
+    pub fn minor_version(&self) -> u8 {
+        self.minor_version.get()
+    }
+
+    /// Header size (bytes)
+    pub fn header_size(&self) -> u8 {
+        self.header_size.get()
+    }
+
+    /// Length of Top DICT structure in bytes
+    pub fn top_dict_length(&self) -> u16 {
+        self.top_dict_length.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Cff2<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
@@ -0,0 +1,166 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [fvar (Font Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar) table
+pub struct Fvar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axes_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    #[allow(dead_code)]
+    reserved: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axis_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axis_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    instance_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    instance_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Fvar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (axes_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (reserved, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (axis_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (axis_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (instance_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (instance_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Fvar {
+            major_version,
+            minor_version,
+            axes_array_offset,
+            reserved,
+            axis_count,
+            axis_size,
+            instance_count,
+            instance_size,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Fvar<'a> {
+    /// Major version number of the font variations table — set to 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the font variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset in bytes from the beginning of the table to the start of
+    /// the VariationAxisRecord array.
+    pub fn axes_array_offset(&self) -> Offset16 {
+        self.axes_array_offset.get()
+    }
+
+    /// The number of variation axes in the font (the number of records
+    /// in the axes array).
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count.get()
+    }
+
+    /// The size in bytes of each VariationAxisRecord — set to 20
+    /// (0x0014) for this version.
+    pub fn axis_size(&self) -> u16 {
+        self.axis_size.get()
+    }
+
+    /// The number of named instances defined in the font (the number
+    /// of records in the instances array).
+    pub fn instance_count(&self) -> u16 {
+        self.instance_count.get()
+    }
+
+    /// The size in bytes of each InstanceRecord — set to either
+    /// axisCount * sizeof(Fixed) + 4, or to axisCount * sizeof(Fixed)
+    /// + 6.
+    pub fn instance_size(&self) -> u16 {
+        self.instance_size.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Fvar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [VariationAxisRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#variationaxisrecord)
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct VariationAxisRecord {
+    /// Tag identifying the design variation for the axis.
+    pub axis_tag: BigEndian<Tag>,
+    /// The minimum coordinate value for the axis.
+    pub min_value: BigEndian<Fixed>,
+    /// The default coordinate value for the axis.
+    pub default_value: BigEndian<Fixed>,
+    /// The maximum coordinate value for the axis.
+    pub max_value: BigEndian<Fixed>,
+    /// Axis qualifiers — see details below.
+    pub flags: BigEndian<VariationAxisFlags>,
+    /// The name ID for entries in the 'name' table that provide a
+    /// display name for this axis.
+    pub axis_name_id: BigEndian<u16>,
+}
+
+impl VariationAxisRecord {
+    /// Tag identifying the design variation for the axis.
+    pub fn axis_tag(&self) -> Tag {
+        self.axis_tag.get()
+    }
+
+    /// The minimum coordinate value for the axis.
+    pub fn min_value(&self) -> Fixed {
+        self.min_value.get()
+    }
+
+    /// The default coordinate value for the axis.
+    pub fn default_value(&self) -> Fixed {
+        self.default_value.get()
+    }
+
+    /// The maximum coordinate value for the axis.
+    pub fn max_value(&self) -> Fixed {
+        self.max_value.get()
+    }
+
+    /// Axis qualifiers — see details below.
+    pub fn flags(&self) -> VariationAxisFlags {
+        self.flags.get()
+    }
+
+    /// The name ID for entries in the 'name' table that provide a
+    /// display name for this axis.
+    pub fn axis_name_id(&self) -> u16 {
+        self.axis_name_id.get()
+    }
+}
+
+bitflags::bitflags! { # [doc = " Flags for a [VariationAxisRecord]"] pub struct VariationAxisFlags : u16 { # [doc = " The axis should not be exposed directly in user interfaces."] const HIDDEN_AXIS = 0x0001 ; } }
+impl font_types::Scalar for VariationAxisFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
@@ -0,0 +1,1537 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// [GPOS](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#gpos-header) 1.0
+pub struct Gpos1_0<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    script_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    lookup_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Gpos1_0<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (script_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (lookup_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Gpos1_0 {
+            major_version,
+            minor_version,
+            script_list_offset,
+            feature_list_offset,
+            lookup_list_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Gpos1_0<'a> {
+    /// Major version of the GPOS table, = 1
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version of the GPOS table, = 0
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset to ScriptList table, from beginning of GPOS table
+    pub fn script_list_offset(&self) -> Offset16 {
+        self.script_list_offset.get()
+    }
+
+    /// Offset to FeatureList table, from beginning of GPOS table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        self.feature_list_offset.get()
+    }
+
+    /// Offset to LookupList table, from beginning of GPOS table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        self.lookup_list_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gpos1_0<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [GPOS](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#gpos-header) 1.1
+pub struct Gpos1_1<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    script_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    lookup_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_variations_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Gpos1_1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (script_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (lookup_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_variations_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Gpos1_1 {
+            major_version,
+            minor_version,
+            script_list_offset,
+            feature_list_offset,
+            lookup_list_offset,
+            feature_variations_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Gpos1_1<'a> {
+    /// Major version of the GPOS table, = 1
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version of the GPOS table, = 1
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset to ScriptList table, from beginning of GPOS table
+    pub fn script_list_offset(&self) -> Offset16 {
+        self.script_list_offset.get()
+    }
+
+    /// Offset to FeatureList table, from beginning of GPOS table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        self.feature_list_offset.get()
+    }
+
+    /// Offset to LookupList table, from beginning of GPOS table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        self.lookup_list_offset.get()
+    }
+
+    /// Offset to FeatureVariations table, from beginning of GPOS table
+    /// (may be NULL)
+    pub fn feature_variations_offset(&self) -> Offset32 {
+        self.feature_variations_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gpos1_1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+pub enum Gpos<'a> {
+    Gpos1_0(Gpos1_0<'a>),
+    Gpos1_1(Gpos1_1<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for Gpos<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        const _: MajorMinor = MajorMinor::VERSION_1_0;
+        const _: MajorMinor = MajorMinor::VERSION_1_1;
+        let version: BigEndian<MajorMinor> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            MajorMinor::VERSION_1_0 => Some(Self::Gpos1_0(font_types::FontRead::read(bytes)?)),
+            MajorMinor::VERSION_1_1 => Some(Self::Gpos1_1(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<'a> Gpos<'a> {
+    /// Offset to FeatureList table, from beginning of GPOS table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.feature_list_offset(),
+            Self::Gpos1_1(_inner) => _inner.feature_list_offset(),
+        }
+    }
+
+    /// Offset to FeatureVariations table, from beginning of GPOS table
+    /// (may be NULL)
+    pub fn feature_variations_offset(&self) -> Option<Offset32> {
+        match self {
+            Self::Gpos1_0(_inner) => None,
+            Self::Gpos1_1(_inner) => Some(_inner.feature_variations_offset()),
+        }
+    }
+
+    /// Offset to LookupList table, from beginning of GPOS table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.lookup_list_offset(),
+            Self::Gpos1_1(_inner) => _inner.lookup_list_offset(),
+        }
+    }
+
+    /// Major version of the GPOS table, = 1
+    pub fn major_version(&self) -> u16 {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.major_version(),
+            Self::Gpos1_1(_inner) => _inner.major_version(),
+        }
+    }
+
+    /// Minor version of the GPOS table, = 0
+    pub fn minor_version(&self) -> u16 {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.minor_version(),
+            Self::Gpos1_1(_inner) => _inner.minor_version(),
+        }
+    }
+
+    /// Offset to ScriptList table, from beginning of GPOS table
+    pub fn script_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.script_list_offset(),
+            Self::Gpos1_1(_inner) => _inner.script_list_offset(),
+        }
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gpos<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Gpos1_0(_inner) => _inner.bytes(),
+            Self::Gpos1_1(_inner) => _inner.bytes(),
+        }
+    }
+}
+
+bitflags::bitflags! { # [doc = " See [ValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#value-record)"] pub struct ValueFormat : u16 { # [doc = " Includes horizontal adjustment for placement"] const X_PLACEMENT = 0x0001 ; # [doc = " Includes vertical adjustment for placement"] const Y_PLACEMENT = 0x0002 ; # [doc = " Includes horizontal adjustment for advance"] const X_ADVANCE = 0x0004 ; # [doc = " Includes vertical adjustment for advance"] const Y_ADVANCE = 0x0008 ; # [doc = " Includes Device table (non-variable font) / VariationIndex"] # [doc = " table (variable font) for horizontal placement"] const X_PLACEMENT_DEVICE = 0x0010 ; # [doc = " Includes Device table (non-variable font) / VariationIndex"] # [doc = " table (variable font) for vertical placement"] const Y_PLACEMENT_DEVICE = 0x0020 ; # [doc = " Includes Device table (non-variable font) / VariationIndex"] # [doc = " table (variable font) for horizontal advance"] const X_ADVANCE_DEVICE = 0x0040 ; # [doc = " Includes Device table (non-variable font) / VariationIndex"] # [doc = " table (variable font) for vertical advance"] const Y_ADVANCE_DEVICE = 0x0080 ; } }
+impl font_types::Scalar for ValueFormat {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+/// [Anchor Table Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-1-design-units): Design Units
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct AnchorFormat1 {
+    /// Format identifier, = 1
+    pub anchor_format: BigEndian<u16>,
+    /// Horizontal value, in design units
+    pub x_coordinate: BigEndian<i16>,
+    /// Vertical value, in design units
+    pub y_coordinate: BigEndian<i16>,
+}
+
+impl AnchorFormat1 {
+    /// Format identifier, = 1
+    pub fn anchor_format(&self) -> u16 {
+        self.anchor_format.get()
+    }
+
+    /// Horizontal value, in design units
+    pub fn x_coordinate(&self) -> i16 {
+        self.x_coordinate.get()
+    }
+
+    /// Vertical value, in design units
+    pub fn y_coordinate(&self) -> i16 {
+        self.y_coordinate.get()
+    }
+}
+
+/// [Anchor Table Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-2-design-units-plus-contour-point): Design Units Plus Contour Point
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct AnchorFormat2 {
+    /// Format identifier, = 2
+    pub anchor_format: BigEndian<u16>,
+    /// Horizontal value, in design units
+    pub x_coordinate: BigEndian<i16>,
+    /// Vertical value, in design units
+    pub y_coordinate: BigEndian<i16>,
+    /// Index to glyph contour point
+    pub anchor_point: BigEndian<u16>,
+}
+
+impl AnchorFormat2 {
+    /// Format identifier, = 2
+    pub fn anchor_format(&self) -> u16 {
+        self.anchor_format.get()
+    }
+
+    /// Horizontal value, in design units
+    pub fn x_coordinate(&self) -> i16 {
+        self.x_coordinate.get()
+    }
+
+    /// Vertical value, in design units
+    pub fn y_coordinate(&self) -> i16 {
+        self.y_coordinate.get()
+    }
+
+    /// Index to glyph contour point
+    pub fn anchor_point(&self) -> u16 {
+        self.anchor_point.get()
+    }
+}
+
+/// [Anchor Table Format 3](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-3-design-units-plus-device-or-variationindex-tables): Design Units Plus Device or VariationIndex Tables
+pub struct AnchorFormat3<'a> {
+    anchor_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    x_coordinate: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_coordinate: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    x_device_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    y_device_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for AnchorFormat3<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (anchor_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (x_coordinate, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_coordinate, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (x_device_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_device_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(AnchorFormat3 {
+            anchor_format,
+            x_coordinate,
+            y_coordinate,
+            x_device_offset,
+            y_device_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> AnchorFormat3<'a> {
+    /// Format identifier, = 3
+    pub fn anchor_format(&self) -> u16 {
+        self.anchor_format.get()
+    }
+
+    /// Horizontal value, in design units
+    pub fn x_coordinate(&self) -> i16 {
+        self.x_coordinate.get()
+    }
+
+    /// Vertical value, in design units
+    pub fn y_coordinate(&self) -> i16 {
+        self.y_coordinate.get()
+    }
+
+    /// Offset to Device table (non-variable font) / VariationIndex
+    /// table (variable font) for X coordinate, from beginning of
+    /// Anchor table (may be NULL)
+    pub fn x_device_offset(&self) -> Offset16 {
+        self.x_device_offset.get()
+    }
+
+    /// Offset to Device table (non-variable font) / VariationIndex
+    /// table (variable font) for Y coordinate, from beginning of
+    /// Anchor table (may be NULL)
+    pub fn y_device_offset(&self) -> Offset16 {
+        self.y_device_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for AnchorFormat3<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Anchor Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-tables)
+pub enum AnchorTable<'a> {
+    Format1(AnchorFormat1),
+    Format2(AnchorFormat2),
+    Format3(AnchorFormat3<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for AnchorTable<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            3 => Some(Self::Format3(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// [Mark Array Table](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-array-table)
+pub struct MarkArray<'a> {
+    mark_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark_records: zerocopy::LayoutVerified<&'a [u8], [MarkRecord]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for MarkArray<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (mark_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_mark_count = mark_count.get();
+        let (mark_records, bytes) =
+            zerocopy::LayoutVerified::<_, [MarkRecord]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_mark_count as usize,
+            )?;
+        let _ = bytes;
+        Some(MarkArray {
+            mark_count,
+            mark_records,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> MarkArray<'a> {
+    /// Number of MarkRecords
+    pub fn mark_count(&self) -> u16 {
+        self.mark_count.get()
+    }
+
+    /// Array of MarkRecords, ordered by corresponding glyphs in the
+    /// associated mark Coverage table.
+    pub fn mark_records(&self) -> &[MarkRecord] {
+        &self.mark_records
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for MarkArray<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MarkArray]
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct MarkRecord {
+    /// Class defined for the associated mark.
+    pub mark_class: BigEndian<u16>,
+    /// Offset to Anchor table, from beginning of MarkArray table.
+    pub mark_anchor_offset: BigEndian<Offset16>,
+}
+
+impl MarkRecord {
+    /// Class defined for the associated mark.
+    pub fn mark_class(&self) -> u16 {
+        self.mark_class.get()
+    }
+
+    /// Offset to Anchor table, from beginning of MarkArray table.
+    pub fn mark_anchor_offset(&self) -> Offset16 {
+        self.mark_anchor_offset.get()
+    }
+}
+
+/// [Single Adjustment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#single-adjustment-positioning-format-1-single-positioning-value): Single Positioning Value
+pub struct SinglePosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    value_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    value_record_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for SinglePosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let __resolved_value_format = value_format.get();
+        let (value_record_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                value_record_len(__resolved_value_format),
+            )?;
+        let _ = bytes;
+        Some(SinglePosFormat1 {
+            pos_format,
+            coverage_offset,
+            value_format,
+            value_record_data,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> SinglePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of SinglePos subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Defines the types of data in the ValueRecord.
+    pub fn value_format(&self) -> ValueFormat {
+        self.value_format.get()
+    }
+
+    /// Defines positioning value(s) — applied to all glyphs in the
+    /// Coverage table.
+    pub fn value_record_data(&self) -> &[u8] {
+        &self.value_record_data
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for SinglePosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Single Adjustment Positioning Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#single-adjustment-positioning-format-2-array-of-positioning-values): Array of Positioning Values
+pub struct SinglePosFormat2<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    value_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    value_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    value_records_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for SinglePosFormat2<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let __resolved_value_format = value_format.get();
+        let (value_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_value_count = value_count.get();
+        let (value_records_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                value_records_len(__resolved_value_format, __resolved_value_count),
+            )?;
+        let _ = bytes;
+        Some(SinglePosFormat2 {
+            pos_format,
+            coverage_offset,
+            value_format,
+            value_count,
+            value_records_data,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> SinglePosFormat2<'a> {
+    /// Format identifier: format = 2
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of SinglePos subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Defines the types of data in the ValueRecords.
+    pub fn value_format(&self) -> ValueFormat {
+        self.value_format.get()
+    }
+
+    /// Number of ValueRecords — must equal glyphCount in the
+    /// Coverage table.
+    pub fn value_count(&self) -> u16 {
+        self.value_count.get()
+    }
+
+    /// Array of ValueRecords — positioning values applied to glyphs.
+    pub fn value_records_data(&self) -> &[u8] {
+        &self.value_records_data
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for SinglePosFormat2<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+pub enum SinglePos<'a> {
+    Format1(SinglePosFormat1<'a>),
+    Format2(SinglePosFormat2<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for SinglePos<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// [Pair Adjustment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#pair-adjustment-positioning-format-1-adjustments-for-glyph-pairs): Adjustments for Glyph Pairs
+pub struct PairPosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    value_format1: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    value_format2: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    pair_set_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    pair_set_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for PairPosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_format1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let (value_format2, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let (pair_set_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_pair_set_count = pair_set_count.get();
+        let (pair_set_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_pair_set_count as usize,
+            )?;
+        let _ = bytes;
+        Some(PairPosFormat1 {
+            pos_format,
+            coverage_offset,
+            value_format1,
+            value_format2,
+            pair_set_count,
+            pair_set_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> PairPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of PairPos subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Defines the types of data in valueRecord1 — for the first
+    /// glyph in the pair (may be zero).
+    pub fn value_format1(&self) -> ValueFormat {
+        self.value_format1.get()
+    }
+
+    /// Defines the types of data in valueRecord2 — for the second
+    /// glyph in the pair (may be zero).
+    pub fn value_format2(&self) -> ValueFormat {
+        self.value_format2.get()
+    }
+
+    /// Number of PairSet tables
+    pub fn pair_set_count(&self) -> u16 {
+        self.pair_set_count.get()
+    }
+
+    /// Array of offsets to PairSet tables. Offsets are from beginning
+    /// of PairPos subtable, ordered by Coverage Index.
+    pub fn pair_set_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.pair_set_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for PairPosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [PairPosFormat1]
+///
+/// The size of each PairValueRecord depends on the value formats of the
+/// parent subtable, and must be passed in when reading.
+pub struct PairSet<'a> {
+    pair_value_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    pair_value_record_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> PairSet<'a> {
+    pub fn read(bytes: &'a [u8], pair_value_record_len: usize) -> Option<Self> {
+        let __resolved_pair_value_record_len = pair_value_record_len;
+        let offset_bytes = bytes;
+        let (pair_value_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_pair_value_count = pair_value_count.get();
+        let (pair_value_record_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                records_len(
+                    __resolved_pair_value_count,
+                    __resolved_pair_value_record_len,
+                ),
+            )?;
+        let _ = bytes;
+        Some(PairSet {
+            pair_value_count,
+            pair_value_record_data,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> PairSet<'a> {
+    /// Number of PairValueRecords
+    pub fn pair_value_count(&self) -> u16 {
+        self.pair_value_count.get()
+    }
+
+    /// Array of PairValueRecords, ordered by glyph ID of the second
+    /// glyph.
+    pub fn pair_value_record_data(&self) -> &[u8] {
+        &self.pair_value_record_data
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for PairSet<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Pair Adjustment Positioning Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#pair-adjustment-positioning-format-2-class-pair-adjustment): Class Pair Adjustment
+pub struct PairPosFormat2<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    value_format1: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    value_format2: zerocopy::LayoutVerified<&'a [u8], BigEndian<ValueFormat>>,
+    class_def1_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    class_def2_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    class1_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    class2_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    class1_record_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for PairPosFormat2<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_format1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let __resolved_value_format1 = value_format1.get();
+        let (value_format2, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<ValueFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let __resolved_value_format2 = value_format2.get();
+        let (class_def1_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (class_def2_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (class1_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_class1_count = class1_count.get();
+        let (class2_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_class2_count = class2_count.get();
+        let (class1_record_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                class1_records_len(
+                    __resolved_class1_count,
+                    __resolved_class2_count,
+                    __resolved_value_format1,
+                    __resolved_value_format2,
+                ),
+            )?;
+        let _ = bytes;
+        Some(PairPosFormat2 {
+            pos_format,
+            coverage_offset,
+            value_format1,
+            value_format2,
+            class_def1_offset,
+            class_def2_offset,
+            class1_count,
+            class2_count,
+            class1_record_data,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> PairPosFormat2<'a> {
+    /// Format identifier: format = 2
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of PairPos subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// ValueRecord definition — for the first glyph of the pair (may
+    /// be zero).
+    pub fn value_format1(&self) -> ValueFormat {
+        self.value_format1.get()
+    }
+
+    /// ValueRecord definition — for the second glyph of the pair
+    /// (may be zero).
+    pub fn value_format2(&self) -> ValueFormat {
+        self.value_format2.get()
+    }
+
+    /// Offset to ClassDef table, from beginning of PairPos subtable
+    /// — for the first glyph of the pair.
+    pub fn class_def1_offset(&self) -> Offset16 {
+        self.class_def1_offset.get()
+    }
+
+    /// Offset to ClassDef table, from beginning of PairPos subtable
+    /// — for the second glyph of the pair.
+    pub fn class_def2_offset(&self) -> Offset16 {
+        self.class_def2_offset.get()
+    }
+
+    /// Number of classes in classDef1 table — includes Class 0.
+    pub fn class1_count(&self) -> u16 {
+        self.class1_count.get()
+    }
+
+    /// Number of classes in classDef2 table — includes Class 0.
+    pub fn class2_count(&self) -> u16 {
+        self.class2_count.get()
+    }
+
+    /// Array of Class1 records, ordered by classes in classDef1.
+    pub fn class1_record_data(&self) -> &[u8] {
+        &self.class1_record_data
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for PairPosFormat2<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Lookup Type 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-2-pair-adjustment-positioning-subtable): Pair Adjustment Positioning Subtable
+pub enum PairPos<'a> {
+    Format1(PairPosFormat1<'a>),
+    Format2(PairPosFormat2<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for PairPos<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// [Cursive Attachment Positioning Format1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#cursive-attachment-positioning-format1-cursive-attachment): Cursvie attachment
+pub struct CursivePosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    entry_exit_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    entry_exit_record: zerocopy::LayoutVerified<&'a [u8], [EntryExitRecord]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for CursivePosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (entry_exit_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_entry_exit_count = entry_exit_count.get();
+        let (entry_exit_record, bytes) =
+            zerocopy::LayoutVerified::<_, [EntryExitRecord]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_entry_exit_count as usize,
+            )?;
+        let _ = bytes;
+        Some(CursivePosFormat1 {
+            pos_format,
+            coverage_offset,
+            entry_exit_count,
+            entry_exit_record,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> CursivePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of CursivePos subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of EntryExit records
+    pub fn entry_exit_count(&self) -> u16 {
+        self.entry_exit_count.get()
+    }
+
+    /// Array of EntryExit records, in Coverage index order.
+    pub fn entry_exit_record(&self) -> &[EntryExitRecord] {
+        &self.entry_exit_record
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for CursivePosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [CursivePosFormat1]
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct EntryExitRecord {
+    /// Offset to entryAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    pub entry_anchor_offset: BigEndian<Offset16>,
+    /// Offset to exitAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    pub exit_anchor_offset: BigEndian<Offset16>,
+}
+
+impl EntryExitRecord {
+    /// Offset to entryAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    pub fn entry_anchor_offset(&self) -> Offset16 {
+        self.entry_anchor_offset.get()
+    }
+
+    /// Offset to exitAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    pub fn exit_anchor_offset(&self) -> Offset16 {
+        self.exit_anchor_offset.get()
+    }
+}
+
+/// [Mark-to-Base Attachment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-base-attachment-positioning-format-1-mark-to-base-attachment-point): Mark-to-base Attachment Point
+pub struct MarkBasePosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    base_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    mark_class_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    base_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for MarkBasePosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (base_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_class_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (base_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(MarkBasePosFormat1 {
+            pos_format,
+            mark_coverage_offset,
+            base_coverage_offset,
+            mark_class_count,
+            mark_array_offset,
+            base_array_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> MarkBasePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to markCoverage table, from beginning of MarkBasePos
+    /// subtable.
+    pub fn mark_coverage_offset(&self) -> Offset16 {
+        self.mark_coverage_offset.get()
+    }
+
+    /// Offset to baseCoverage table, from beginning of MarkBasePos
+    /// subtable.
+    pub fn base_coverage_offset(&self) -> Offset16 {
+        self.base_coverage_offset.get()
+    }
+
+    /// Number of classes defined for marks
+    pub fn mark_class_count(&self) -> u16 {
+        self.mark_class_count.get()
+    }
+
+    /// Offset to MarkArray table, from beginning of MarkBasePos
+    /// subtable.
+    pub fn mark_array_offset(&self) -> Offset16 {
+        self.mark_array_offset.get()
+    }
+
+    /// Offset to BaseArray table, from beginning of MarkBasePos
+    /// subtable.
+    pub fn base_array_offset(&self) -> Offset16 {
+        self.base_array_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for MarkBasePosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MarkBasePosFormat1]
+///
+/// Each BaseRecord contains one anchor offset per mark class; the records
+/// are stored here as a single flat array.
+pub struct BaseArray<'a> {
+    base_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    base_anchor_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> BaseArray<'a> {
+    pub fn read(bytes: &'a [u8], mark_class_count: usize) -> Option<Self> {
+        let __resolved_mark_class_count = mark_class_count;
+        let offset_bytes = bytes;
+        let (base_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_base_count = base_count.get();
+        let (base_anchor_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                records_len(__resolved_base_count, __resolved_mark_class_count),
+            )?;
+        let _ = bytes;
+        Some(BaseArray {
+            base_count,
+            base_anchor_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> BaseArray<'a> {
+    /// Number of BaseRecords
+    pub fn base_count(&self) -> u16 {
+        self.base_count.get()
+    }
+
+    /// Array of BaseRecords, in order of baseCoverage Index. Offsets
+    /// are from beginning of BaseArray table, ordered by class
+    /// (offsets may be NULL).
+    pub fn base_anchor_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.base_anchor_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for BaseArray<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Mark-to-Ligature Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-ligature-attachment-positioning-format-1-mark-to-ligature-attachment): Mark-to-Ligature Attachment
+pub struct MarkLigPosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    ligature_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    mark_class_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    ligature_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for MarkLigPosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (ligature_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_class_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (ligature_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(MarkLigPosFormat1 {
+            pos_format,
+            mark_coverage_offset,
+            ligature_coverage_offset,
+            mark_class_count,
+            mark_array_offset,
+            ligature_array_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> MarkLigPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to markCoverage table, from beginning of MarkLigPos
+    /// subtable.
+    pub fn mark_coverage_offset(&self) -> Offset16 {
+        self.mark_coverage_offset.get()
+    }
+
+    /// Offset to ligatureCoverage table, from beginning of MarkLigPos
+    /// subtable.
+    pub fn ligature_coverage_offset(&self) -> Offset16 {
+        self.ligature_coverage_offset.get()
+    }
+
+    /// Number of defined mark classes
+    pub fn mark_class_count(&self) -> u16 {
+        self.mark_class_count.get()
+    }
+
+    /// Offset to MarkArray table, from beginning of MarkLigPos
+    /// subtable.
+    pub fn mark_array_offset(&self) -> Offset16 {
+        self.mark_array_offset.get()
+    }
+
+    /// Offset to LigatureArray table, from beginning of MarkLigPos
+    /// subtable.
+    pub fn ligature_array_offset(&self) -> Offset16 {
+        self.ligature_array_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for MarkLigPosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MarkLigPosFormat1]
+pub struct LigatureArray<'a> {
+    ligature_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    ligature_attach_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for LigatureArray<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (ligature_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_ligature_count = ligature_count.get();
+        let (ligature_attach_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_ligature_count as usize,
+            )?;
+        let _ = bytes;
+        Some(LigatureArray {
+            ligature_count,
+            ligature_attach_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> LigatureArray<'a> {
+    /// Number of LigatureAttach table offsets
+    pub fn ligature_count(&self) -> u16 {
+        self.ligature_count.get()
+    }
+
+    /// Array of offsets to LigatureAttach tables. Offsets are from
+    /// beginning of LigatureArray table, ordered by ligatureCoverage
+    /// index.
+    pub fn ligature_attach_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.ligature_attach_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for LigatureArray<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MarkLigPosFormat1]
+///
+/// Each ComponentRecord contains one anchor offset per mark class; the
+/// records are stored here as a single flat array.
+pub struct LigatureAttach<'a> {
+    component_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    ligature_anchor_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> LigatureAttach<'a> {
+    pub fn read(bytes: &'a [u8], mark_class_count: usize) -> Option<Self> {
+        let __resolved_mark_class_count = mark_class_count;
+        let offset_bytes = bytes;
+        let (component_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_component_count = component_count.get();
+        let (ligature_anchor_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                records_len(__resolved_component_count, __resolved_mark_class_count),
+            )?;
+        let _ = bytes;
+        Some(LigatureAttach {
+            component_count,
+            ligature_anchor_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> LigatureAttach<'a> {
+    /// Number of ComponentRecords in this ligature
+    pub fn component_count(&self) -> u16 {
+        self.component_count.get()
+    }
+
+    /// Array of Component records, ordered in writing direction.
+    /// Offsets are from beginning of LigatureAttach table, ordered by
+    /// class (offsets may be NULL).
+    pub fn ligature_anchor_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.ligature_anchor_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for LigatureAttach<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Mark-to-Mark Attachment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-mark-attachment-positioning-format-1-mark-to-mark-attachment): Mark-to-Mark Attachment
+pub struct MarkMarkPosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark1_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    mark2_coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    mark_class_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark1_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    mark2_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for MarkMarkPosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark1_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark2_coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark_class_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark1_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (mark2_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(MarkMarkPosFormat1 {
+            pos_format,
+            mark1_coverage_offset,
+            mark2_coverage_offset,
+            mark_class_count,
+            mark1_array_offset,
+            mark2_array_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> MarkMarkPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Offset to Combining Mark Coverage table, from beginning of
+    /// MarkMarkPos subtable.
+    pub fn mark1_coverage_offset(&self) -> Offset16 {
+        self.mark1_coverage_offset.get()
+    }
+
+    /// Offset to Base Mark Coverage table, from beginning of
+    /// MarkMarkPos subtable.
+    pub fn mark2_coverage_offset(&self) -> Offset16 {
+        self.mark2_coverage_offset.get()
+    }
+
+    /// Number of Combining Mark classes defined
+    pub fn mark_class_count(&self) -> u16 {
+        self.mark_class_count.get()
+    }
+
+    /// Offset to MarkArray table for mark1, from beginning of
+    /// MarkMarkPos subtable.
+    pub fn mark1_array_offset(&self) -> Offset16 {
+        self.mark1_array_offset.get()
+    }
+
+    /// Offset to Mark2Array table for mark2, from beginning of
+    /// MarkMarkPos subtable.
+    pub fn mark2_array_offset(&self) -> Offset16 {
+        self.mark2_array_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for MarkMarkPosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MarkMarkPosFormat1]
+///
+/// Each Mark2Record contains one anchor offset per mark class; the records
+/// are stored here as a single flat array.
+pub struct Mark2Array<'a> {
+    mark2_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    mark2_anchor_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> Mark2Array<'a> {
+    pub fn read(bytes: &'a [u8], mark_class_count: usize) -> Option<Self> {
+        let __resolved_mark_class_count = mark_class_count;
+        let offset_bytes = bytes;
+        let (mark2_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_mark2_count = mark2_count.get();
+        let (mark2_anchor_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                records_len(__resolved_mark2_count, __resolved_mark_class_count),
+            )?;
+        let _ = bytes;
+        Some(Mark2Array {
+            mark2_count,
+            mark2_anchor_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Mark2Array<'a> {
+    /// Number of Mark2 records
+    pub fn mark2_count(&self) -> u16 {
+        self.mark2_count.get()
+    }
+
+    /// Array of Mark2Records, in Coverage order. Offsets are from
+    /// beginning of Mark2Array table, in class order (offsets may be
+    /// NULL).
+    pub fn mark2_anchor_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.mark2_anchor_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Mark2Array<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Extension Positioning Subtable Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#extension-positioning-subtable-format-1)
+pub struct ExtensionPosFormat1<'a> {
+    pos_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    extension_lookup_type: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    extension_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for ExtensionPosFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (pos_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (extension_lookup_type, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (extension_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(ExtensionPosFormat1 {
+            pos_format,
+            extension_lookup_type,
+            extension_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> ExtensionPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn pos_format(&self) -> u16 {
+        self.pos_format.get()
+    }
+
+    /// Lookup type of subtable referenced by extensionOffset (i.e. the
+    /// extension subtable).
+    pub fn extension_lookup_type(&self) -> u16 {
+        self.extension_lookup_type.get()
+    }
+
+    /// Offset to the extension subtable, of lookup type
+    /// extensionLookupType, relative to the start of the
+    /// ExtensionPosFormat1 subtable.
+    pub fn extension_offset(&self) -> Offset32 {
+        self.extension_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for ExtensionPosFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+fn value_record_len(format: ValueFormat) -> usize {
+    format.record_byte_len()
+}
+
+fn value_records_len(format: ValueFormat, count: u16) -> usize {
+    format.record_byte_len() * count as usize
+}
+
+fn records_len(count: u16, record_len: usize) -> usize {
+    count as usize * record_len
+}
+
+fn class1_records_len(
+    class1_count: u16,
+    class2_count: u16,
+    format1: ValueFormat,
+    format2: ValueFormat,
+) -> usize {
+    let class2_record_len = format1.record_byte_len() + format2.record_byte_len();
+    class1_count as usize * class2_count as usize * class2_record_len
+}
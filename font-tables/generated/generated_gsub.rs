@@ -0,0 +1,900 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// [GSUB](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#gsub-header) 1.0
+pub struct Gsub1_0<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    script_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    lookup_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Gsub1_0<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (script_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (lookup_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Gsub1_0 {
+            major_version,
+            minor_version,
+            script_list_offset,
+            feature_list_offset,
+            lookup_list_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Gsub1_0<'a> {
+    /// Major version of the GSUB table, = 1
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version of the GSUB table, = 0
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset to ScriptList table, from beginning of GSUB table
+    pub fn script_list_offset(&self) -> Offset16 {
+        self.script_list_offset.get()
+    }
+
+    /// Offset to FeatureList table, from beginning of GSUB table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        self.feature_list_offset.get()
+    }
+
+    /// Offset to LookupList table, from beginning of GSUB table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        self.lookup_list_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gsub1_0<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [GSUB](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#gsub-header) 1.1
+pub struct Gsub1_1<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    script_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    lookup_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    feature_variations_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Gsub1_1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (script_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (lookup_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (feature_variations_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Gsub1_1 {
+            major_version,
+            minor_version,
+            script_list_offset,
+            feature_list_offset,
+            lookup_list_offset,
+            feature_variations_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Gsub1_1<'a> {
+    /// Major version of the GSUB table, = 1
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version of the GSUB table, = 1
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset to ScriptList table, from beginning of GSUB table
+    pub fn script_list_offset(&self) -> Offset16 {
+        self.script_list_offset.get()
+    }
+
+    /// Offset to FeatureList table, from beginning of GSUB table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        self.feature_list_offset.get()
+    }
+
+    /// Offset to LookupList table, from beginning of GSUB table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        self.lookup_list_offset.get()
+    }
+
+    /// Offset to FeatureVariations table, from beginning of the GSUB
+    /// table (may be NULL)
+    pub fn feature_variations_offset(&self) -> Offset32 {
+        self.feature_variations_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gsub1_1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+pub enum Gsub<'a> {
+    Gsub1_0(Gsub1_0<'a>),
+    Gsub1_1(Gsub1_1<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for Gsub<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        const _: MajorMinor = MajorMinor::VERSION_1_0;
+        const _: MajorMinor = MajorMinor::VERSION_1_1;
+        let version: BigEndian<MajorMinor> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            MajorMinor::VERSION_1_0 => Some(Self::Gsub1_0(font_types::FontRead::read(bytes)?)),
+            MajorMinor::VERSION_1_1 => Some(Self::Gsub1_1(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<'a> Gsub<'a> {
+    /// Offset to FeatureList table, from beginning of GSUB table
+    pub fn feature_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.feature_list_offset(),
+            Self::Gsub1_1(_inner) => _inner.feature_list_offset(),
+        }
+    }
+
+    /// Offset to FeatureVariations table, from beginning of the GSUB
+    /// table (may be NULL)
+    pub fn feature_variations_offset(&self) -> Option<Offset32> {
+        match self {
+            Self::Gsub1_0(_inner) => None,
+            Self::Gsub1_1(_inner) => Some(_inner.feature_variations_offset()),
+        }
+    }
+
+    /// Offset to LookupList table, from beginning of GSUB table
+    pub fn lookup_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.lookup_list_offset(),
+            Self::Gsub1_1(_inner) => _inner.lookup_list_offset(),
+        }
+    }
+
+    /// Major version of the GSUB table, = 1
+    pub fn major_version(&self) -> u16 {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.major_version(),
+            Self::Gsub1_1(_inner) => _inner.major_version(),
+        }
+    }
+
+    /// Minor version of the GSUB table, = 0
+    pub fn minor_version(&self) -> u16 {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.minor_version(),
+            Self::Gsub1_1(_inner) => _inner.minor_version(),
+        }
+    }
+
+    /// Offset to ScriptList table, from beginning of GSUB table
+    pub fn script_list_offset(&self) -> Offset16 {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.script_list_offset(),
+            Self::Gsub1_1(_inner) => _inner.script_list_offset(),
+        }
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gsub<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Gsub1_0(_inner) => _inner.bytes(),
+            Self::Gsub1_1(_inner) => _inner.bytes(),
+        }
+    }
+}
+
+/// [Single Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#11-single-substitution-format-1)
+pub struct SingleSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    delta_glyph_id: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for SingleSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (delta_glyph_id, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(SingleSubstFormat1 {
+            subst_format,
+            coverage_offset,
+            delta_glyph_id,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> SingleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Add to original glyph ID to get substitute glyph ID
+    pub fn delta_glyph_id(&self) -> i16 {
+        self.delta_glyph_id.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for SingleSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Single Substitution Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#12-single-substitution-format-2)
+pub struct SingleSubstFormat2<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    substitute_glyph_ids: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for SingleSubstFormat2<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_glyph_count = glyph_count.get();
+        let (substitute_glyph_ids, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_glyph_count as usize,
+            )?;
+        let _ = bytes;
+        Some(SingleSubstFormat2 {
+            subst_format,
+            coverage_offset,
+            glyph_count,
+            substitute_glyph_ids,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> SingleSubstFormat2<'a> {
+    /// Format identifier: format = 2
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of glyph IDs in the substituteGlyphIDs array
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count.get()
+    }
+
+    /// Array of substitute glyph IDs — ordered by Coverage index
+    pub fn substitute_glyph_ids(&self) -> &[BigEndian<u16>] {
+        &self.substitute_glyph_ids
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for SingleSubstFormat2<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#lookuptype-1-single-substitution-subtable): Single Substitution Subtable
+pub enum SingleSubst<'a> {
+    Format1(SingleSubstFormat1<'a>),
+    Format2(SingleSubstFormat2<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for SingleSubst<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// [Multiple Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#21-multiple-substitution-format-1)
+pub struct MultipleSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    sequence_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    sequence_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for MultipleSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (sequence_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_sequence_count = sequence_count.get();
+        let (sequence_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_sequence_count as usize,
+            )?;
+        let _ = bytes;
+        Some(MultipleSubstFormat1 {
+            subst_format,
+            coverage_offset,
+            sequence_count,
+            sequence_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> MultipleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of Sequence table offsets in the sequenceOffsets array
+    pub fn sequence_count(&self) -> u16 {
+        self.sequence_count.get()
+    }
+
+    /// Array of offsets to Sequence tables. Offsets are from beginning
+    /// of substitution subtable, ordered by Coverage index
+    pub fn sequence_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.sequence_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for MultipleSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [MultipleSubstFormat1]
+pub struct Sequence<'a> {
+    glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    substitute_glyph_ids: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+}
+
+impl<'a> font_types::FontRead<'a> for Sequence<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_glyph_count = glyph_count.get();
+        let (substitute_glyph_ids, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_glyph_count as usize,
+            )?;
+        let _ = bytes;
+        Some(Sequence {
+            glyph_count,
+            substitute_glyph_ids,
+        })
+    }
+}
+
+impl<'a> Sequence<'a> {
+    /// Number of glyph IDs in the substituteGlyphIDs array. This must
+    /// always be greater than 0.
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count.get()
+    }
+
+    /// String of glyph IDs to substitute
+    pub fn substitute_glyph_ids(&self) -> &[BigEndian<u16>] {
+        &self.substitute_glyph_ids
+    }
+}
+
+/// [Alternate Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#31-alternate-substitution-format-1)
+pub struct AlternateSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    alternate_set_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    alternate_set_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for AlternateSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (alternate_set_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_alternate_set_count = alternate_set_count.get();
+        let (alternate_set_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_alternate_set_count as usize,
+            )?;
+        let _ = bytes;
+        Some(AlternateSubstFormat1 {
+            subst_format,
+            coverage_offset,
+            alternate_set_count,
+            alternate_set_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> AlternateSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of AlternateSet tables
+    pub fn alternate_set_count(&self) -> u16 {
+        self.alternate_set_count.get()
+    }
+
+    /// Array of offsets to AlternateSet tables. Offsets are from
+    /// beginning of substitution subtable, ordered by Coverage index
+    pub fn alternate_set_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.alternate_set_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for AlternateSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [AlternateSubstFormat1]
+pub struct AlternateSet<'a> {
+    glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    alternate_glyph_ids: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+}
+
+impl<'a> font_types::FontRead<'a> for AlternateSet<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_glyph_count = glyph_count.get();
+        let (alternate_glyph_ids, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_glyph_count as usize,
+            )?;
+        let _ = bytes;
+        Some(AlternateSet {
+            glyph_count,
+            alternate_glyph_ids,
+        })
+    }
+}
+
+impl<'a> AlternateSet<'a> {
+    /// Number of glyph IDs in the alternateGlyphIDs array
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count.get()
+    }
+
+    /// Array of alternate glyph IDs, in arbitrary order
+    pub fn alternate_glyph_ids(&self) -> &[BigEndian<u16>] {
+        &self.alternate_glyph_ids
+    }
+}
+
+/// [Ligature Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#41-ligature-substitution-format-1)
+pub struct LigatureSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    ligature_set_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    ligature_set_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for LigatureSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (ligature_set_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_ligature_set_count = ligature_set_count.get();
+        let (ligature_set_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_ligature_set_count as usize,
+            )?;
+        let _ = bytes;
+        Some(LigatureSubstFormat1 {
+            subst_format,
+            coverage_offset,
+            ligature_set_count,
+            ligature_set_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> LigatureSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of LigatureSet tables
+    pub fn ligature_set_count(&self) -> u16 {
+        self.ligature_set_count.get()
+    }
+
+    /// Array of offsets to LigatureSet tables. Offsets are from
+    /// beginning of substitution subtable, ordered by Coverage index
+    pub fn ligature_set_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.ligature_set_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for LigatureSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [LigatureSubstFormat1]
+pub struct LigatureSet<'a> {
+    ligature_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    ligature_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for LigatureSet<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (ligature_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_ligature_count = ligature_count.get();
+        let (ligature_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_ligature_count as usize,
+            )?;
+        let _ = bytes;
+        Some(LigatureSet {
+            ligature_count,
+            ligature_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> LigatureSet<'a> {
+    /// Number of Ligature tables
+    pub fn ligature_count(&self) -> u16 {
+        self.ligature_count.get()
+    }
+
+    /// Array of offsets to Ligature tables. Offsets are from beginning
+    /// of LigatureSet table, ordered by preference.
+    pub fn ligature_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.ligature_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for LigatureSet<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// Part of [LigatureSubstFormat1]
+pub struct Ligature<'a> {
+    ligature_glyph: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    component_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    component_glyph_ids: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+}
+
+impl<'a> font_types::FontRead<'a> for Ligature<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (ligature_glyph, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (component_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_component_count = component_count.get();
+        let (component_glyph_ids, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                minus_one(__resolved_component_count),
+            )?;
+        let _ = bytes;
+        Some(Ligature {
+            ligature_glyph,
+            component_count,
+            component_glyph_ids,
+        })
+    }
+}
+
+impl<'a> Ligature<'a> {
+    /// glyph ID of ligature to substitute
+    pub fn ligature_glyph(&self) -> u16 {
+        self.ligature_glyph.get()
+    }
+
+    /// Number of components in the ligature
+    pub fn component_count(&self) -> u16 {
+        self.component_count.get()
+    }
+
+    /// Array of component glyph IDs — start with the second
+    /// component, ordered in writing direction
+    pub fn component_glyph_ids(&self) -> &[BigEndian<u16>] {
+        &self.component_glyph_ids
+    }
+}
+
+/// [Extension Substitution Subtable Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#71-extension-substitution-subtable-format-1)
+pub struct ExtensionSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    extension_lookup_type: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    extension_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for ExtensionSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (extension_lookup_type, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (extension_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(ExtensionSubstFormat1 {
+            subst_format,
+            extension_lookup_type,
+            extension_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> ExtensionSubstFormat1<'a> {
+    /// Format identifier. Set to 1.
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Lookup type of subtable referenced by extensionOffset (that is,
+    /// the extension subtable).
+    pub fn extension_lookup_type(&self) -> u16 {
+        self.extension_lookup_type.get()
+    }
+
+    /// Offset to the extension subtable, of lookup type
+    /// extensionLookupType, relative to the start of the
+    /// ExtensionSubstFormat1 subtable.
+    pub fn extension_offset(&self) -> Offset32 {
+        self.extension_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for ExtensionSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Reverse Chaining Contextual Single Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#81-reverse-chaining-contextual-single-substitution-format-1-coverage-based-glyph-contexts)
+pub struct ReverseChainSingleSubstFormat1<'a> {
+    subst_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    coverage_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    backtrack_glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    backtrack_coverage_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    lookahead_glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    lookahead_coverage_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    substitute_glyph_ids: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for ReverseChainSingleSubstFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (subst_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (coverage_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (backtrack_glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_backtrack_glyph_count = backtrack_glyph_count.get();
+        let (backtrack_coverage_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_backtrack_glyph_count as usize,
+            )?;
+        let (lookahead_glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_lookahead_glyph_count = lookahead_glyph_count.get();
+        let (lookahead_coverage_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_lookahead_glyph_count as usize,
+            )?;
+        let (glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_glyph_count = glyph_count.get();
+        let (substitute_glyph_ids, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_glyph_count as usize,
+            )?;
+        let _ = bytes;
+        Some(ReverseChainSingleSubstFormat1 {
+            subst_format,
+            coverage_offset,
+            backtrack_glyph_count,
+            backtrack_coverage_offsets,
+            lookahead_glyph_count,
+            lookahead_coverage_offsets,
+            glyph_count,
+            substitute_glyph_ids,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> ReverseChainSingleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    pub fn subst_format(&self) -> u16 {
+        self.subst_format.get()
+    }
+
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable.
+    pub fn coverage_offset(&self) -> Offset16 {
+        self.coverage_offset.get()
+    }
+
+    /// Number of glyphs in the backtrack sequence.
+    pub fn backtrack_glyph_count(&self) -> u16 {
+        self.backtrack_glyph_count.get()
+    }
+
+    /// Array of offsets to coverage tables in backtrack sequence, in
+    /// glyph sequence order.
+    pub fn backtrack_coverage_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.backtrack_coverage_offsets
+    }
+
+    /// Number of glyphs in lookahead sequence.
+    pub fn lookahead_glyph_count(&self) -> u16 {
+        self.lookahead_glyph_count.get()
+    }
+
+    /// Array of offsets to coverage tables in lookahead sequence, in
+    /// glyph sequence order.
+    pub fn lookahead_coverage_offsets(&self) -> &[BigEndian<Offset16>] {
+        &self.lookahead_coverage_offsets
+    }
+
+    /// Number of glyph IDs in the substituteGlyphIDs array.
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count.get()
+    }
+
+    /// Array of substitute glyph IDs — ordered by Coverage index.
+    pub fn substitute_glyph_ids(&self) -> &[BigEndian<u16>] {
+        &self.substitute_glyph_ids
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for ReverseChainSingleSubstFormat1<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+fn minus_one(inp: u16) -> usize {
+    inp.saturating_sub(1) as usize
+}
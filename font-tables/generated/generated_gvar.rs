@@ -0,0 +1,227 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [gvar (Glyph Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/gvar) table
+pub struct Gvar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    axis_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    shared_tuple_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    shared_tuples_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    flags: zerocopy::LayoutVerified<&'a [u8], BigEndian<GvarFlags>>,
+    glyph_variation_data_array_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    glyph_variation_data_offsets: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Gvar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (axis_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (shared_tuple_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (shared_tuples_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (glyph_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_glyph_count = glyph_count.get();
+        let (flags, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<GvarFlags>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_flags = flags.get();
+        let (glyph_variation_data_array_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (glyph_variation_data_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                glyph_variation_data_offsets_len(__resolved_glyph_count, __resolved_flags),
+            )?;
+        let _ = bytes;
+        Some(Gvar {
+            major_version,
+            minor_version,
+            axis_count,
+            shared_tuple_count,
+            shared_tuples_offset,
+            glyph_count,
+            flags,
+            glyph_variation_data_array_offset,
+            glyph_variation_data_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Gvar<'a> {
+    /// Major version number of the glyph variations table — set to 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the glyph variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count.get()
+    }
+
+    /// The number of shared tuple records. Shared tuple records can be
+    /// referenced within glyph variation data tables for multiple
+    /// glyphs, as opposed to other tuple records stored directly
+    /// within a glyph variation data table.
+    pub fn shared_tuple_count(&self) -> u16 {
+        self.shared_tuple_count.get()
+    }
+
+    /// Offset from the start of this table to the shared tuple records.
+    pub fn shared_tuples_offset(&self) -> Offset32 {
+        self.shared_tuples_offset.get()
+    }
+
+    /// The number of glyphs in this font. This must match the number
+    /// of glyphs stored elsewhere in the font.
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count.get()
+    }
+
+    /// Bit-field that gives the format of the offset array that
+    /// follows. If bit 0 is clear, the offsets are uint16; if bit 0 is
+    /// set, the offsets are uint32.
+    pub fn flags(&self) -> GvarFlags {
+        self.flags.get()
+    }
+
+    /// Offset from the start of this table to the array of
+    /// GlyphVariationData tables.
+    pub fn glyph_variation_data_array_offset(&self) -> Offset32 {
+        self.glyph_variation_data_array_offset.get()
+    }
+
+    /// Offsets from the start of the GlyphVariationData array to each
+    /// GlyphVariationData table.
+    ///
+    /// These are either `Offset16` values divided by 2, or `Offset32`
+    /// values, depending on `flags`.
+    pub fn glyph_variation_data_offsets(&self) -> &[u8] {
+        &self.glyph_variation_data_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Gvar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+bitflags::bitflags! { # [doc = " Flags for the [Gvar] table"] pub struct GvarFlags : u16 { # [doc = " If set, offsets to glyph variation data are 32 bits."] const LONG_OFFSETS = 0x0001 ; } }
+impl font_types::Scalar for GvarFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+/// [GlyphVariationData](https://docs.microsoft.com/en-us/typography/opentype/spec/gvar#the-glyphvariationdata-table-array)
+pub struct GlyphVariationData<'a> {
+    tuple_variation_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<TupleVariationCount>>,
+    data_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    tuple_variation_headers: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for GlyphVariationData<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (tuple_variation_count, bytes) = zerocopy::LayoutVerified::<
+            _,
+            BigEndian<TupleVariationCount>,
+        >::new_unaligned_from_prefix(bytes)?;
+        let (data_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let (tuple_variation_headers, bytes) = (
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned(bytes)?,
+            0,
+        );
+        let _ = bytes;
+        Some(GlyphVariationData {
+            tuple_variation_count,
+            data_offset,
+            tuple_variation_headers,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> GlyphVariationData<'a> {
+    /// A packed field. The high 4 bits are flags, and the low 12 bits
+    /// are the number of tuple variation tables for this glyph.
+    pub fn tuple_variation_count(&self) -> TupleVariationCount {
+        self.tuple_variation_count.get()
+    }
+
+    /// Offset from the start of the GlyphVariationData table to the
+    /// serialized data
+    pub fn data_offset(&self) -> Offset16 {
+        self.data_offset.get()
+    }
+
+    /// The tuple variation headers, followed by the serialized data.
+    pub fn tuple_variation_headers(&self) -> &[u8] {
+        &self.tuple_variation_headers
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for GlyphVariationData<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+bitflags::bitflags! { # [doc = " The `tupleVariationCount` field of a [GlyphVariationData] table."] pub struct TupleVariationCount : u16 { # [doc = " Flag indicating that some or all tuple variation tables"] # [doc = " reference a shared set of “point” numbers. These shared numbers"] # [doc = " are represented as packed point number data at the start of the"] # [doc = " serialized data."] const SHARED_POINT_NUMBERS = 0x8000 ; # [doc = " Mask for the low 12 bits to give the count value."] const COUNT_MASK = 0x0FFF ; } }
+impl font_types::Scalar for TupleVariationCount {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+bitflags::bitflags! { # [doc = " The `tupleIndex` field of a tuple variation header."] pub struct TupleIndex : u16 { # [doc = " Flag indicating that this tuple variation header includes an"] # [doc = " embedded peak tuple record, immediately after the tupleIndex"] # [doc = " field."] const EMBEDDED_PEAK_TUPLE = 0x8000 ; # [doc = " Flag indicating that this tuple variation table applies to an"] # [doc = " intermediate region within the variation space."] const INTERMEDIATE_REGION = 0x4000 ; # [doc = " Flag indicating that the serialized data for this tuple"] # [doc = " variation table includes packed “point” number data."] const PRIVATE_POINT_NUMBERS = 0x2000 ; # [doc = " Mask for the low 12 bits to give the shared tuple records index."] const TUPLE_INDEX_MASK = 0x0FFF ; } }
+impl font_types::Scalar for TupleIndex {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+fn glyph_variation_data_offsets_len(glyph_count: u16, flags: GvarFlags) -> usize {
+    let offset_size = if flags.contains(GvarFlags::LONG_OFFSETS) {
+        4
+    } else {
+        2
+    };
+    (glyph_count as usize + 1) * offset_size
+}
@@ -23,7 +23,7 @@ pub struct Head {
     /// Set to 0x5F0F3CF5.
     pub magic_number: BigEndian<u32>,
     /// See the flags enum
-    pub flags: BigEndian<u16>,
+    pub flags: BigEndian<HeadFlags>,
     /// Set to a value from 16 to 16384. Any value in this range is
     /// valid. In fonts that have TrueType outlines, a power of 2 is
     /// recommended as this allows performance optimizations in some
@@ -43,8 +43,9 @@ pub struct Head {
     pub x_max: BigEndian<i16>,
     /// Maximum y coordinate across all glyph bounding boxes.
     pub y_max: BigEndian<i16>,
-    /// see somewhere else
-    pub mac_style: BigEndian<u16>,
+    /// Style information, which should agree with the `fsSelection`
+    /// field in the OS/2 table.
+    pub mac_style: BigEndian<MacStyle>,
     /// Smallest readable size in pixels.
     pub lowest_rec_ppem: BigEndian<u16>,
     /// Deprecated (Set to 2).
@@ -86,7 +87,7 @@ impl Head {
     }
 
     /// See the flags enum
-    pub fn flags(&self) -> u16 {
+    pub fn flags(&self) -> HeadFlags {
         self.flags.get()
     }
 
@@ -130,8 +131,9 @@ impl Head {
         self.y_max.get()
     }
 
-    /// see somewhere else
-    pub fn mac_style(&self) -> u16 {
+    /// Style information, which should agree with the `fsSelection`
+    /// field in the OS/2 table.
+    pub fn mac_style(&self) -> MacStyle {
         self.mac_style.get()
     }
 
@@ -155,3 +157,27 @@ impl Head {
         self.glyph_data_format.get()
     }
 }
+
+bitflags::bitflags! { # [doc = " Flags used in the [Head] table"] pub struct HeadFlags : u16 { # [doc = " Bit 0: Baseline for font at y=0."] const BASELINE_AT_Y_0 = 0x0001 ; # [doc = " Bit 1: Left sidebearing point at x=0 (relevant only for"] # [doc = " TrueType rasterizers)."] const LSB_AT_X_0 = 0x0002 ; # [doc = " Bit 2: Instructions may depend on point size."] const INSTRUCTIONS_DEPEND_ON_POINT_SIZE = 0x0004 ; # [doc = " Bit 3: Force ppem to integer values for all internal scaler"] # [doc = " math; may use fractional ppem sizes if this bit is clear. It is"] # [doc = " strongly recommended that this be set in hinted fonts."] const FORCE_INTEGER_PPEM = 0x0008 ; # [doc = " Bit 4: Instructions may alter advance width (the advance widths"] # [doc = " might not scale linearly)."] const INSTRUCTIONS_ALTER_ADVANCE_WIDTH = 0x0010 ; # [doc = " Bit 11: Font data is “lossless” as a result of having been"] # [doc = " subjected to optimizing transformation and/or compression (such"] # [doc = " as compression mechanisms defined by ISO/IEC 14496-18,"] # [doc = " MicroType® Express, WOFF 2.0, or similar) where the original"] # [doc = " font functionality and features are retained but the binary"] # [doc = " compatibility between input and output font files is not"] # [doc = " guaranteed. As a result of the applied transform, the DSIG"] # [doc = " table may also be invalidated."] const LOSSLESS = 0x0800 ; # [doc = " Bit 12: Font converted (produce compatible metrics)."] const CONVERTED = 0x1000 ; # [doc = " Bit 13: Font optimized for ClearType™. Note, fonts that rely on"] # [doc = " embedded bitmaps (EBDT) for rendering should not be considered"] # [doc = " optimized for ClearType, and therefore should keep this bit"] # [doc = " cleared."] const CLEARTYPE_OPTIMIZED = 0x2000 ; # [doc = " Bit 14: Last Resort font. If set, indicates that the glyphs"] # [doc = " encoded in the 'cmap' subtables are simply generic symbolic"] # [doc = " representations of code point ranges and do not truly represent"] # [doc = " support for those code points. If unset, indicates that the"] # [doc = " glyphs encoded in the 'cmap' subtables represent proper support"] # [doc = " for those code points."] const LAST_RESORT = 0x4000 ; } }
+impl font_types::Scalar for HeadFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+bitflags::bitflags! { # [doc = " The `macStyle` field of the [Head] table"] pub struct MacStyle : u16 { # [doc = " Bit 0: Bold (if set to 1)"] const BOLD = 0x0001 ; # [doc = " Bit 1: Italic (if set to 1)"] const ITALIC = 0x0002 ; # [doc = " Bit 2: Underline (if set to 1)"] const UNDERLINE = 0x0004 ; # [doc = " Bit 3: Outline (if set to 1)"] const OUTLINE = 0x0008 ; # [doc = " Bit 4: Shadow (if set to 1)"] const SHADOW = 0x0010 ; # [doc = " Bit 5: Condensed (if set to 1)"] const CONDENSED = 0x0020 ; # [doc = " Bit 6: Extended (if set to 1)"] const EXTENDED = 0x0040 ; } }
+impl font_types::Scalar for MacStyle {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
@@ -0,0 +1,86 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [HVAR (Horizontal Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
+pub struct Hvar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    item_variation_store_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    advance_width_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    lsb_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    rsb_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Hvar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (item_variation_store_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (advance_width_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (lsb_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (rsb_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Hvar {
+            major_version,
+            minor_version,
+            item_variation_store_offset,
+            advance_width_mapping_offset,
+            lsb_mapping_offset,
+            rsb_mapping_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Hvar<'a> {
+    /// Major version number of the horizontal metrics variations table — set to 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the horizontal metrics variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table.
+    pub fn item_variation_store_offset(&self) -> Offset32 {
+        self.item_variation_store_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for advance widths (may be NULL).
+    pub fn advance_width_mapping_offset(&self) -> Offset32 {
+        self.advance_width_mapping_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for left side bearings (may be NULL).
+    pub fn lsb_mapping_offset(&self) -> Offset32 {
+        self.lsb_mapping_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for right side bearings (may be NULL).
+    pub fn rsb_mapping_offset(&self) -> Offset32 {
+        self.rsb_mapping_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Hvar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
@@ -275,12 +275,15 @@ impl FeatureRecord {
 
 /// [Feature Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#feature-table)
 pub struct Feature<'a> {
+    feature_params_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
     lookup_index_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
     lookup_list_indices: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
 }
 
 impl<'a> font_types::FontRead<'a> for Feature<'a> {
     fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (feature_params_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
         let (lookup_index_count, bytes) =
             zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
         let __resolved_lookup_index_count = lookup_index_count.get();
@@ -291,6 +294,7 @@ impl<'a> font_types::FontRead<'a> for Feature<'a> {
             )?;
         let _ = bytes;
         Some(Feature {
+            feature_params_offset,
             lookup_index_count,
             lookup_list_indices,
         })
@@ -298,6 +302,12 @@ impl<'a> font_types::FontRead<'a> for Feature<'a> {
 }
 
 impl<'a> Feature<'a> {
+    /// Offset from start of Feature table to FeatureParams table, if
+    /// defined for the feature and present, else NULL
+    pub fn feature_params_offset(&self) -> Offset16 {
+        self.feature_params_offset.get()
+    }
+
     /// Number of LookupList indices for this feature
     pub fn lookup_index_count(&self) -> u16 {
         self.lookup_index_count.get()
@@ -359,10 +369,9 @@ impl<'a> font_types::OffsetHost<'a> for LookupList<'a> {
 /// [Lookup Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#lookup-table)
 pub struct Lookup<'a> {
     lookup_type: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
-    lookup_flag: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    lookup_flag: zerocopy::LayoutVerified<&'a [u8], BigEndian<LookupFlag>>,
     sub_table_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
     subtable_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
-    mark_filtering_set: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
     offset_bytes: &'a [u8],
 }
 
@@ -372,7 +381,7 @@ impl<'a> font_types::FontRead<'a> for Lookup<'a> {
         let (lookup_type, bytes) =
             zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
         let (lookup_flag, bytes) =
-            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+            zerocopy::LayoutVerified::<_, BigEndian<LookupFlag>>::new_unaligned_from_prefix(bytes)?;
         let (sub_table_count, bytes) =
             zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
         let __resolved_sub_table_count = sub_table_count.get();
@@ -381,15 +390,12 @@ impl<'a> font_types::FontRead<'a> for Lookup<'a> {
                 bytes,
                 __resolved_sub_table_count as usize,
             )?;
-        let (mark_filtering_set, bytes) =
-            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
         let _ = bytes;
         Some(Lookup {
             lookup_type,
             lookup_flag,
             sub_table_count,
             subtable_offsets,
-            mark_filtering_set,
             offset_bytes,
         })
     }
@@ -402,7 +408,7 @@ impl<'a> Lookup<'a> {
     }
 
     /// Lookup qualifiers
-    pub fn lookup_flag(&self) -> u16 {
+    pub fn lookup_flag(&self) -> LookupFlag {
         self.lookup_flag.get()
     }
 
@@ -416,13 +422,6 @@ impl<'a> Lookup<'a> {
     pub fn subtable_offsets(&self) -> &[BigEndian<Offset16>] {
         &self.subtable_offsets
     }
-
-    /// Index (base 0) into GDEF mark glyph sets structure. This field
-    /// is only present if the USE_MARK_FILTERING_SET lookup flag is
-    /// set.
-    pub fn mark_filtering_set(&self) -> u16 {
-        self.mark_filtering_set.get()
-    }
 }
 
 impl<'a> font_types::OffsetHost<'a> for Lookup<'a> {
@@ -431,6 +430,18 @@ impl<'a> font_types::OffsetHost<'a> for Lookup<'a> {
     }
 }
 
+bitflags::bitflags! { # [doc = " [Lookup Flags](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#lookupFlags)"] pub struct LookupFlag : u16 { # [doc = " This bit relates only to the correct processing of the cursive"] # [doc = " attachment lookup type (GPOS lookup type 3). When this bit is"] # [doc = " set, the last glyph in a given sequence to which the cursive"] # [doc = " attachment lookup is applied, will be positioned on the baseline."] const RIGHT_TO_LEFT = 0x0001 ; # [doc = " If set, skips over base glyphs"] const IGNORE_BASE_GLYPHS = 0x0002 ; # [doc = " If set, skips over ligatures"] const IGNORE_LIGATURES = 0x0004 ; # [doc = " If set, skips over all combining marks"] const IGNORE_MARKS = 0x0008 ; # [doc = " If set, indicates that the lookup table structure is followed by"] # [doc = " a MarkFilteringSet field. The layout engine skips over all mark"] # [doc = " glyphs not in the mark filtering set indicated."] const USE_MARK_FILTERING_SET = 0x0010 ; # [doc = " If not zero, skips over all marks of attachment type different"] # [doc = " from specified."] const MARK_ATTACHMENT_TYPE_MASK = 0xFF00 ; } }
+impl font_types::Scalar for LookupFlag {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
 /// [Coverage Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#coverage-format-1)
 pub struct CoverageFormat1<'a> {
     coverage_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
@@ -993,10 +1004,12 @@ impl<'a> font_types::OffsetHost<'a> for SequenceContextFormat2<'a> {
 pub struct ClassSequenceRuleSet<'a> {
     class_seq_rule_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
     class_seq_rule_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset16>]>,
+    offset_bytes: &'a [u8],
 }
 
 impl<'a> font_types::FontRead<'a> for ClassSequenceRuleSet<'a> {
     fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
         let (class_seq_rule_count, bytes) =
             zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
         let __resolved_class_seq_rule_count = class_seq_rule_count.get();
@@ -1009,6 +1022,7 @@ impl<'a> font_types::FontRead<'a> for ClassSequenceRuleSet<'a> {
         Some(ClassSequenceRuleSet {
             class_seq_rule_count,
             class_seq_rule_offsets,
+            offset_bytes,
         })
     }
 }
@@ -1026,6 +1040,12 @@ impl<'a> ClassSequenceRuleSet<'a> {
     }
 }
 
+impl<'a> font_types::OffsetHost<'a> for ClassSequenceRuleSet<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
 /// Part of [SequenceContextFormat2]
 pub struct ClassSequenceRule<'a> {
     glyph_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
@@ -1162,6 +1182,31 @@ impl<'a> font_types::OffsetHost<'a> for SequenceContextFormat3<'a> {
     }
 }
 
+/// [Sequence Context](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#sequence-context-format-1-simple-glyph-contexts)
+pub enum SequenceContext<'a> {
+    Format1(SequenceContextFormat1<'a>),
+    Format2(SequenceContextFormat2<'a>),
+    Format3(SequenceContextFormat3<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for SequenceContext<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            3 => Some(Self::Format3(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
 /// [Chained Sequence Context Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#chained-sequence-context-format-1-simple-glyph-contexts)
 pub struct ChainedSequenceContextFormat1<'a> {
     format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
@@ -1739,6 +1784,31 @@ impl<'a> font_types::OffsetHost<'a> for ChainedSequenceContextFormat3<'a> {
     }
 }
 
+/// [Chained Sequence Context](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#chained-sequence-context-format-1-simple-glyph-contexts)
+pub enum ChainedSequenceContext<'a> {
+    Format1(ChainedSequenceContextFormat1<'a>),
+    Format2(ChainedSequenceContextFormat2<'a>),
+    Format3(ChainedSequenceContextFormat3<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for ChainedSequenceContext<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u16> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            2 => Some(Self::Format2(font_types::FontRead::read(bytes)?)),
+            3 => Some(Self::Format3(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
 /// [Device Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#device-and-variationindex-tables)
 pub struct Device<'a> {
     start_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
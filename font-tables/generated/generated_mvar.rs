@@ -0,0 +1,114 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [MVAR (Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar) table
+pub struct Mvar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    #[allow(dead_code)]
+    reserved: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    value_record_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    value_record_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    item_variation_store_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Mvar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (reserved, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_record_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (value_record_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (item_variation_store_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Mvar {
+            major_version,
+            minor_version,
+            reserved,
+            value_record_size,
+            value_record_count,
+            item_variation_store_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Mvar<'a> {
+    /// Major version number of the metrics variations table — set to 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the metrics variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// The size in bytes of each value record — must be greater than
+    /// zero.
+    pub fn value_record_size(&self) -> u16 {
+        self.value_record_size.get()
+    }
+
+    /// The number of value records — may be zero.
+    pub fn value_record_count(&self) -> u16 {
+        self.value_record_count.get()
+    }
+
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table. This is zero if there are no value
+    /// records.
+    pub fn item_variation_store_offset(&self) -> Offset16 {
+        self.item_variation_store_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Mvar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [ValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar#table-formats) metrics variation record
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct ValueRecord {
+    /// Four-byte tag identifying a font-wide measure.
+    pub value_tag: BigEndian<Tag>,
+    /// A delta-set outer index — used to select an item variation
+    /// data subtable within the item variation store.
+    pub delta_set_outer_index: BigEndian<u16>,
+    /// A delta-set inner index — used to select a delta-set row
+    /// within an item variation data subtable.
+    pub delta_set_inner_index: BigEndian<u16>,
+}
+
+impl ValueRecord {
+    /// Four-byte tag identifying a font-wide measure.
+    pub fn value_tag(&self) -> Tag {
+        self.value_tag.get()
+    }
+
+    /// A delta-set outer index — used to select an item variation
+    /// data subtable within the item variation store.
+    pub fn delta_set_outer_index(&self) -> u16 {
+        self.delta_set_outer_index.get()
+    }
+
+    /// A delta-set inner index — used to select a delta-set row
+    /// within an item variation data subtable.
+    pub fn delta_set_inner_index(&self) -> u16 {
+        self.delta_set_inner_index.get()
+    }
+}
@@ -0,0 +1,335 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [OS/2 (OS/2 and Windows Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/os2) table
+///
+/// These are the fields common to all versions; later fields are
+/// accessed by hand.
+pub struct Os2<'a> {
+    version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    x_avg_char_width: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    us_weight_class: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    us_width_class: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    fs_type: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    y_subscript_x_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_subscript_y_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_subscript_x_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_subscript_y_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_superscript_x_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_superscript_y_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_superscript_x_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_superscript_y_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_strikeout_size: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    y_strikeout_position: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    s_family_class: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    panose_10: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u8>]>,
+    ul_unicode_range_1: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    ul_unicode_range_2: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    ul_unicode_range_3: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    ul_unicode_range_4: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    ach_vend_id: zerocopy::LayoutVerified<&'a [u8], BigEndian<Tag>>,
+    fs_selection: zerocopy::LayoutVerified<&'a [u8], BigEndian<SelectionFlags>>,
+    us_first_char_index: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    us_last_char_index: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    s_typo_ascender: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    s_typo_descender: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    s_typo_line_gap: zerocopy::LayoutVerified<&'a [u8], BigEndian<i16>>,
+    us_win_ascent: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    us_win_descent: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Os2<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (x_avg_char_width, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (us_weight_class, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (us_width_class, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (fs_type, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_subscript_x_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_subscript_y_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_subscript_x_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_subscript_y_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_superscript_x_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_superscript_y_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_superscript_x_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_superscript_y_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_strikeout_size, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (y_strikeout_position, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (s_family_class, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (panose_10, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u8>]>::new_slice_unaligned_from_prefix(
+                bytes, 10,
+            )?;
+        let (ul_unicode_range_1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (ul_unicode_range_2, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (ul_unicode_range_3, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (ul_unicode_range_4, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (ach_vend_id, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Tag>>::new_unaligned_from_prefix(bytes)?;
+        let (fs_selection, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<SelectionFlags>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let (us_first_char_index, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (us_last_char_index, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (s_typo_ascender, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (s_typo_descender, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (s_typo_line_gap, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<i16>>::new_unaligned_from_prefix(bytes)?;
+        let (us_win_ascent, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (us_win_descent, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Os2 {
+            version,
+            x_avg_char_width,
+            us_weight_class,
+            us_width_class,
+            fs_type,
+            y_subscript_x_size,
+            y_subscript_y_size,
+            y_subscript_x_offset,
+            y_subscript_y_offset,
+            y_superscript_x_size,
+            y_superscript_y_size,
+            y_superscript_x_offset,
+            y_superscript_y_offset,
+            y_strikeout_size,
+            y_strikeout_position,
+            s_family_class,
+            panose_10,
+            ul_unicode_range_1,
+            ul_unicode_range_2,
+            ul_unicode_range_3,
+            ul_unicode_range_4,
+            ach_vend_id,
+            fs_selection,
+            us_first_char_index,
+            us_last_char_index,
+            s_typo_ascender,
+            s_typo_descender,
+            s_typo_line_gap,
+            us_win_ascent,
+            us_win_descent,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Os2<'a> {
+    /// The version of this table.
+    pub fn version(&self) -> u16 {
+        self.version.get()
+    }
+
+    /// The average advance width of all non-zero width glyphs in the
+    /// font.
+    pub fn x_avg_char_width(&self) -> i16 {
+        self.x_avg_char_width.get()
+    }
+
+    /// Indicates the visual weight (degree of blackness or thickness
+    /// of strokes) of the characters in the font. Values from 1 to
+    /// 1000 are valid.
+    pub fn us_weight_class(&self) -> u16 {
+        self.us_weight_class.get()
+    }
+
+    /// Indicates a relative change from the normal aspect ratio
+    /// (width to height ratio) as specified by a font designer for the
+    /// glyphs in a font.
+    pub fn us_width_class(&self) -> u16 {
+        self.us_width_class.get()
+    }
+
+    /// Indicates font embedding licensing rights for the font.
+    pub fn fs_type(&self) -> u16 {
+        self.fs_type.get()
+    }
+
+    /// The recommended horizontal size in font design units for
+    /// subscripts for this font.
+    pub fn y_subscript_x_size(&self) -> i16 {
+        self.y_subscript_x_size.get()
+    }
+
+    /// The recommended vertical size in font design units for
+    /// subscripts for this font.
+    pub fn y_subscript_y_size(&self) -> i16 {
+        self.y_subscript_y_size.get()
+    }
+
+    /// The recommended horizontal offset in font design units for
+    /// subscripts for this font.
+    pub fn y_subscript_x_offset(&self) -> i16 {
+        self.y_subscript_x_offset.get()
+    }
+
+    /// The recommended vertical offset in font design units for
+    /// subscripts for this font.
+    pub fn y_subscript_y_offset(&self) -> i16 {
+        self.y_subscript_y_offset.get()
+    }
+
+    /// The recommended horizontal size in font design units for
+    /// superscripts for this font.
+    pub fn y_superscript_x_size(&self) -> i16 {
+        self.y_superscript_x_size.get()
+    }
+
+    /// The recommended vertical size in font design units for
+    /// superscripts for this font.
+    pub fn y_superscript_y_size(&self) -> i16 {
+        self.y_superscript_y_size.get()
+    }
+
+    /// The recommended horizontal offset in font design units for
+    /// superscripts for this font.
+    pub fn y_superscript_x_offset(&self) -> i16 {
+        self.y_superscript_x_offset.get()
+    }
+
+    /// The recommended vertical offset in font design units for
+    /// superscripts for this font.
+    pub fn y_superscript_y_offset(&self) -> i16 {
+        self.y_superscript_y_offset.get()
+    }
+
+    /// Thickness of the strikeout stroke in font design units.
+    pub fn y_strikeout_size(&self) -> i16 {
+        self.y_strikeout_size.get()
+    }
+
+    /// The position of the top of the strikeout stroke relative to the
+    /// baseline in font design units.
+    pub fn y_strikeout_position(&self) -> i16 {
+        self.y_strikeout_position.get()
+    }
+
+    /// This parameter is a classification of font-family design.
+    pub fn s_family_class(&self) -> i16 {
+        self.s_family_class.get()
+    }
+
+    /// This 10-byte series of numbers is used to describe the visual
+    /// characteristics of a given typeface.
+    pub fn panose_10(&self) -> &[BigEndian<u8>] {
+        &self.panose_10
+    }
+
+    /// Unicode Character Range (bits 0–31).
+    pub fn ul_unicode_range_1(&self) -> u32 {
+        self.ul_unicode_range_1.get()
+    }
+
+    /// Unicode Character Range (bits 32–63).
+    pub fn ul_unicode_range_2(&self) -> u32 {
+        self.ul_unicode_range_2.get()
+    }
+
+    /// Unicode Character Range (bits 64–95).
+    pub fn ul_unicode_range_3(&self) -> u32 {
+        self.ul_unicode_range_3.get()
+    }
+
+    /// Unicode Character Range (bits 96–127).
+    pub fn ul_unicode_range_4(&self) -> u32 {
+        self.ul_unicode_range_4.get()
+    }
+
+    /// The four-character identifier for the vendor of the given type
+    /// face.
+    pub fn ach_vend_id(&self) -> Tag {
+        self.ach_vend_id.get()
+    }
+
+    /// Contains information concerning the nature of the font patterns.
+    pub fn fs_selection(&self) -> SelectionFlags {
+        self.fs_selection.get()
+    }
+
+    /// The minimum Unicode index (character code) in this font.
+    pub fn us_first_char_index(&self) -> u16 {
+        self.us_first_char_index.get()
+    }
+
+    /// The maximum Unicode index (character code) in this font.
+    pub fn us_last_char_index(&self) -> u16 {
+        self.us_last_char_index.get()
+    }
+
+    /// The typographic ascender for this font.
+    pub fn s_typo_ascender(&self) -> i16 {
+        self.s_typo_ascender.get()
+    }
+
+    /// The typographic descender for this font.
+    pub fn s_typo_descender(&self) -> i16 {
+        self.s_typo_descender.get()
+    }
+
+    /// The typographic line gap for this font.
+    pub fn s_typo_line_gap(&self) -> i16 {
+        self.s_typo_line_gap.get()
+    }
+
+    /// The “Windows ascender” metric. This should be used to specify
+    /// the height above the baseline for a clipping region.
+    pub fn us_win_ascent(&self) -> u16 {
+        self.us_win_ascent.get()
+    }
+
+    /// The “Windows descender” metric. This should be used to specify
+    /// the vertical extent below the baseline for a clipping region.
+    pub fn us_win_descent(&self) -> u16 {
+        self.us_win_descent.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Os2<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+bitflags::bitflags! { # [doc = " The `fsSelection` field of the [Os2] table."] pub struct SelectionFlags : u16 { # [doc = " Font contains italic or oblique glyphs, otherwise they are"] # [doc = " upright."] const ITALIC = 0x0001 ; # [doc = " Glyphs are underscored."] const UNDERSCORE = 0x0002 ; # [doc = " Glyphs have their foreground and background reversed."] const NEGATIVE = 0x0004 ; # [doc = " Outline (hollow) glyphs, otherwise they are solid."] const OUTLINED = 0x0008 ; # [doc = " Glyphs are overstruck."] const STRIKEOUT = 0x0010 ; # [doc = " Glyphs are emboldened."] const BOLD = 0x0020 ; # [doc = " Glyphs are in the standard weight/style for the font."] const REGULAR = 0x0040 ; # [doc = " If set, it is strongly recommended that applications use"] # [doc = " OS/2.sTypoAscender - OS/2.sTypoDescender + OS/2.sTypoLineGap as"] # [doc = " the default line spacing for this font."] const USE_TYPO_METRICS = 0x0080 ; # [doc = " The font has 'name' table strings consistent with a"] # [doc = " weight/width/slope family without requiring use of name IDs 21"] # [doc = " and 22."] const WWS = 0x0100 ; # [doc = " Font contains oblique glyphs."] const OBLIQUE = 0x0200 ; } }
+impl font_types::Scalar for SelectionFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
@@ -249,11 +249,270 @@ impl<'a> Post2_0<'a> {
     }
 }
 
+/// [post (PostScript)](https://docs.microsoft.com/en-us/typography/opentype/spec/post#version-25) table, version 2.5 (deprecated)
+pub struct Post2_5<'a> {
+    version: zerocopy::LayoutVerified<&'a [u8], BigEndian<Version16Dot16>>,
+    italic_angle: zerocopy::LayoutVerified<&'a [u8], BigEndian<Fixed>>,
+    underline_position: zerocopy::LayoutVerified<&'a [u8], BigEndian<FWord>>,
+    underline_thickness: zerocopy::LayoutVerified<&'a [u8], BigEndian<FWord>>,
+    is_fixed_pitch: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    min_mem_type42: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    max_mem_type42: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    min_mem_type1: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    max_mem_type1: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    #[allow(dead_code)]
+    num_glyphs: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    offset: zerocopy::LayoutVerified<&'a [u8], [BigEndian<i8>]>,
+}
+
+impl<'a> font_types::FontRead<'a> for Post2_5<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Version16Dot16>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let (italic_angle, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Fixed>>::new_unaligned_from_prefix(bytes)?;
+        let (underline_position, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<FWord>>::new_unaligned_from_prefix(bytes)?;
+        let (underline_thickness, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<FWord>>::new_unaligned_from_prefix(bytes)?;
+        let (is_fixed_pitch, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (min_mem_type42, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (max_mem_type42, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (min_mem_type1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (max_mem_type1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (num_glyphs, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_num_glyphs = num_glyphs.get();
+        let (offset, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<i8>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_num_glyphs as usize,
+            )?;
+        let _ = bytes;
+        Some(Post2_5 {
+            version,
+            italic_angle,
+            underline_position,
+            underline_thickness,
+            is_fixed_pitch,
+            min_mem_type42,
+            max_mem_type42,
+            min_mem_type1,
+            max_mem_type1,
+            num_glyphs,
+            offset,
+        })
+    }
+}
+
+impl<'a> Post2_5<'a> {
+    /// 0x00010000 for version 1.0 0x00020000 for version 2.0
+    /// 0x00025000 for version 2.5 (deprecated) 0x00030000 for version
+    /// 3.0
+    pub fn version(&self) -> Version16Dot16 {
+        self.version.get()
+    }
+
+    /// Italic angle in counter-clockwise degrees from the vertical.
+    /// Zero for upright text, negative for text that leans to the
+    /// right (forward).
+    pub fn italic_angle(&self) -> Fixed {
+        self.italic_angle.get()
+    }
+
+    /// This is the suggested distance of the top of the underline from
+    /// the baseline (negative values indicate below baseline). The
+    /// PostScript definition of this FontInfo dictionary key (the y
+    /// coordinate of the center of the stroke) is not used for
+    /// historical reasons. The value of the PostScript key may be
+    /// calculated by subtracting half the underlineThickness from the
+    /// value of this field.
+    pub fn underline_position(&self) -> FWord {
+        self.underline_position.get()
+    }
+
+    /// Suggested values for the underline thickness. In general, the
+    /// underline thickness should match the thickness of the
+    /// underscore character (U+005F LOW LINE), and should also match
+    /// the strikeout thickness, which is specified in the OS/2 table.
+    pub fn underline_thickness(&self) -> FWord {
+        self.underline_thickness.get()
+    }
+
+    /// Set to 0 if the font is proportionally spaced, non-zero if the
+    /// font is not proportionally spaced (i.e. monospaced).
+    pub fn is_fixed_pitch(&self) -> u32 {
+        self.is_fixed_pitch.get()
+    }
+
+    /// Minimum memory usage when an OpenType font is downloaded.
+    pub fn min_mem_type42(&self) -> u32 {
+        self.min_mem_type42.get()
+    }
+
+    /// Maximum memory usage when an OpenType font is downloaded.
+    pub fn max_mem_type42(&self) -> u32 {
+        self.max_mem_type42.get()
+    }
+
+    /// Minimum memory usage when an OpenType font is downloaded as a
+    /// Type 1 font.
+    pub fn min_mem_type1(&self) -> u32 {
+        self.min_mem_type1.get()
+    }
+
+    /// Maximum memory usage when an OpenType font is downloaded as a
+    /// Type 1 font.
+    pub fn max_mem_type1(&self) -> u32 {
+        self.max_mem_type1.get()
+    }
+
+    /// Difference between the graphic index and the standard order of
+    /// the glyph.
+    pub fn offset(&self) -> &[BigEndian<i8>] {
+        &self.offset
+    }
+}
+
+/// [post (PostScript)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html) table, Apple version 4.0
+pub struct Post4_0<'a> {
+    version: zerocopy::LayoutVerified<&'a [u8], BigEndian<Version16Dot16>>,
+    italic_angle: zerocopy::LayoutVerified<&'a [u8], BigEndian<Fixed>>,
+    underline_position: zerocopy::LayoutVerified<&'a [u8], BigEndian<FWord>>,
+    underline_thickness: zerocopy::LayoutVerified<&'a [u8], BigEndian<FWord>>,
+    is_fixed_pitch: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    min_mem_type42: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    max_mem_type42: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    min_mem_type1: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    max_mem_type1: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    char_codes: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+}
+
+impl<'a> font_types::FontRead<'a> for Post4_0<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Version16Dot16>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let (italic_angle, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Fixed>>::new_unaligned_from_prefix(bytes)?;
+        let (underline_position, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<FWord>>::new_unaligned_from_prefix(bytes)?;
+        let (underline_thickness, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<FWord>>::new_unaligned_from_prefix(bytes)?;
+        let (is_fixed_pitch, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (min_mem_type42, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (max_mem_type42, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (min_mem_type1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (max_mem_type1, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let (char_codes, bytes) = (
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned(bytes)?,
+            0,
+        );
+        let _ = bytes;
+        Some(Post4_0 {
+            version,
+            italic_angle,
+            underline_position,
+            underline_thickness,
+            is_fixed_pitch,
+            min_mem_type42,
+            max_mem_type42,
+            min_mem_type1,
+            max_mem_type1,
+            char_codes,
+        })
+    }
+}
+
+impl<'a> Post4_0<'a> {
+    /// 0x00010000 for version 1.0 0x00020000 for version 2.0
+    /// 0x00025000 for version 2.5 (deprecated) 0x00030000 for version
+    /// 3.0
+    pub fn version(&self) -> Version16Dot16 {
+        self.version.get()
+    }
+
+    /// Italic angle in counter-clockwise degrees from the vertical.
+    /// Zero for upright text, negative for text that leans to the
+    /// right (forward).
+    pub fn italic_angle(&self) -> Fixed {
+        self.italic_angle.get()
+    }
+
+    /// This is the suggested distance of the top of the underline from
+    /// the baseline (negative values indicate below baseline). The
+    /// PostScript definition of this FontInfo dictionary key (the y
+    /// coordinate of the center of the stroke) is not used for
+    /// historical reasons. The value of the PostScript key may be
+    /// calculated by subtracting half the underlineThickness from the
+    /// value of this field.
+    pub fn underline_position(&self) -> FWord {
+        self.underline_position.get()
+    }
+
+    /// Suggested values for the underline thickness. In general, the
+    /// underline thickness should match the thickness of the
+    /// underscore character (U+005F LOW LINE), and should also match
+    /// the strikeout thickness, which is specified in the OS/2 table.
+    pub fn underline_thickness(&self) -> FWord {
+        self.underline_thickness.get()
+    }
+
+    /// Set to 0 if the font is proportionally spaced, non-zero if the
+    /// font is not proportionally spaced (i.e. monospaced).
+    pub fn is_fixed_pitch(&self) -> u32 {
+        self.is_fixed_pitch.get()
+    }
+
+    /// Minimum memory usage when an OpenType font is downloaded.
+    pub fn min_mem_type42(&self) -> u32 {
+        self.min_mem_type42.get()
+    }
+
+    /// Maximum memory usage when an OpenType font is downloaded.
+    pub fn max_mem_type42(&self) -> u32 {
+        self.max_mem_type42.get()
+    }
+
+    /// Minimum memory usage when an OpenType font is downloaded as a
+    /// Type 1 font.
+    pub fn min_mem_type1(&self) -> u32 {
+        self.min_mem_type1.get()
+    }
+
+    /// Maximum memory usage when an OpenType font is downloaded as a
+    /// Type 1 font.
+    pub fn max_mem_type1(&self) -> u32 {
+        self.max_mem_type1.get()
+    }
+
+    /// The character code of each glyph, or 0xFFFF if the glyph has no
+    /// character code. There is no count field; the array extends to the
+    /// end of the table, and should have one entry per glyph.
+    pub fn char_codes(&self) -> &[BigEndian<u16>] {
+        &self.char_codes
+    }
+}
+
 pub enum Post<'a> {
     Post1_0(Post1_0),
     Post2_0(Post2_0<'a>),
-    Post2_5(Post1_0),
+    Post2_5(Post2_5<'a>),
     Post3_0(Post1_0),
+    Post4_0(Post4_0<'a>),
 }
 
 impl<'a> font_types::FontRead<'a> for Post<'a> {
@@ -262,12 +521,14 @@ impl<'a> font_types::FontRead<'a> for Post<'a> {
         const _: Version16Dot16 = Version16Dot16::VERSION_2_0;
         const _: Version16Dot16 = Version16Dot16::VERSION_2_5;
         const _: Version16Dot16 = Version16Dot16::VERSION_3_0;
+        const _: Version16Dot16 = Version16Dot16::VERSION_4_0;
         let version: BigEndian<Version16Dot16> = font_types::FontRead::read(bytes)?;
         match version.get() {
             Version16Dot16::VERSION_1_0 => Some(Self::Post1_0(font_types::FontRead::read(bytes)?)),
             Version16Dot16::VERSION_2_0 => Some(Self::Post2_0(font_types::FontRead::read(bytes)?)),
             Version16Dot16::VERSION_2_5 => Some(Self::Post2_5(font_types::FontRead::read(bytes)?)),
             Version16Dot16::VERSION_3_0 => Some(Self::Post3_0(font_types::FontRead::read(bytes)?)),
+            Version16Dot16::VERSION_4_0 => Some(Self::Post4_0(font_types::FontRead::read(bytes)?)),
             _other => {
                 #[cfg(feature = "std")]
                 {
@@ -280,6 +541,19 @@ impl<'a> font_types::FontRead<'a> for Post<'a> {
 }
 
 impl<'a> Post<'a> {
+    /// The character code of each glyph, or 0xFFFF if the glyph has no
+    /// character code. There is no count field; the array extends to the
+    /// end of the table, and should have one entry per glyph.
+    pub fn char_codes(&self) -> Option<&[BigEndian<u16>]> {
+        match self {
+            Self::Post1_0(_inner) => None,
+            Self::Post2_0(_inner) => None,
+            Self::Post2_5(_inner) => None,
+            Self::Post3_0(_inner) => None,
+            Self::Post4_0(_inner) => Some(_inner.char_codes()),
+        }
+    }
+
     /// Array of indices into the string data. See below for details.
     pub fn glyph_name_index(&self) -> Option<&[BigEndian<u16>]> {
         match self {
@@ -287,6 +561,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => Some(_inner.glyph_name_index()),
             Self::Post2_5(_inner) => None,
             Self::Post3_0(_inner) => None,
+            Self::Post4_0(_inner) => None,
         }
     }
 
@@ -298,6 +573,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.is_fixed_pitch(),
             Self::Post2_5(_inner) => _inner.is_fixed_pitch(),
             Self::Post3_0(_inner) => _inner.is_fixed_pitch(),
+            Self::Post4_0(_inner) => _inner.is_fixed_pitch(),
         }
     }
 
@@ -310,6 +586,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.italic_angle(),
             Self::Post2_5(_inner) => _inner.italic_angle(),
             Self::Post3_0(_inner) => _inner.italic_angle(),
+            Self::Post4_0(_inner) => _inner.italic_angle(),
         }
     }
 
@@ -321,6 +598,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.max_mem_type1(),
             Self::Post2_5(_inner) => _inner.max_mem_type1(),
             Self::Post3_0(_inner) => _inner.max_mem_type1(),
+            Self::Post4_0(_inner) => _inner.max_mem_type1(),
         }
     }
 
@@ -331,6 +609,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.max_mem_type42(),
             Self::Post2_5(_inner) => _inner.max_mem_type42(),
             Self::Post3_0(_inner) => _inner.max_mem_type42(),
+            Self::Post4_0(_inner) => _inner.max_mem_type42(),
         }
     }
 
@@ -342,6 +621,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.min_mem_type1(),
             Self::Post2_5(_inner) => _inner.min_mem_type1(),
             Self::Post3_0(_inner) => _inner.min_mem_type1(),
+            Self::Post4_0(_inner) => _inner.min_mem_type1(),
         }
     }
 
@@ -352,6 +632,19 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.min_mem_type42(),
             Self::Post2_5(_inner) => _inner.min_mem_type42(),
             Self::Post3_0(_inner) => _inner.min_mem_type42(),
+            Self::Post4_0(_inner) => _inner.min_mem_type42(),
+        }
+    }
+
+    /// Difference between the graphic index and the standard order of
+    /// the glyph.
+    pub fn offset(&self) -> Option<&[BigEndian<i8>]> {
+        match self {
+            Self::Post1_0(_inner) => None,
+            Self::Post2_0(_inner) => None,
+            Self::Post2_5(_inner) => Some(_inner.offset()),
+            Self::Post3_0(_inner) => None,
+            Self::Post4_0(_inner) => None,
         }
     }
 
@@ -362,6 +655,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => Some(_inner.string_data()),
             Self::Post2_5(_inner) => None,
             Self::Post3_0(_inner) => None,
+            Self::Post4_0(_inner) => None,
         }
     }
 
@@ -378,6 +672,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.underline_position(),
             Self::Post2_5(_inner) => _inner.underline_position(),
             Self::Post3_0(_inner) => _inner.underline_position(),
+            Self::Post4_0(_inner) => _inner.underline_position(),
         }
     }
 
@@ -391,6 +686,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.underline_thickness(),
             Self::Post2_5(_inner) => _inner.underline_thickness(),
             Self::Post3_0(_inner) => _inner.underline_thickness(),
+            Self::Post4_0(_inner) => _inner.underline_thickness(),
         }
     }
 
@@ -403,6 +699,7 @@ impl<'a> Post<'a> {
             Self::Post2_0(_inner) => _inner.version(),
             Self::Post2_5(_inner) => _inner.version(),
             Self::Post3_0(_inner) => _inner.version(),
+            Self::Post4_0(_inner) => _inner.version(),
         }
     }
 }
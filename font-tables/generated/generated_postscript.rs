@@ -0,0 +1,49 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// A range in a format 3 [FDSelect](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect-format-3)
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct FdSelectRange3 {
+    /// First glyph index in range
+    pub first: BigEndian<u16>,
+    /// FD index for all glyphs in range
+    pub fd: BigEndian<u8>,
+}
+
+impl FdSelectRange3 {
+    /// First glyph index in range
+    pub fn first(&self) -> u16 {
+        self.first.get()
+    }
+
+    /// FD index for all glyphs in range
+    pub fn fd(&self) -> u8 {
+        self.fd.get()
+    }
+}
+
+/// A range in a format 4 [FDSelect](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect-format-4)
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct FdSelectRange4 {
+    /// First glyph index in range
+    pub first: BigEndian<u32>,
+    /// FD index for all glyphs in range
+    pub fd: BigEndian<u16>,
+}
+
+impl FdSelectRange4 {
+    /// First glyph index in range
+    pub fn first(&self) -> u32 {
+        self.first.get()
+    }
+
+    /// FD index for all glyphs in range
+    pub fn fd(&self) -> u16 {
+        self.fd.get()
+    }
+}
@@ -0,0 +1,379 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// [Item Variation Store](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+pub struct ItemVariationStore<'a> {
+    format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    variation_region_list_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    item_variation_data_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    item_variation_data_offsets: zerocopy::LayoutVerified<&'a [u8], [BigEndian<Offset32>]>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for ItemVariationStore<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (variation_region_list_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (item_variation_data_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_item_variation_data_count = item_variation_data_count.get();
+        let (item_variation_data_offsets, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<Offset32>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_item_variation_data_count as usize,
+            )?;
+        let _ = bytes;
+        Some(ItemVariationStore {
+            format,
+            variation_region_list_offset,
+            item_variation_data_count,
+            item_variation_data_offsets,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> ItemVariationStore<'a> {
+    /// Format — set to 1
+    pub fn format(&self) -> u16 {
+        self.format.get()
+    }
+
+    /// Offset in bytes from the start of the item variation store to
+    /// the variation region list.
+    pub fn variation_region_list_offset(&self) -> Offset32 {
+        self.variation_region_list_offset.get()
+    }
+
+    /// The number of item variation data subtables.
+    pub fn item_variation_data_count(&self) -> u16 {
+        self.item_variation_data_count.get()
+    }
+
+    /// Offsets in bytes from the start of the item variation store to
+    /// each item variation data subtable.
+    pub fn item_variation_data_offsets(&self) -> &[BigEndian<Offset32>] {
+        &self.item_variation_data_offsets
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for ItemVariationStore<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
+
+/// [Variation Region List](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions)
+pub struct VariationRegionList<'a> {
+    axis_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    region_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    variation_regions: zerocopy::LayoutVerified<&'a [u8], [RegionAxisCoordinates]>,
+}
+
+impl<'a> font_types::FontRead<'a> for VariationRegionList<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (axis_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_axis_count = axis_count.get();
+        let (region_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_region_count = region_count.get();
+        let (variation_regions , bytes) = zerocopy :: LayoutVerified :: < _ , [RegionAxisCoordinates] > :: new_slice_unaligned_from_prefix (bytes , regions_len (__resolved_axis_count , __resolved_region_count)) ? ;
+        let _ = bytes;
+        Some(VariationRegionList {
+            axis_count,
+            region_count,
+            variation_regions,
+        })
+    }
+}
+
+impl<'a> VariationRegionList<'a> {
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count.get()
+    }
+
+    /// The number of variation region tables in the variation region
+    /// list. Must be less than 32,768.
+    pub fn region_count(&self) -> u16 {
+        self.region_count.get()
+    }
+
+    /// Array of variation regions, each with one RegionAxisCoordinates
+    /// record per axis.
+    pub fn variation_regions(&self) -> &[RegionAxisCoordinates] {
+        &self.variation_regions
+    }
+}
+
+/// Part of a [VariationRegionList]
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    pub start_coord: BigEndian<F2Dot14>,
+    /// The region peak coordinate value for the current axis.
+    pub peak_coord: BigEndian<F2Dot14>,
+    /// The region end coordinate value for the current axis.
+    pub end_coord: BigEndian<F2Dot14>,
+}
+
+impl RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    pub fn start_coord(&self) -> F2Dot14 {
+        self.start_coord.get()
+    }
+
+    /// The region peak coordinate value for the current axis.
+    pub fn peak_coord(&self) -> F2Dot14 {
+        self.peak_coord.get()
+    }
+
+    /// The region end coordinate value for the current axis.
+    pub fn end_coord(&self) -> F2Dot14 {
+        self.end_coord.get()
+    }
+}
+
+/// [Item Variation Data](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+pub struct ItemVariationData<'a> {
+    item_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    word_delta_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<DeltaSetFormat>>,
+    region_index_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    region_indexes: zerocopy::LayoutVerified<&'a [u8], [BigEndian<u16>]>,
+    delta_sets: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+}
+
+impl<'a> font_types::FontRead<'a> for ItemVariationData<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (item_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_item_count = item_count.get();
+        let (word_delta_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<DeltaSetFormat>>::new_unaligned_from_prefix(
+                bytes,
+            )?;
+        let __resolved_word_delta_count = word_delta_count.get();
+        let (region_index_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_region_index_count = region_index_count.get();
+        let (region_indexes, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_region_index_count as usize,
+            )?;
+        let (delta_sets, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                delta_sets_len(
+                    __resolved_item_count,
+                    __resolved_word_delta_count,
+                    __resolved_region_index_count,
+                ),
+            )?;
+        let _ = bytes;
+        Some(ItemVariationData {
+            item_count,
+            word_delta_count,
+            region_index_count,
+            region_indexes,
+            delta_sets,
+        })
+    }
+}
+
+impl<'a> ItemVariationData<'a> {
+    /// The number of delta sets for distinct items.
+    pub fn item_count(&self) -> u16 {
+        self.item_count.get()
+    }
+
+    /// A packed field: the high bit is a flag; see
+    /// [`DeltaSetFormat`] for details.
+    pub fn word_delta_count(&self) -> DeltaSetFormat {
+        self.word_delta_count.get()
+    }
+
+    /// The number of variation regions referenced.
+    pub fn region_index_count(&self) -> u16 {
+        self.region_index_count.get()
+    }
+
+    /// Array of indices into the variation region list for the regions
+    /// referenced by this item variation data table.
+    pub fn region_indexes(&self) -> &[BigEndian<u16>] {
+        &self.region_indexes
+    }
+
+    /// Delta-set rows.
+    pub fn delta_sets(&self) -> &[u8] {
+        &self.delta_sets
+    }
+}
+
+bitflags::bitflags! { # [doc = " The `wordDeltaCount` field of an [ItemVariationData] table."] pub struct DeltaSetFormat : u16 { # [doc = " Flag indicating that “word” deltas are long (int32)"] const LONG_WORDS = 0x8000 ; # [doc = " Count of “word” deltas"] const WORD_DELTA_COUNT_MASK = 0x7FFF ; } }
+impl font_types::Scalar for DeltaSetFormat {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data)
+pub enum DeltaSetIndexMap<'a> {
+    Format0(DeltaSetIndexMapFormat0<'a>),
+    Format1(DeltaSetIndexMapFormat1<'a>),
+}
+
+impl<'a> font_types::FontRead<'a> for DeltaSetIndexMap<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let version: BigEndian<u8> = font_types::FontRead::read(bytes)?;
+        match version.get() {
+            0 => Some(Self::Format0(font_types::FontRead::read(bytes)?)),
+            1 => Some(Self::Format1(font_types::FontRead::read(bytes)?)),
+            _other => {
+                #[cfg(feature = "std")]
+                {
+                    eprintln!("unknown enum variant {:?}", version);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) format 0
+pub struct DeltaSetIndexMapFormat0<'a> {
+    format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    entry_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    map_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    map_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+}
+
+impl<'a> font_types::FontRead<'a> for DeltaSetIndexMapFormat0<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (entry_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_entry_format = entry_format.get();
+        let (map_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_map_count = map_count.get();
+        let (map_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                map_data_len(__resolved_entry_format, __resolved_map_count),
+            )?;
+        let _ = bytes;
+        Some(DeltaSetIndexMapFormat0 {
+            format,
+            entry_format,
+            map_count,
+            map_data,
+        })
+    }
+}
+
+impl<'a> DeltaSetIndexMapFormat0<'a> {
+    /// DeltaSetIndexMap format: set to 0.
+    pub fn format(&self) -> u8 {
+        self.format.get()
+    }
+
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    pub fn entry_format(&self) -> u8 {
+        self.entry_format.get()
+    }
+
+    /// The number of mapping entries.
+    pub fn map_count(&self) -> u16 {
+        self.map_count.get()
+    }
+
+    /// The delta-set index mapping data.
+    pub fn map_data(&self) -> &[u8] {
+        &self.map_data
+    }
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) format 1
+pub struct DeltaSetIndexMapFormat1<'a> {
+    format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    entry_format: zerocopy::LayoutVerified<&'a [u8], BigEndian<u8>>,
+    map_count: zerocopy::LayoutVerified<&'a [u8], BigEndian<u32>>,
+    map_data: zerocopy::LayoutVerified<&'a [u8], [u8]>,
+}
+
+impl<'a> font_types::FontRead<'a> for DeltaSetIndexMapFormat1<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let (format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let (entry_format, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u8>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_entry_format = entry_format.get();
+        let (map_count, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u32>>::new_unaligned_from_prefix(bytes)?;
+        let __resolved_map_count = map_count.get();
+        let (map_data, bytes) =
+            zerocopy::LayoutVerified::<_, [u8]>::new_slice_unaligned_from_prefix(
+                bytes,
+                map_data_len(__resolved_entry_format, __resolved_map_count),
+            )?;
+        let _ = bytes;
+        Some(DeltaSetIndexMapFormat1 {
+            format,
+            entry_format,
+            map_count,
+            map_data,
+        })
+    }
+}
+
+impl<'a> DeltaSetIndexMapFormat1<'a> {
+    /// DeltaSetIndexMap format: set to 1.
+    pub fn format(&self) -> u8 {
+        self.format.get()
+    }
+
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    pub fn entry_format(&self) -> u8 {
+        self.entry_format.get()
+    }
+
+    /// The number of mapping entries.
+    pub fn map_count(&self) -> u32 {
+        self.map_count.get()
+    }
+
+    /// The delta-set index mapping data.
+    pub fn map_data(&self) -> &[u8] {
+        &self.map_data
+    }
+}
+
+fn regions_len(axis_count: u16, region_count: u16) -> usize {
+    axis_count as usize * region_count as usize
+}
+
+fn delta_sets_len(item_count: u16, format: DeltaSetFormat, region_index_count: u16) -> usize {
+    item_count as usize * format.row_byte_len(region_index_count)
+}
+
+fn map_data_len(entry_format: u8, map_count: impl Into<u32>) -> usize {
+    crate::variations::map_entry_size(entry_format) * map_count.into() as usize
+}
@@ -0,0 +1,130 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// [vhea](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea) Vertical Header Table
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct Vhea {
+    /// Version number of the vertical header table; 0x00010000 or
+    /// 0x00011000.
+    pub version: BigEndian<Version16Dot16>,
+    /// Typographic ascent: the distance from the vertical centerline to
+    /// the previous line's descent.
+    pub ascender: BigEndian<FWord>,
+    /// Typographic descent: the distance from the vertical centerline to
+    /// the next line's ascent.
+    pub descender: BigEndian<FWord>,
+    /// Typographic line gap. Set to 0 in version 1.1.
+    pub line_gap: BigEndian<FWord>,
+    /// Maximum advance height value in 'vmtx' table.
+    pub advance_height_max: BigEndian<UfWord>,
+    /// Minimum top sidebearing value in 'vmtx' table for glyphs with
+    /// contours (empty glyphs should be ignored).
+    pub min_top_side_bearing: BigEndian<FWord>,
+    /// Minimum bottom sidebearing value; calculated as min(ah - (tsb +
+    /// yMax - yMin)) for glyphs with contours (empty glyphs should be
+    /// ignored).
+    pub min_bottom_side_bearing: BigEndian<FWord>,
+    /// Max(tsb + (yMax - yMin)).
+    pub y_max_extent: BigEndian<FWord>,
+    /// Used to calculate the slope of the cursor (rise/run); 0 for
+    /// vertical.
+    pub caret_slope_rise: BigEndian<i16>,
+    /// 1 for vertical.
+    pub caret_slope_run: BigEndian<i16>,
+    /// The amount by which a slanted highlight on a glyph needs to be
+    /// shifted to produce the best appearance. Set to 0 for
+    /// non-slanted fonts
+    pub caret_offset: BigEndian<i16>,
+    /// set to 0
+    pub reserved1: BigEndian<i16>,
+    /// set to 0
+    pub reserved2: BigEndian<i16>,
+    /// set to 0
+    pub reserved3: BigEndian<i16>,
+    /// set to 0
+    pub reserved4: BigEndian<i16>,
+    /// 0 for current format.
+    pub metric_data_format: BigEndian<i16>,
+    /// Number of advance heights in the 'vmtx' table
+    pub number_of_long_ver_metrics: BigEndian<u16>,
+}
+
+impl Vhea {
+    /// Version number of the vertical header table; 0x00010000 or
+    /// 0x00011000.
+    pub fn version(&self) -> Version16Dot16 {
+        self.version.get()
+    }
+
+    /// Typographic ascent: the distance from the vertical centerline to
+    /// the previous line's descent.
+    pub fn ascender(&self) -> FWord {
+        self.ascender.get()
+    }
+
+    /// Typographic descent: the distance from the vertical centerline to
+    /// the next line's ascent.
+    pub fn descender(&self) -> FWord {
+        self.descender.get()
+    }
+
+    /// Typographic line gap. Set to 0 in version 1.1.
+    pub fn line_gap(&self) -> FWord {
+        self.line_gap.get()
+    }
+
+    /// Maximum advance height value in 'vmtx' table.
+    pub fn advance_height_max(&self) -> UfWord {
+        self.advance_height_max.get()
+    }
+
+    /// Minimum top sidebearing value in 'vmtx' table for glyphs with
+    /// contours (empty glyphs should be ignored).
+    pub fn min_top_side_bearing(&self) -> FWord {
+        self.min_top_side_bearing.get()
+    }
+
+    /// Minimum bottom sidebearing value; calculated as min(ah - (tsb +
+    /// yMax - yMin)) for glyphs with contours (empty glyphs should be
+    /// ignored).
+    pub fn min_bottom_side_bearing(&self) -> FWord {
+        self.min_bottom_side_bearing.get()
+    }
+
+    /// Max(tsb + (yMax - yMin)).
+    pub fn y_max_extent(&self) -> FWord {
+        self.y_max_extent.get()
+    }
+
+    /// Used to calculate the slope of the cursor (rise/run); 0 for
+    /// vertical.
+    pub fn caret_slope_rise(&self) -> i16 {
+        self.caret_slope_rise.get()
+    }
+
+    /// 1 for vertical.
+    pub fn caret_slope_run(&self) -> i16 {
+        self.caret_slope_run.get()
+    }
+
+    /// The amount by which a slanted highlight on a glyph needs to be
+    /// shifted to produce the best appearance. Set to 0 for
+    /// non-slanted fonts
+    pub fn caret_offset(&self) -> i16 {
+        self.caret_offset.get()
+    }
+
+    /// 0 for current format.
+    pub fn metric_data_format(&self) -> i16 {
+        self.metric_data_format.get()
+    }
+
+    /// Number of advance heights in the 'vmtx' table
+    pub fn number_of_long_ver_metrics(&self) -> u16 {
+        self.number_of_long_ver_metrics.get()
+    }
+}
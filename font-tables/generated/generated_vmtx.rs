@@ -0,0 +1,79 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+pub struct Vmtx<'a> {
+    v_metrics: zerocopy::LayoutVerified<&'a [u8], [longVerMetric]>,
+    top_side_bearings: zerocopy::LayoutVerified<&'a [u8], [BigEndian<i16>]>,
+}
+
+impl<'a> Vmtx<'a> {
+    pub fn read(
+        bytes: &'a [u8],
+        number_of_long_ver_metrics: usize,
+        num_glyphs: usize,
+    ) -> Option<Self> {
+        let __resolved_number_of_long_ver_metrics = number_of_long_ver_metrics;
+        let __resolved_num_glyphs = num_glyphs;
+        let (v_metrics, bytes) =
+            zerocopy::LayoutVerified::<_, [longVerMetric]>::new_slice_unaligned_from_prefix(
+                bytes,
+                __resolved_number_of_long_ver_metrics as usize,
+            )?;
+        let (top_side_bearings, bytes) =
+            zerocopy::LayoutVerified::<_, [BigEndian<i16>]>::new_slice_unaligned_from_prefix(
+                bytes,
+                n_glyphs_less_n_metrics(
+                    __resolved_num_glyphs,
+                    __resolved_number_of_long_ver_metrics,
+                ),
+            )?;
+        let _ = bytes;
+        Some(Vmtx {
+            v_metrics,
+            top_side_bearings,
+        })
+    }
+}
+
+impl<'a> Vmtx<'a> {
+    /// Paired advance height and top side bearing values for each
+    /// glyph. Records are indexed by glyph ID.
+    pub fn v_metrics(&self) -> &[longVerMetric] {
+        &self.v_metrics
+    }
+
+    /// Top side bearings for glyph IDs greater than or equal to
+    /// numOfLongVerMetrics.
+    pub fn top_side_bearings(&self) -> &[BigEndian<i16>] {
+        &self.top_side_bearings
+    }
+}
+
+#[derive(Clone, Copy, Debug, zerocopy :: FromBytes, zerocopy :: Unaligned)]
+#[repr(C)]
+pub struct longVerMetric {
+    /// Advance height, in font design units.
+    pub advance_height: BigEndian<u16>,
+    /// Glyph top side bearing, in font design units.
+    pub tsb: BigEndian<i16>,
+}
+
+impl longVerMetric {
+    /// Advance height, in font design units.
+    pub fn advance_height(&self) -> u16 {
+        self.advance_height.get()
+    }
+
+    /// Glyph top side bearing, in font design units.
+    pub fn tsb(&self) -> i16 {
+        self.tsb.get()
+    }
+}
+
+fn n_glyphs_less_n_metrics(num_glyphs: usize, num_metrics: usize) -> usize {
+    num_glyphs.saturating_sub(num_metrics)
+}
@@ -0,0 +1,97 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+use font_types::*;
+
+/// The [VVAR (Vertical Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
+pub struct Vvar<'a> {
+    major_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    minor_version: zerocopy::LayoutVerified<&'a [u8], BigEndian<u16>>,
+    item_variation_store_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    advance_height_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    tsb_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    bsb_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    v_org_mapping_offset: zerocopy::LayoutVerified<&'a [u8], BigEndian<Offset32>>,
+    offset_bytes: &'a [u8],
+}
+
+impl<'a> font_types::FontRead<'a> for Vvar<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        let offset_bytes = bytes;
+        let (major_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (minor_version, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<u16>>::new_unaligned_from_prefix(bytes)?;
+        let (item_variation_store_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (advance_height_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (tsb_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (bsb_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let (v_org_mapping_offset, bytes) =
+            zerocopy::LayoutVerified::<_, BigEndian<Offset32>>::new_unaligned_from_prefix(bytes)?;
+        let _ = bytes;
+        Some(Vvar {
+            major_version,
+            minor_version,
+            item_variation_store_offset,
+            advance_height_mapping_offset,
+            tsb_mapping_offset,
+            bsb_mapping_offset,
+            v_org_mapping_offset,
+            offset_bytes,
+        })
+    }
+}
+
+impl<'a> Vvar<'a> {
+    /// Major version number of the vertical metrics variations table — set to 1.
+    pub fn major_version(&self) -> u16 {
+        self.major_version.get()
+    }
+
+    /// Minor version number of the vertical metrics variations table — set to 0.
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version.get()
+    }
+
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table.
+    pub fn item_variation_store_offset(&self) -> Offset32 {
+        self.item_variation_store_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for advance heights (may be NULL).
+    pub fn advance_height_mapping_offset(&self) -> Offset32 {
+        self.advance_height_mapping_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for top side bearings (may be NULL).
+    pub fn tsb_mapping_offset(&self) -> Offset32 {
+        self.tsb_mapping_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for bottom side bearings (may be NULL).
+    pub fn bsb_mapping_offset(&self) -> Offset32 {
+        self.bsb_mapping_offset.get()
+    }
+
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for Y coordinates of vertical origins (may be
+    /// NULL).
+    pub fn v_org_mapping_offset(&self) -> Offset32 {
+        self.v_org_mapping_offset.get()
+    }
+}
+
+impl<'a> font_types::OffsetHost<'a> for Vvar<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        self.offset_bytes
+    }
+}
@@ -0,0 +1,195 @@
+//! Resolving names for glyphs.
+//!
+//! Glyph names are optional: a `post` table of version 3.0 carries no names
+//! at all. [`GlyphNames`] uses the `post` table where it can, and otherwise
+//! synthesizes a name from the glyph's unicode mapping or its glyph id.
+
+#[cfg(feature = "aglfn")]
+pub mod aglfn;
+
+use crate::tables::{
+    cmap::Cmap,
+    post::{Post, PostNames},
+};
+
+/// The name of a glyph, along with its source.
+///
+/// The `Display` impl of this type writes out the actual name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphName<'a> {
+    /// A name from the `post` table.
+    Post(&'a str),
+    /// A name from the Adobe Glyph List For New Fonts (AGLFN).
+    Agl(&'static str),
+    /// A name synthesized from a codepoint, in the form `uniXXXX` or `uXXXXX`.
+    Unicode(u32),
+    /// A name synthesized from the glyph id, in the form `glyphXXX`.
+    Index(u16),
+}
+
+/// A resolver that can produce a name for every glyph in a font.
+///
+/// Names are looked up in the following order:
+///
+/// - the `post` table, if it has a name for the glyph
+/// - if the `aglfn` feature is enabled, the AGLFN name of the glyph's codepoint
+/// - a `uniXXXX` or `uXXXXX` name, if the glyph is mapped in the `cmap`
+/// - `glyphXXX`, where `XXX` is the glyph id
+pub struct GlyphNames<'a> {
+    post: Option<PostNames<'a>>,
+    codepoints: Vec<Option<u32>>,
+}
+
+impl<'a> GlyphNames<'a> {
+    /// Create a new resolver.
+    ///
+    /// `num_glyphs` should be read from the `maxp` table.
+    pub fn new(post: Option<&'a Post>, cmap: Option<&Cmap>, num_glyphs: u16) -> Self {
+        let mut codepoints = vec![None; num_glyphs as usize];
+        if let Some(subtable) = cmap.and_then(Cmap::best_unicode_subtable) {
+            for (codepoint, gid) in subtable.iter_mappings() {
+                if let Some(slot) = codepoints.get_mut(gid as usize) {
+                    // if a glyph has multiple codepoints, prefer the lowest
+                    if slot.map(|prev| codepoint < prev).unwrap_or(true) {
+                        *slot = Some(codepoint);
+                    }
+                }
+            }
+        }
+
+        // version 4.0 tables map glyphs to character codes
+        if let Some(Post::Post4_0(table)) = post {
+            for (gid, slot) in codepoints.iter_mut().enumerate() {
+                if slot.is_none() {
+                    *slot = table.char_code(gid as u16).map(Into::into);
+                }
+            }
+        }
+
+        GlyphNames {
+            post: post.map(Post::names),
+            codepoints,
+        }
+    }
+
+    /// The number of glyphs covered by this resolver.
+    pub fn num_glyphs(&self) -> u16 {
+        self.codepoints.len() as u16
+    }
+
+    /// Return the name of the given glyph.
+    pub fn get(&self, glyph_id: u16) -> GlyphName<'a> {
+        if let Some(name) = self
+            .post
+            .as_ref()
+            .and_then(|post| post.glyph_name(glyph_id))
+            .filter(|name| !name.is_empty())
+        {
+            return GlyphName::Post(name);
+        }
+
+        match self.codepoints.get(glyph_id as usize).copied().flatten() {
+            #[cfg(feature = "aglfn")]
+            Some(codepoint) => match aglfn::glyph_name(codepoint) {
+                Some(name) => GlyphName::Agl(name),
+                None => GlyphName::Unicode(codepoint),
+            },
+            #[cfg(not(feature = "aglfn"))]
+            Some(codepoint) => GlyphName::Unicode(codepoint),
+            None => GlyphName::Index(glyph_id),
+        }
+    }
+
+    /// Iterate over the names of all glyphs, in glyph id order.
+    pub fn iter(&self) -> impl Iterator<Item = GlyphName<'a>> + '_ {
+        (0..self.num_glyphs()).map(|gid| self.get(gid))
+    }
+}
+
+impl std::fmt::Display for GlyphName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GlyphName::Post(name) => f.write_str(name),
+            GlyphName::Agl(name) => f.write_str(name),
+            GlyphName::Unicode(codepoint) if *codepoint <= 0xFFFF => {
+                write!(f, "uni{:04X}", codepoint)
+            }
+            GlyphName::Unicode(codepoint) => write!(f, "u{:04X}", codepoint),
+            GlyphName::Index(glyph_id) => write!(f, "glyph{}", glyph_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, Fixed, FontRead, Offset32, Version16Dot16};
+
+    use super::*;
+
+    fn make_post(version: Version16Dot16) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.push(version);
+        buf.push(Fixed::from_f64(0.0));
+        buf.extend([-75i16, 50]);
+        buf.extend([0u32, 0, 0, 0, 0]);
+        buf
+    }
+
+    fn make_cmap() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 1]); // version, num_tables
+        buf.extend([3u16, 10]);
+        buf.push(Offset32::new(12));
+        buf.extend([12u16, 0]);
+        buf.extend([0u32, 0, 3]); // length, language, num_groups
+        buf.extend([0x41u32, 0x41, 1]);
+        buf.extend([0xE9u32, 0xE9, 2]);
+        buf.extend([0x1F600u32, 0x1F600, 3]);
+        buf
+    }
+
+    #[test]
+    fn fallback_names() {
+        let post = make_post(Version16Dot16::VERSION_3_0);
+        let post = Post::read(&post).unwrap();
+        let cmap = make_cmap();
+        let cmap = Cmap::read(&cmap).unwrap();
+
+        let names = GlyphNames::new(Some(&post), Some(&cmap), 5);
+        let names = names
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        #[cfg(not(feature = "aglfn"))]
+        assert_eq!(names, ["glyph0", "uni0041", "uni00E9", "u1F600", "glyph4"]);
+        #[cfg(feature = "aglfn")]
+        assert_eq!(names, ["glyph0", "A", "eacute", "u1F600", "glyph4"]);
+    }
+
+    #[test]
+    fn post_names_first() {
+        let mut post = make_post(Version16Dot16::VERSION_2_0);
+        post.push(3u16);
+        post.extend([0u16, 258, 0]);
+        post.push(5u8);
+        post.extend(*b"A.alt");
+        let post = Post::read(&post).unwrap();
+        let cmap = make_cmap();
+        let cmap = Cmap::read(&cmap).unwrap();
+
+        let names = GlyphNames::new(Some(&post), Some(&cmap), 4);
+        assert_eq!(names.get(0), GlyphName::Post(".notdef"));
+        assert_eq!(names.get(1), GlyphName::Post("A.alt"));
+        assert_eq!(names.get(2), GlyphName::Post(".notdef"));
+        assert_eq!(names.get(3).to_string(), "u1F600");
+    }
+
+    #[cfg(feature = "aglfn")]
+    #[test]
+    fn aglfn_lookup() {
+        assert_eq!(aglfn::glyph_name(0x20), Some("space"));
+        assert_eq!(aglfn::glyph_name(0x2126), Some("Omega"));
+        assert_eq!(aglfn::glyph_name(0x03A9), None);
+        assert_eq!(aglfn::codepoint("Euro"), Some(0x20AC));
+    }
+}
@@ -1,12 +1,287 @@
 //! [OpenType™ Layout Common Table Formats](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2)
 
-use font_types::OffsetHost;
+use font_types::{BigEndian, F2Dot14, FontRead, Offset, OffsetHost, Tag};
+
+use crate::variations::ItemVariationStore;
+
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
 
 #[path = "../generated/generated_layout.rs"]
 mod generated;
 
 pub use generated::*;
 
+/// The script tag used when a font has no entry for a specific script.
+pub const DEFAULT_SCRIPT: Tag = Tag::new(b"DFLT");
+
+/// The value of `required_feature_index` when there is no required feature.
+const NO_REQUIRED_FEATURE: u16 = 0xFFFF;
+
+/// Lookups nested deeper than this in contextual lookups are ignored.
+#[cfg(feature = "std")]
+pub(crate) const MAX_NESTING_DEPTH: usize = 16;
+
+impl<'a> ScriptList<'a> {
+    /// Return the script table for this tag, if it exists.
+    pub fn script(&self, tag: Tag) -> Option<Script<'a>> {
+        self.script_records()
+            .iter()
+            .find(|rec| rec.script_tag() == tag)
+            .and_then(|rec| self.resolve_offset(rec.script_offset()))
+    }
+
+    /// Return the script table for this tag, falling back to the `DFLT`
+    /// script if there is no table for this tag.
+    pub fn script_or_default(&self, tag: Tag) -> Option<Script<'a>> {
+        self.script(tag).or_else(|| self.script(DEFAULT_SCRIPT))
+    }
+
+    /// Return the language system for this script and language.
+    ///
+    /// This falls back to the `DFLT` script if the script is missing, and to
+    /// the script's default language system if the language is missing.
+    pub fn lang_sys(&self, script: Tag, lang: Tag) -> Option<LangSys<'a>> {
+        self.script_or_default(script)?.lang_sys_or_default(lang)
+    }
+}
+
+impl<'a> Script<'a> {
+    pub fn default_lang_sys(&self) -> Option<LangSys<'a>> {
+        self.resolve_offset(self.default_lang_sys_offset())
+    }
+
+    /// Return the language system for this tag, if it exists.
+    pub fn lang_sys(&self, tag: Tag) -> Option<LangSys<'a>> {
+        self.lang_sys_records()
+            .iter()
+            .find(|rec| rec.lang_sys_tag() == tag)
+            .and_then(|rec| self.resolve_offset(rec.lang_sys_offset()))
+    }
+
+    /// Return the language system for this tag, falling back to the default
+    /// language system if there is no table for this tag.
+    pub fn lang_sys_or_default(&self, tag: Tag) -> Option<LangSys<'a>> {
+        self.lang_sys(tag).or_else(|| self.default_lang_sys())
+    }
+}
+
+impl<'a> LangSys<'a> {
+    /// The index of the feature required for this language system, if any.
+    pub fn required_feature(&self) -> Option<u16> {
+        Some(self.required_feature_index()).filter(|idx| *idx != NO_REQUIRED_FEATURE)
+    }
+
+    /// Iterate over all of the feature indices for this language system,
+    /// starting with the required feature, if there is one.
+    pub fn all_feature_indices(&self) -> impl Iterator<Item = u16> + '_ {
+        self.required_feature()
+            .into_iter()
+            .chain(self.feature_indices().iter().map(|idx| idx.get()))
+    }
+}
+
+impl<'a> FeatureList<'a> {
+    /// Return the tag and feature table at the given index.
+    pub fn get(&self, index: u16) -> Option<(Tag, Feature<'a>)> {
+        let record = self.feature_records().get(index as usize)?;
+        let feature = self.resolve_offset(record.feature_offset())?;
+        Some((record.feature_tag(), feature))
+    }
+
+    /// Collect the lookups enabled by a feature for a language system.
+    ///
+    /// The required feature of the language system is included if it has
+    /// this tag. The result is sorted, which is the order in which lookups
+    /// are applied, and contains no duplicates.
+    #[cfg(feature = "std")]
+    pub fn lookup_indices(&self, lang_sys: &LangSys, feature: Tag) -> Vec<u16> {
+        self.lookup_indices_with_substitution(lang_sys, feature, None)
+    }
+
+    /// Collect the lookups enabled by a feature, replacing any feature
+    /// tables that have alternates in `substitution`.
+    ///
+    /// See [`FeatureVariations::matching_record`] for how to find the
+    /// substitution for a set of variation coordinates.
+    #[cfg(feature = "std")]
+    pub fn lookup_indices_with_substitution(
+        &self,
+        lang_sys: &LangSys,
+        feature: Tag,
+        substitution: Option<&FeatureTableSubstitution<'a>>,
+    ) -> Vec<u16> {
+        let mut result = lang_sys
+            .all_feature_indices()
+            .filter_map(|idx| {
+                let (tag, feature) = self.get(idx)?;
+                let alternate = substitution.and_then(|subst| subst.alternate_feature(idx));
+                Some((tag, alternate.unwrap_or(feature)))
+            })
+            .filter(|(tag, _)| *tag == feature)
+            .flat_map(|(_, feature)| {
+                feature
+                    .lookup_list_indices()
+                    .iter()
+                    .map(|idx| idx.get())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
+    /// Collect the lookups of the required feature of a language system,
+    /// which are always applied, whatever the feature's tag.
+    ///
+    /// Feature tables that have alternates in `substitution` are replaced.
+    /// The result is sorted, and contains no duplicates.
+    #[cfg(feature = "std")]
+    pub fn required_lookups(
+        &self,
+        lang_sys: &LangSys,
+        substitution: Option<&FeatureTableSubstitution<'a>>,
+    ) -> Vec<u16> {
+        let idx = match lang_sys.required_feature() {
+            Some(idx) => idx,
+            None => return Vec::new(),
+        };
+        let alternate = substitution.and_then(|subst| subst.alternate_feature(idx));
+        let feature = match alternate.or_else(|| self.get(idx).map(|(_, feature)| feature)) {
+            Some(feature) => feature,
+            None => return Vec::new(),
+        };
+        let mut result = feature
+            .lookup_list_indices()
+            .iter()
+            .map(|idx| idx.get())
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+}
+
+/// Collect the lookups of the required feature, for a given script and
+/// language.
+///
+/// This is shared by GSUB and GPOS, like [`feature_lookups`].
+#[cfg(feature = "std")]
+pub(crate) fn required_lookups(
+    script_list: Option<ScriptList>,
+    feature_list: Option<FeatureList>,
+    substitution: Option<&FeatureTableSubstitution>,
+    script: Tag,
+    lang: Tag,
+) -> Vec<u16> {
+    let lang_sys = script_list.and_then(|list| list.lang_sys(script, lang));
+    match (lang_sys, feature_list) {
+        (Some(lang_sys), Some(feature_list)) => {
+            feature_list.required_lookups(&lang_sys, substitution)
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Collect the lookups enabled by a feature, for a given script and language.
+///
+/// This is shared by GSUB and GPOS; see [`ScriptList::lang_sys`] for how
+/// missing scripts and languages are handled.
+#[cfg(feature = "std")]
+pub(crate) fn feature_lookups(
+    script_list: Option<ScriptList>,
+    feature_list: Option<FeatureList>,
+    substitution: Option<&FeatureTableSubstitution>,
+    script: Tag,
+    lang: Tag,
+    feature: Tag,
+) -> Vec<u16> {
+    let lang_sys = script_list.and_then(|list| list.lang_sys(script, lang));
+    match (lang_sys, feature_list) {
+        (Some(lang_sys), Some(feature_list)) => {
+            feature_list.lookup_indices_with_substitution(&lang_sys, feature, substitution)
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl<'a> FeatureVariations<'a> {
+    /// The condition set for this record.
+    ///
+    /// This is `None` if the offset is null, in which case the record
+    /// matches all coordinates.
+    pub fn condition_set(&self, record: &FeatureVariationRecord) -> Option<ConditionSet<'a>> {
+        self.resolve_offset(record.condition_set_offset())
+    }
+
+    pub fn feature_table_substitution(
+        &self,
+        record: &FeatureVariationRecord,
+    ) -> Option<FeatureTableSubstitution<'a>> {
+        self.resolve_offset(record.feature_table_substitution_offset())
+    }
+
+    /// Return the first record whose conditions are met at these normalized
+    /// coordinates, along with its feature table substitutions.
+    ///
+    /// Coordinates are in axis order; missing coordinates are treated as 0.
+    pub fn matching_record(
+        &self,
+        coords: &[F2Dot14],
+    ) -> Option<(FeatureVariationRecord, Option<FeatureTableSubstitution<'a>>)> {
+        let record = self.feature_variation_records().iter().find(|record| {
+            record.condition_set_offset().non_null().is_none()
+                || self
+                    .condition_set(record)
+                    .map(|set| set.matches(coords))
+                    .unwrap_or(false)
+        })?;
+        Some((*record, self.feature_table_substitution(record)))
+    }
+}
+
+/// The format of [`ConditionFormat1`] tables.
+const CONDITION_FORMAT_AXIS_RANGE: u16 = 1;
+
+impl<'a> ConditionSet<'a> {
+    /// Returns `true` if all conditions are met at these coordinates.
+    ///
+    /// A condition with an unknown format is never met.
+    pub fn matches(&self, coords: &[F2Dot14]) -> bool {
+        self.condition_offsets().iter().all(|off| {
+            self.resolve_offset::<ConditionFormat1>(off.get())
+                .filter(|condition| condition.format() == CONDITION_FORMAT_AXIS_RANGE)
+                .map(|condition| condition.matches(coords))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl ConditionFormat1 {
+    /// Returns `true` if the coordinate for this axis is within range.
+    pub fn matches(&self, coords: &[F2Dot14]) -> bool {
+        let coord = coords
+            .get(self.axis_index() as usize)
+            .copied()
+            .unwrap_or_default();
+        coord >= self.filter_range_min_value() && coord <= self.filter_range_max_value()
+    }
+}
+
+impl<'a> FeatureTableSubstitution<'a> {
+    /// The alternate feature table for the feature at this index in the
+    /// feature list, if it is substituted.
+    pub fn alternate_feature(&self, feature_index: u16) -> Option<Feature<'a>> {
+        let substitutions = self.substitutions();
+        // records are sorted by feature index
+        let idx = substitutions
+            .binary_search_by_key(&feature_index, |rec| rec.feature_index())
+            .ok()?;
+        self.resolve_offset(substitutions[idx].alternate_feature_offset())
+    }
+}
+
 impl<'a> LookupList<'a> {
     /// Iterate all of the [`Lookup`]s in this list.
     pub fn iter_lookups(&self) -> impl Iterator<Item = Lookup<'a>> + '_ {
@@ -14,4 +289,707 @@ impl<'a> LookupList<'a> {
             .iter()
             .filter_map(|off| self.resolve_offset(off.get()))
     }
+
+    /// Return the lookup at the given index.
+    pub fn get(&self, index: u16) -> Option<Lookup<'a>> {
+        self.lookup_offsets()
+            .get(index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl LookupFlag {
+    /// The mark attachment class of marks this lookup applies to, if any.
+    ///
+    /// If this is set, marks with a different mark attachment class (as
+    /// defined in GDEF) are skipped.
+    pub fn mark_attachment_type(self) -> Option<u16> {
+        let class = (self & LookupFlag::MARK_ATTACHMENT_TYPE_MASK).bits() >> 8;
+        (class != 0).then_some(class)
+    }
+}
+
+impl<'a> Lookup<'a> {
+    /// Index (base 0) into GDEF mark glyph sets structure.
+    ///
+    /// This is only present if the `USE_MARK_FILTERING_SET` lookup flag
+    /// is set.
+    pub fn mark_filtering_set(&self) -> Option<u16> {
+        if !self
+            .lookup_flag()
+            .contains(LookupFlag::USE_MARK_FILTERING_SET)
+        {
+            return None;
+        }
+        let pos = 6 + self.subtable_offsets().len() * 2;
+        let bytes = self.bytes().get(pos..)?;
+        BigEndian::<u16>::read(bytes).map(|x| x.get())
+    }
+
+    /// Iterate over the raw data of each of this lookup's subtables.
+    ///
+    /// The interpretation of this data depends on the lookup type, and on
+    /// whether this is a GSUB or GPOS lookup.
+    pub fn subtable_data(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.subtable_offsets()
+            .iter()
+            .map(|off| self.bytes_at_offset(off.get()))
+            .filter(|data| !data.is_empty())
+    }
+}
+
+impl<'a> SequenceContextFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The rule set for the glyph with the given coverage index.
+    pub fn seq_rule_set(&self, coverage_index: u16) -> Option<SequenceRuleSet<'a>> {
+        self.seq_rule_set_offsets()
+            .get(coverage_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> SequenceRuleSet<'a> {
+    pub fn seq_rules(&self) -> impl Iterator<Item = SequenceRule<'a>> + '_ {
+        self.seq_rule_offsets()
+            .iter()
+            .filter_map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> SequenceContextFormat2<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    pub fn class_def(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.class_def_offset())
+    }
+
+    /// The rule set for glyphs of the given class.
+    pub fn class_seq_rule_set(&self, class: u16) -> Option<ClassSequenceRuleSet<'a>> {
+        self.class_seq_rule_set_offsets()
+            .get(class as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ClassSequenceRuleSet<'a> {
+    pub fn class_seq_rules(&self) -> impl Iterator<Item = ClassSequenceRule<'a>> + '_ {
+        self.class_seq_rule_offsets()
+            .iter()
+            .filter_map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> SequenceContextFormat3<'a> {
+    /// The coverage tables for each glyph in the input sequence.
+    pub fn coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> SequenceContext<'a> {
+    /// The coverage table for the first glyph in the input sequence.
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            SequenceContext::Format1(table) => table.coverage(),
+            SequenceContext::Format2(table) => table.coverage(),
+            SequenceContext::Format3(table) => table.coverages().next().flatten(),
+        }
+    }
+}
+
+impl<'a> ChainedSequenceContextFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The rule set for the glyph with the given coverage index.
+    pub fn chained_seq_rule_set(&self, coverage_index: u16) -> Option<ChainedSequenceRuleSet<'a>> {
+        self.chained_seq_rule_set_offsets()
+            .get(coverage_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ChainedSequenceRuleSet<'a> {
+    pub fn chained_seq_rules(&self) -> impl Iterator<Item = ChainedSequenceRule<'a>> + '_ {
+        self.chained_seq_rule_offsets()
+            .iter()
+            .filter_map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ChainedSequenceContextFormat2<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    pub fn backtrack_class_def(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.backtrack_class_def_offset())
+    }
+
+    pub fn input_class_def(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.input_class_def_offset())
+    }
+
+    pub fn lookahead_class_def(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.lookahead_class_def_offset())
+    }
+
+    /// The rule set for glyphs of the given input class.
+    pub fn chained_class_seq_rule_set(
+        &self,
+        class: u16,
+    ) -> Option<ChainedClassSequenceRuleSet<'a>> {
+        self.chained_class_seq_rule_set_offsets()
+            .get(class as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ChainedClassSequenceRuleSet<'a> {
+    pub fn chained_class_seq_rules(
+        &self,
+    ) -> impl Iterator<Item = ChainedClassSequenceRule<'a>> + '_ {
+        self.chained_class_seq_rule_offsets()
+            .iter()
+            .filter_map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ChainedSequenceContextFormat3<'a> {
+    /// The coverage tables for the backtrack sequence, in glyph order.
+    pub fn backtrack_coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.backtrack_coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+
+    /// The coverage tables for the input sequence.
+    pub fn input_coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.input_coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+
+    /// The coverage tables for the lookahead sequence.
+    pub fn lookahead_coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.lookahead_coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ChainedSequenceContext<'a> {
+    /// The coverage table for the first glyph in the input sequence.
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            ChainedSequenceContext::Format1(table) => table.coverage(),
+            ChainedSequenceContext::Format2(table) => table.coverage(),
+            ChainedSequenceContext::Format3(table) => table.input_coverages().next().flatten(),
+        }
+    }
+}
+
+impl<'a> CoverageFormat1<'a> {
+    /// The coverage index for this glyph, if it is covered.
+    pub fn get(&self, glyph_id: u16) -> Option<u16> {
+        self.glyph_array()
+            .binary_search_by(|probe| probe.get().cmp(&glyph_id))
+            .ok()
+            .map(|idx| idx as u16)
+    }
+
+    /// Iterate over the covered glyphs, in coverage index order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.glyph_array().iter().map(|gid| gid.get())
+    }
+}
+
+impl<'a> CoverageFormat2<'a> {
+    /// The coverage index for this glyph, if it is covered.
+    pub fn get(&self, glyph_id: u16) -> Option<u16> {
+        let records = self.range_records();
+        let idx = records
+            .binary_search_by(|rec| {
+                if rec.end_glyph_id() < glyph_id {
+                    core::cmp::Ordering::Less
+                } else if rec.start_glyph_id() > glyph_id {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .ok()?;
+        let record = &records[idx];
+        record
+            .start_coverage_index()
+            .checked_add(glyph_id - record.start_glyph_id())
+    }
+
+    /// Iterate over the covered glyphs, in coverage index order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.range_records()
+            .iter()
+            .flat_map(|rec| rec.start_glyph_id()..=rec.end_glyph_id())
+    }
+}
+
+impl<'a> CoverageTable<'a> {
+    /// The coverage index for this glyph, if it is covered.
+    pub fn get(&self, glyph_id: u16) -> Option<u16> {
+        match self {
+            CoverageTable::Format1(table) => table.get(glyph_id),
+            CoverageTable::Format2(table) => table.get(glyph_id),
+        }
+    }
+
+    /// Iterate over the covered glyphs, in coverage index order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        let format1 = match self {
+            CoverageTable::Format1(table) => Some(table.iter()),
+            _ => None,
+        };
+        let format2 = match self {
+            CoverageTable::Format2(table) => Some(table.iter()),
+            _ => None,
+        };
+        format1
+            .into_iter()
+            .flatten()
+            .chain(format2.into_iter().flatten())
+    }
+}
+
+impl<'a> ClassDefFormat1<'a> {
+    /// The class of this glyph; unlisted glyphs are in class 0.
+    pub fn get(&self, glyph_id: u16) -> u16 {
+        glyph_id
+            .checked_sub(self.start_glyph_id())
+            .and_then(|idx| self.class_value_array().get(idx as usize))
+            .map(|class| class.get())
+            .unwrap_or(0)
+    }
+
+    /// Iterate over `(glyph_id, class)` pairs for glyphs in a non-zero class.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        let start = self.start_glyph_id();
+        self.class_value_array()
+            .iter()
+            .enumerate()
+            .filter(|(_, class)| class.get() != 0)
+            .filter_map(move |(idx, class)| {
+                let gid = start.checked_add(u16::try_from(idx).ok()?)?;
+                Some((gid, class.get()))
+            })
+    }
+}
+
+impl<'a> ClassDefFormat2<'a> {
+    /// The class of this glyph; unlisted glyphs are in class 0.
+    pub fn get(&self, glyph_id: u16) -> u16 {
+        let records = self.class_range_records();
+        records
+            .binary_search_by(|rec| {
+                if rec.end_glyph_id() < glyph_id {
+                    core::cmp::Ordering::Less
+                } else if rec.start_glyph_id() > glyph_id {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .map(|idx| records[idx].class())
+            .unwrap_or(0)
+    }
+
+    /// Iterate over `(glyph_id, class)` pairs for glyphs in a non-zero class.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.class_range_records()
+            .iter()
+            .filter(|rec| rec.class() != 0)
+            .flat_map(|rec| {
+                let class = rec.class();
+                (rec.start_glyph_id()..=rec.end_glyph_id()).map(move |gid| (gid, class))
+            })
+    }
+}
+
+impl<'a> ClassDef<'a> {
+    /// The class of this glyph; unlisted glyphs are in class 0.
+    pub fn get(&self, glyph_id: u16) -> u16 {
+        match self {
+            ClassDef::Format1(table) => table.get(glyph_id),
+            ClassDef::Format2(table) => table.get(glyph_id),
+        }
+    }
+
+    /// Iterate over `(glyph_id, class)` pairs for glyphs in a non-zero class.
+    ///
+    /// Glyphs in class 0 are not listed, since any glyph not in the table
+    /// is also in class 0.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        let format1 = match self {
+            ClassDef::Format1(table) => Some(table.iter()),
+            _ => None,
+        };
+        let format2 = match self {
+            ClassDef::Format2(table) => Some(table.iter()),
+            _ => None,
+        };
+        format1
+            .into_iter()
+            .flatten()
+            .chain(format2.into_iter().flatten())
+    }
+
+    /// Collect the glyphs in each non-zero class.
+    #[cfg(feature = "std")]
+    pub fn invert(&self) -> BTreeMap<u16, BTreeSet<u16>> {
+        let mut result = BTreeMap::<u16, BTreeSet<u16>>::new();
+        for (gid, class) in self.iter() {
+            result.entry(class).or_default().insert(gid);
+        }
+        result
+    }
+}
+
+impl<'a> Device<'a> {
+    /// The adjustment, in pixels, at the given size.
+    ///
+    /// Returns 0 if `ppem` is outside the range covered by this table, or if
+    /// the `deltaFormat` is not recognized.
+    pub fn delta(&self, ppem: u16) -> i16 {
+        // deltas are packed from the most significant bits of each word
+        let bits: u32 = match self.delta_format() {
+            1 => 2,
+            2 => 4,
+            3 => 8,
+            _ => return 0,
+        };
+        if ppem < self.start_size() || ppem > self.end_size() {
+            return 0;
+        }
+        let idx = (ppem - self.start_size()) as u32;
+        let per_word = 16 / bits;
+        let word = match self.delta_value().get((idx / per_word) as usize) {
+            Some(word) => word.get(),
+            None => return 0,
+        };
+        let shift = 16 - bits * (idx % per_word + 1);
+        // shift the value into the top bits, then sign-extend it back down
+        ((word >> shift) << (16 - bits)) as i16 >> (16 - bits)
+    }
+}
+
+/// The `deltaFormat` that identifies a [`VariationIndex`] table.
+const VARIATION_INDEX_FORMAT: u16 = 0x8000;
+
+impl VariationIndex {
+    /// Compute the delta for this index, at a location in design space.
+    ///
+    /// The store is the one in the GDEF table; see
+    /// [`ItemVariationStore::compute_delta`] for details.
+    pub fn compute_delta(&self, store: &ItemVariationStore, coords: &[F2Dot14]) -> Option<f32> {
+        store.compute_delta(
+            self.delta_set_outer_index(),
+            self.delta_set_inner_index(),
+            coords,
+        )
+    }
+}
+
+/// Either a [`Device`] table or a [`VariationIndex`] table.
+///
+/// These share an offset, and are distinguished by their `deltaFormat` field.
+pub enum DeviceOrVariationIndex<'a> {
+    Device(Device<'a>),
+    VariationIndex(VariationIndex),
+}
+
+impl<'a> FontRead<'a> for DeviceOrVariationIndex<'a> {
+    fn read(bytes: &'a [u8]) -> Option<Self> {
+        // deltaFormat is the third field in both tables
+        let format: BigEndian<u16> = FontRead::read(bytes.get(4..)?)?;
+        if format.get() == VARIATION_INDEX_FORMAT {
+            VariationIndex::read(bytes).map(Self::VariationIndex)
+        } else {
+            Device::read(bytes).map(Self::Device)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    //! Utilities for building layout tables in tests.
+
+    use font_types::{test_helpers::BeBuffer, Tag};
+
+    /// Build a table from a header and the subtables that follow it.
+    ///
+    /// `header` is passed the offset of each subtable from the start of the
+    /// table; its length must not depend on them.
+    pub fn table(header: impl Fn(&[u16]) -> BeBuffer, subtables: &[Vec<u8>]) -> Vec<u8> {
+        let mut offset = header(&vec![0; subtables.len()]).len();
+        let offsets = subtables
+            .iter()
+            .map(|subtable| {
+                let subtable_offset = offset as u16;
+                offset += subtable.len();
+                subtable_offset
+            })
+            .collect::<Vec<_>>();
+        let mut data = header(&offsets).to_vec();
+        subtables.iter().for_each(|subtable| data.extend(subtable));
+        data
+    }
+
+    /// A format 1 coverage table for these glyphs, which must be sorted.
+    pub fn coverage(glyphs: &[u16]) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, glyphs.len() as u16]);
+        buf.extend(glyphs.iter().copied());
+        buf.to_vec()
+    }
+
+    /// A format 2 class definition table, from `(glyph, class)` pairs
+    /// sorted by glyph.
+    pub fn class_def(classes: &[(u16, u16)]) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, classes.len() as u16]);
+        for (glyph, class) in classes {
+            buf.extend([*glyph, *glyph, *class]);
+        }
+        buf.to_vec()
+    }
+
+    /// A lookup of this type, with these subtables.
+    pub fn lookup(lookup_type: u16, flags: u16, subtables: &[Vec<u8>]) -> Vec<u8> {
+        table(
+            |offsets| {
+                let mut buf = BeBuffer::new();
+                buf.extend([lookup_type, flags, offsets.len() as u16]);
+                buf.extend(offsets.iter().copied());
+                buf
+            },
+            subtables,
+        )
+    }
+
+    /// A GSUB or GPOS table with these lookups.
+    ///
+    /// There is a single `DFLT` script, whose default language system
+    /// enables every feature. Features are a tag and lookup indices.
+    pub fn layout_table(features: &[(Tag, &[u16])], lookups: &[Vec<u8>]) -> Vec<u8> {
+        layout_table_with_required(None, features, lookups)
+    }
+
+    /// A GSUB or GPOS table like [`layout_table`], where the feature at
+    /// `required` is the required feature, rather than an enabled one.
+    pub fn layout_table_with_required(
+        required: Option<u16>,
+        features: &[(Tag, &[u16])],
+        lookups: &[Vec<u8>],
+    ) -> Vec<u8> {
+        let enabled = (0..features.len() as u16)
+            .filter(|idx| Some(*idx) != required)
+            .collect::<Vec<_>>();
+        let mut lang_sys = BeBuffer::new();
+        lang_sys.extend([0u16, required.unwrap_or(0xFFFF), enabled.len() as u16]);
+        lang_sys.extend(enabled);
+        // the default language system, and no others
+        let script = table(|offsets| u16_array(&[offsets[0], 0]), &[lang_sys.to_vec()]);
+        let script_list = table(
+            |offsets| {
+                let mut buf = BeBuffer::new();
+                buf.push(1u16);
+                buf.push(Tag::new(b"DFLT"));
+                buf.push(offsets[0]);
+                buf
+            },
+            &[script],
+        );
+        let feature_tables = features
+            .iter()
+            .map(|(_, lookups)| {
+                let mut buf = BeBuffer::new();
+                buf.extend([0u16, lookups.len() as u16]);
+                buf.extend(lookups.iter().copied());
+                buf.to_vec()
+            })
+            .collect::<Vec<_>>();
+        let feature_list = table(
+            |offsets| {
+                let mut buf = BeBuffer::new();
+                buf.push(features.len() as u16);
+                for ((tag, _), offset) in features.iter().zip(offsets) {
+                    buf.push(*tag);
+                    buf.push(*offset);
+                }
+                buf
+            },
+            &feature_tables,
+        );
+        let lookup_list = table(
+            |offsets| {
+                let mut buf = u16_array(&[offsets.len() as u16]);
+                buf.extend(offsets.iter().copied());
+                buf
+            },
+            lookups,
+        );
+        table(
+            |offsets| {
+                let mut buf = u16_array(&[1, 0]);
+                buf.extend(offsets.iter().copied());
+                buf
+            },
+            &[script_list, feature_list, lookup_list],
+        )
+    }
+
+    /// Big-endian `u16` values.
+    pub fn u16_array(values: &[u16]) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend(values.iter().copied());
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+
+    #[test]
+    fn device_deltas() {
+        let mut buf = BeBuffer::new();
+        // 2-bit deltas for sizes 10..=13: 1, -1, 0, -2
+        buf.extend([10u16, 13, 1, 0b0111_0010_0000_0000]);
+        let device = Device::read(&buf).unwrap();
+        let deltas = (9..=14).map(|ppem| device.delta(ppem)).collect::<Vec<_>>();
+        assert_eq!(deltas, [0, 1, -1, 0, -2, 0]);
+
+        let mut buf = BeBuffer::new();
+        // 4-bit deltas for sizes 12..=16: -8, 7, 1, 0, -3
+        buf.extend([12u16, 16, 2, 0x8710, 0xD000]);
+        let device = Device::read(&buf).unwrap();
+        let deltas = (12..=16).map(|ppem| device.delta(ppem)).collect::<Vec<_>>();
+        assert_eq!(deltas, [-8, 7, 1, 0, -3]);
+
+        let mut buf = BeBuffer::new();
+        // 8-bit deltas for sizes 8..=10: -128, 127, 5
+        buf.extend([8u16, 10, 3, 0x807F, 0x0500]);
+        let device = Device::read(&buf).unwrap();
+        let deltas = (8..=10).map(|ppem| device.delta(ppem)).collect::<Vec<_>>();
+        assert_eq!(deltas, [-128, 127, 5]);
+    }
+
+    #[test]
+    fn feature_variations() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.push(2u32);
+        // a record for wght >= 0.5, and a fallback with no conditions
+        buf.extend([24u32, 38]);
+        buf.extend([0u32, 0]);
+        // condition set, with a single condition on axis 0
+        buf.push(1u16);
+        buf.push(6u32);
+        buf.extend([1u16, 0]);
+        buf.extend([F2Dot14::from_f32(0.5), F2Dot14::from_f32(1.0)]);
+        // substitution of feature 1, with a feature enabling lookup 7
+        buf.extend([1u16, 0, 1, 1]);
+        buf.push(12u32);
+        buf.extend([0u16, 1, 7]);
+
+        let variations = FeatureVariations::read(&buf).unwrap();
+        let (record, substitution) = variations
+            .matching_record(&[F2Dot14::from_f32(0.75)])
+            .unwrap();
+        assert_eq!(record.condition_set_offset().non_null(), Some(24));
+        let substitution = substitution.unwrap();
+        assert!(substitution.alternate_feature(0).is_none());
+        let feature = substitution.alternate_feature(1).unwrap();
+        assert_eq!(feature.lookup_list_indices()[0].get(), 7);
+
+        for coords in [&[F2Dot14::from_f32(0.25)][..], &[]] {
+            let (record, substitution) = variations.matching_record(coords).unwrap();
+            assert!(record.condition_set_offset().non_null().is_none());
+            assert!(substitution.is_none());
+        }
+    }
+
+    #[test]
+    fn coverage_format_1() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 4, 3, 7, 8, 20]);
+        let coverage = CoverageTable::read(&buf).unwrap();
+        assert_eq!(coverage.get(3), Some(0));
+        assert_eq!(coverage.get(8), Some(2));
+        assert_eq!(coverage.get(20), Some(3));
+        assert_eq!(coverage.get(5), None);
+        assert_eq!(coverage.iter().collect::<Vec<_>>(), [3, 7, 8, 20]);
+    }
+
+    #[test]
+    fn coverage_format_2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 2]);
+        buf.extend([5u16, 7, 0]);
+        buf.extend([10u16, 11, 3]);
+        let coverage = CoverageTable::read(&buf).unwrap();
+        assert_eq!(coverage.get(4), None);
+        assert_eq!(coverage.get(5), Some(0));
+        assert_eq!(coverage.get(7), Some(2));
+        assert_eq!(coverage.get(8), None);
+        assert_eq!(coverage.get(11), Some(4));
+        assert_eq!(coverage.iter().collect::<Vec<_>>(), [5, 6, 7, 10, 11]);
+    }
+
+    #[test]
+    fn class_def_format_1() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 10, 4, 1, 0, 2, 1]);
+        let class_def = ClassDef::read(&buf).unwrap();
+        assert_eq!(class_def.get(9), 0);
+        assert_eq!(class_def.get(10), 1);
+        assert_eq!(class_def.get(11), 0);
+        assert_eq!(class_def.get(12), 2);
+        assert_eq!(class_def.get(14), 0);
+        assert_eq!(
+            class_def.iter().collect::<Vec<_>>(),
+            [(10, 1), (12, 2), (13, 1)]
+        );
+        let inverted = class_def.invert();
+        assert_eq!(inverted.len(), 2);
+        assert_eq!(inverted[&1].iter().copied().collect::<Vec<_>>(), [10, 13]);
+        assert_eq!(inverted[&2].iter().copied().collect::<Vec<_>>(), [12]);
+    }
+
+    #[test]
+    fn class_def_format_2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 3]);
+        buf.extend([2u16, 3, 1]);
+        buf.extend([5u16, 5, 0]);
+        buf.extend([8u16, 9, 3]);
+        let class_def = ClassDef::read(&buf).unwrap();
+        assert_eq!(class_def.get(1), 0);
+        assert_eq!(class_def.get(3), 1);
+        assert_eq!(class_def.get(5), 0);
+        assert_eq!(class_def.get(9), 3);
+        assert_eq!(class_def.get(10), 0);
+        assert_eq!(
+            class_def.iter().collect::<Vec<_>>(),
+            [(2, 1), (3, 1), (8, 3), (9, 3)]
+        );
+    }
 }
@@ -15,8 +15,16 @@ extern crate core as std;
 
 use font_types::{FontRead, Offset, Tag};
 
+#[cfg(feature = "std")]
+pub mod glyph_names;
 pub mod layout;
+pub mod metrics;
+pub mod pen;
+pub mod postscript;
+#[cfg(feature = "std")]
+pub mod shape;
 pub mod tables;
+pub mod variations;
 
 #[path = "../generated/generated_font.rs"]
 mod generated;
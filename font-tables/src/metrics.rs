@@ -0,0 +1,169 @@
+//! Font-wide metrics, with variations applied.
+
+use font_types::{F2Dot14, Tag};
+
+use crate::tables::{mvar, os2::SelectionFlags, TableProvider};
+
+/// Font-wide metrics, in font units, at a location in design space.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Metrics {
+    /// The number of font units per em.
+    pub units_per_em: u16,
+    /// The distance from the baseline to the top of a line.
+    pub ascent: f32,
+    /// The distance from the baseline to the bottom of a line; this is
+    /// usually negative.
+    pub descent: f32,
+    /// The recommended gap between lines.
+    pub leading: f32,
+    /// The height of lowercase letters, such as 'x'.
+    pub x_height: Option<f32>,
+    /// The height of uppercase letters.
+    pub cap_height: Option<f32>,
+    /// The position of the top of the underline.
+    pub underline_position: Option<f32>,
+    /// The thickness of the underline.
+    pub underline_thickness: Option<f32>,
+    /// The position of the top of the strikeout stroke.
+    pub strikeout_position: Option<f32>,
+    /// The thickness of the strikeout stroke.
+    pub strikeout_thickness: Option<f32>,
+}
+
+impl Metrics {
+    /// Resolve the metrics of a font, at a location in design space.
+    ///
+    /// `coords` are normalized coordinates, and are empty for the default
+    /// location. The line metrics come from `OS/2` if it sets
+    /// `USE_TYPO_METRICS`, and otherwise from `hhea`; deltas from `MVAR` are
+    /// applied to each metric.
+    pub fn new(font: &impl TableProvider, coords: &[F2Dot14]) -> Option<Self> {
+        let head = font.head()?;
+        let os2 = font.os2();
+        let mvar = font.mvar();
+        let vary = |value: i16, tag: Tag| {
+            let delta = mvar.as_ref().and_then(|mvar| mvar.delta(tag, coords));
+            value as f32 + delta.unwrap_or_default()
+        };
+
+        let use_typo_metrics = os2.as_ref().is_some_and(|os2| {
+            os2.fs_selection()
+                .contains(SelectionFlags::USE_TYPO_METRICS)
+        });
+        let (ascent, descent, leading) = match &os2 {
+            Some(os2) if use_typo_metrics => (
+                os2.s_typo_ascender(),
+                os2.s_typo_descender(),
+                os2.s_typo_line_gap(),
+            ),
+            _ => {
+                let hhea = font.hhea()?;
+                (
+                    hhea.ascender().to_i16(),
+                    hhea.descender().to_i16(),
+                    hhea.line_gap().to_i16(),
+                )
+            }
+        };
+
+        let post = font.post();
+        Some(Metrics {
+            units_per_em: head.units_per_em(),
+            ascent: vary(ascent, mvar::HORIZONTAL_ASCENDER),
+            descent: vary(descent, mvar::HORIZONTAL_DESCENDER),
+            leading: vary(leading, mvar::HORIZONTAL_LINE_GAP),
+            x_height: os2
+                .as_ref()
+                .and_then(|os2| os2.sx_height())
+                .map(|value| vary(value, mvar::X_HEIGHT)),
+            cap_height: os2
+                .as_ref()
+                .and_then(|os2| os2.s_cap_height())
+                .map(|value| vary(value, mvar::CAP_HEIGHT)),
+            underline_position: post
+                .as_ref()
+                .map(|post| vary(post.underline_position().to_i16(), mvar::UNDERLINE_OFFSET)),
+            underline_thickness: post
+                .as_ref()
+                .map(|post| vary(post.underline_thickness().to_i16(), mvar::UNDERLINE_SIZE)),
+            strikeout_position: os2
+                .as_ref()
+                .map(|os2| vary(os2.y_strikeout_position(), mvar::STRIKEOUT_OFFSET)),
+            strikeout_thickness: os2
+                .as_ref()
+                .map(|os2| vary(os2.y_strikeout_size(), mvar::STRIKEOUT_SIZE)),
+        })
+    }
+
+    /// The distance between the baselines of consecutive lines.
+    pub fn line_height(&self) -> f32 {
+        self.ascent - self.descent + self.leading
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::variations::test_helpers::{f2dot14, var_store};
+    use crate::FontRef;
+
+    static INCONSOLATA: &[u8] = include_bytes!("../../resources/Inconsolata-Regular.ttf");
+
+    /// A font with an extra MVAR table.
+    struct WithMvar<'a> {
+        font: FontRef<'a>,
+        mvar: &'a [u8],
+    }
+
+    impl TableProvider for WithMvar<'_> {
+        fn data_for_tag(&self, tag: Tag) -> Option<&[u8]> {
+            if tag == mvar::TAG {
+                Some(self.mvar)
+            } else {
+                self.font.table_data(tag)
+            }
+        }
+    }
+
+    #[test]
+    fn static_metrics() {
+        let font = FontRef::new(INCONSOLATA).unwrap();
+        let metrics = Metrics::new(&font, &[]).unwrap();
+        assert_eq!(metrics.units_per_em, 1000);
+        assert_eq!(
+            (metrics.ascent, metrics.descent, metrics.leading),
+            (859.0, -190.0, 0.0)
+        );
+        assert_eq!(metrics.line_height(), 1049.0);
+        assert_eq!(metrics.x_height, Some(457.0));
+        assert_eq!(metrics.underline_position, Some(-175.0));
+    }
+
+    #[test]
+    fn mvar_deltas() {
+        let mut buf = BeBuffer::new();
+        // ascender & x-height records, and a var store at 28
+        buf.extend([1u16, 0, 0, 8, 2, 28]);
+        buf.push(mvar::HORIZONTAL_ASCENDER);
+        buf.extend([0u16, 0]);
+        buf.push(mvar::X_HEIGHT);
+        buf.extend([0u16, 1]);
+        // var store: one region, peaking at wght = 1.0
+        buf.extend(var_store(&[(0.0, 1.0, 1.0)], &[100, -40]));
+
+        let font = WithMvar {
+            font: FontRef::new(INCONSOLATA).unwrap(),
+            mvar: &buf,
+        };
+        let metrics = Metrics::new(&font, &[f2dot14(0.5)]).unwrap();
+        assert_eq!(metrics.ascent, 909.0);
+        assert_eq!(metrics.descent, -190.0);
+        assert_eq!(metrics.line_height(), 1099.0);
+        assert_eq!(metrics.x_height, Some(437.0));
+        // the default location is unchanged
+        let metrics = Metrics::new(&font, &[]).unwrap();
+        assert_eq!(metrics.line_height(), 1049.0);
+    }
+}
@@ -0,0 +1,25 @@
+//! An interface for receiving glyph outlines.
+
+/// A receiver of outline commands.
+///
+/// Outlines are made up of contours, each of which starts with a call to
+/// [`move_to`][Pen::move_to] and ends with a call to [`close`][Pen::close].
+/// Coordinates are in font units, with y pointing up.
+pub trait Pen {
+    /// Begin a new contour at this point.
+    fn move_to(&mut self, x: f32, y: f32);
+
+    /// Add a line from the current point to this point.
+    fn line_to(&mut self, x: f32, y: f32);
+
+    /// Add a quadratic curve from the current point to (`x`, `y`), with
+    /// one control point.
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32);
+
+    /// Add a cubic curve from the current point to (`x`, `y`), with two
+    /// control points.
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32);
+
+    /// Close the current contour.
+    fn close(&mut self);
+}
@@ -0,0 +1,506 @@
+//! Common formats for the [CFF] and [CFF2] tables.
+//!
+//! [CFF]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff
+//! [CFF2]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff2
+
+#[path = "../generated/generated_postscript.rs"]
+mod generated;
+
+mod charstring;
+mod dict;
+
+pub use charstring::CharstringError;
+pub(crate) use charstring::{evaluate, CharstringContext};
+pub(crate) use dict::operators;
+pub use dict::{DictIter, Operands};
+pub use generated::*;
+
+use core::ops::Range;
+
+use font_types::{BigEndian, FontRead};
+use zerocopy::LayoutVerified;
+
+/// An [INDEX], an array of variable-sized objects.
+///
+/// [INDEX]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#index-data
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Index<'a> {
+    count: u32,
+    off_size: u8,
+    offsets: &'a [u8],
+    data: &'a [u8],
+    len: usize,
+}
+
+impl<'a> Index<'a> {
+    /// Read an INDEX with a 16-bit count, as used in CFF.
+    pub fn read(data: &'a [u8]) -> Option<Self> {
+        let count = BigEndian::<u16>::read(data)?.get();
+        Self::read_impl(data, count as u32, 2)
+    }
+
+    /// Read an INDEX with a 32-bit count, as used in CFF2.
+    pub fn read_cff2(data: &'a [u8]) -> Option<Self> {
+        let count = BigEndian::<u32>::read(data)?.get();
+        Self::read_impl(data, count, 4)
+    }
+
+    fn read_impl(data: &'a [u8], count: u32, count_len: usize) -> Option<Self> {
+        // an empty INDEX is only the count
+        if count == 0 {
+            return Some(Index {
+                len: count_len,
+                ..Default::default()
+            });
+        }
+        let off_size = *data.get(count_len)?;
+        if !(1..=4).contains(&off_size) {
+            return None;
+        }
+        let offsets_start = count_len + 1;
+        let offsets_len = (count as usize + 1) * off_size as usize;
+        let offsets = data.get(offsets_start..offsets_start + offsets_len)?;
+        let data_start = offsets_start + offsets_len;
+        // offsets are relative to the byte before the object data
+        let data_len = read_offset(offsets, off_size, count as usize)?.checked_sub(1)?;
+        let data = data.get(data_start..data_start + data_len)?;
+        Some(Index {
+            count,
+            off_size,
+            offsets,
+            data,
+            len: data_start + data_len,
+        })
+    }
+
+    /// The number of objects in this INDEX.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The total size of this INDEX, in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The data for the object at this index.
+    pub fn get(&self, idx: u32) -> Option<&'a [u8]> {
+        if idx >= self.count {
+            return None;
+        }
+        let start = read_offset(self.offsets, self.off_size, idx as usize)?;
+        let end = read_offset(self.offsets, self.off_size, idx as usize + 1)?;
+        self.data.get(start.checked_sub(1)?..end.checked_sub(1)?)
+    }
+
+    /// Iterate over the objects in this INDEX.
+    pub fn iter(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.count).filter_map(|idx| self.get(idx))
+    }
+}
+
+fn read_offset(offsets: &[u8], off_size: u8, idx: usize) -> Option<usize> {
+    let off_size = off_size as usize;
+    let bytes = offsets.get(idx * off_size..(idx + 1) * off_size)?;
+    Some(
+        bytes
+            .iter()
+            .fold(0usize, |acc, byte| (acc << 8) | *byte as usize),
+    )
+}
+
+/// An [FDSelect] structure, which assigns glyphs to font DICTs in CID-keyed
+/// fonts.
+///
+/// [FDSelect]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect
+#[derive(Clone, Copy)]
+pub enum FdSelect<'a> {
+    Format0(&'a [u8]),
+    Format3 {
+        ranges: &'a [FdSelectRange3],
+        sentinel: u16,
+    },
+    /// A format with 32-bit glyph ids and 16-bit font indices, used in CFF2.
+    Format4 {
+        ranges: &'a [FdSelectRange4],
+        sentinel: u32,
+    },
+}
+
+impl<'a> FdSelect<'a> {
+    /// Read an FDSelect, for a font with `num_glyphs` glyphs.
+    pub fn read(data: &'a [u8], num_glyphs: u32) -> Option<Self> {
+        let format = *data.first()?;
+        let data = data.get(1..)?;
+        match format {
+            0 => data.get(..num_glyphs as usize).map(FdSelect::Format0),
+            3 => {
+                let n_ranges = BigEndian::<u16>::read(data)?.get();
+                let (ranges, rest) =
+                    LayoutVerified::<_, [FdSelectRange3]>::new_slice_unaligned_from_prefix(
+                        data.get(2..)?,
+                        n_ranges as usize,
+                    )?;
+                let sentinel = BigEndian::<u16>::read(rest)?.get();
+                Some(FdSelect::Format3 {
+                    ranges: ranges.into_slice(),
+                    sentinel,
+                })
+            }
+            4 => {
+                let n_ranges = BigEndian::<u32>::read(data)?.get();
+                let (ranges, rest) =
+                    LayoutVerified::<_, [FdSelectRange4]>::new_slice_unaligned_from_prefix(
+                        data.get(4..)?,
+                        n_ranges as usize,
+                    )?;
+                let sentinel = BigEndian::<u32>::read(rest)?.get();
+                Some(FdSelect::Format4 {
+                    ranges: ranges.into_slice(),
+                    sentinel,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The index of the font DICT for this glyph.
+    pub fn font_index(&self, glyph_id: u16) -> Option<u16> {
+        match self {
+            FdSelect::Format0(fds) => fds.get(glyph_id as usize).map(|fd| *fd as u16),
+            FdSelect::Format3 { ranges, sentinel } => {
+                if glyph_id >= *sentinel {
+                    return None;
+                }
+                // the first range that starts after this glyph
+                let next = ranges.partition_point(|range| range.first() <= glyph_id);
+                ranges
+                    .get(next.checked_sub(1)?)
+                    .map(|range| range.fd() as u16)
+            }
+            FdSelect::Format4 { ranges, sentinel } => {
+                let glyph_id = glyph_id as u32;
+                if glyph_id >= *sentinel {
+                    return None;
+                }
+                let next = ranges.partition_point(|range| range.first() <= glyph_id);
+                ranges.get(next.checked_sub(1)?).map(|range| range.fd())
+            }
+        }
+    }
+}
+
+/// The values we use from a [Private DICT].
+///
+/// [Private DICT]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#private-dict-data
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PrivateDict {
+    /// The offset to the local subroutines, from the start of the private
+    /// DICT.
+    pub subrs_offset: Option<usize>,
+    /// The width of glyphs whose charstrings don't specify one.
+    pub default_width_x: f64,
+    /// The value added to widths specified in charstrings.
+    pub nominal_width_x: f64,
+    /// The item variation data used by charstrings, in CFF2.
+    pub vsindex: u16,
+}
+
+impl PrivateDict {
+    /// Read the values we use, failing if any of them are blended, since we
+    /// don't apply blends in DICTs.
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let mut dict = PrivateDict::default();
+        let mut blended = false;
+        for (op, operands) in DictIter::new(data) {
+            if blended && op != operators::BLEND && is_used(op) {
+                return None;
+            }
+            blended = op == operators::BLEND;
+            match op {
+                operators::SUBRS => dict.subrs_offset = Some(operands.get_offset(0)?),
+                operators::DEFAULT_WIDTH_X => dict.default_width_x = operands.get(0)?,
+                operators::NOMINAL_WIDTH_X => dict.nominal_width_x = operands.get(0)?,
+                operators::VSINDEX => dict.vsindex = operands.get(0)? as u16,
+                _ => (),
+            }
+        }
+        Some(dict)
+    }
+}
+
+fn is_used(op: dict::Operator) -> bool {
+    matches!(
+        op,
+        operators::SUBRS
+            | operators::DEFAULT_WIDTH_X
+            | operators::NOMINAL_WIDTH_X
+            | operators::VSINDEX
+    )
+}
+
+/// Read the private DICT at `range` in a CFF or CFF2 table, along with its
+/// local subroutines, using `read_index` for the table's INDEX format.
+pub(crate) fn private_dict_and_subrs<'a>(
+    table: &'a [u8],
+    range: Range<usize>,
+    read_index: fn(&'a [u8]) -> Option<Index<'a>>,
+) -> Option<(PrivateDict, Index<'a>)> {
+    let dict = PrivateDict::read(table.get(range.clone())?)?;
+    // local subroutines are relative to the private DICT
+    let local_subrs = match dict.subrs_offset {
+        Some(offset) => read_index(table.get(range.start.checked_add(offset)?..)?)?,
+        None => Index::default(),
+    };
+    Some((dict, local_subrs))
+}
+
+/// The bias added to subroutine numbers in charstrings, for an INDEX with
+/// this many subroutines.
+pub(crate) fn subr_bias(count: u32) -> i32 {
+    match count {
+        0..=1239 => 107,
+        1240..=33899 => 1131,
+        _ => 32768,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    //! Utilities for building CFF data in tests.
+
+    /// Build a table from a header and the sections that follow it.
+    ///
+    /// Both are passed the offset of each section from the start of the
+    /// table; their sizes must not depend on the offsets.
+    pub fn table_with_sections(
+        header: impl Fn(&[i32]) -> Vec<u8>,
+        sections: impl Fn(&[i32]) -> Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        // enough offsets for any of our tests
+        let placeholder = [0; 16];
+        let mut offset = header(&placeholder).len();
+        let offsets = sections(&placeholder)
+            .iter()
+            .map(|section| {
+                let section_offset = offset as i32;
+                offset += section.len();
+                section_offset
+            })
+            .collect::<Vec<_>>();
+        let mut data = header(&offsets);
+        sections(&offsets)
+            .iter()
+            .for_each(|section| data.extend(section));
+        data
+    }
+
+    /// Build an INDEX with a 16-bit count, containing these objects.
+    pub fn index(objects: &[&[u8]]) -> Vec<u8> {
+        let mut result = (objects.len() as u16).to_be_bytes().to_vec();
+        if objects.is_empty() {
+            return result;
+        }
+        result.push(4);
+        let mut offset = 1u32;
+        result.extend(offset.to_be_bytes());
+        for object in objects {
+            offset += object.len() as u32;
+            result.extend(offset.to_be_bytes());
+        }
+        objects.iter().for_each(|object| result.extend(*object));
+        result
+    }
+
+    /// Build an INDEX with a 32-bit count, as used in CFF2.
+    pub fn index_cff2(objects: &[&[u8]]) -> Vec<u8> {
+        let mut result = vec![0, 0];
+        result.extend(index(objects));
+        result
+    }
+
+    /// Encode a DICT entry, with integer operands in their five-byte form
+    /// so that sizes are independent of values.
+    pub fn dict_entry(operands: &[i32], op: u16) -> Vec<u8> {
+        let mut result = Vec::new();
+        for operand in operands {
+            result.push(29);
+            result.extend(operand.to_be_bytes());
+        }
+        if op > 0xFF {
+            result.push(12);
+        }
+        result.push(op as u8);
+        result
+    }
+
+    /// Assemble a charstring from operators and operands separated by
+    /// whitespace.
+    ///
+    /// Integers are encoded as 16-bit numbers, other numbers as 16.16 fixed
+    /// point, and tokens starting with "0x" are copied as a single byte.
+    pub fn charstring(source: &str) -> Vec<u8> {
+        let mut result = Vec::new();
+        for token in source.split_whitespace() {
+            if let Some(byte) = token.strip_prefix("0x") {
+                result.push(u8::from_str_radix(byte, 16).unwrap());
+            } else if let Ok(value) = token.parse::<i16>() {
+                result.push(28);
+                result.extend(value.to_be_bytes());
+            } else if let Ok(value) = token.parse::<f64>() {
+                result.push(255);
+                result.extend(((value * 65536.0) as i32).to_be_bytes());
+            } else {
+                result.extend(charstring_operator(token));
+            }
+        }
+        result
+    }
+
+    fn charstring_operator(name: &str) -> &'static [u8] {
+        match name {
+            "hstem" => &[1],
+            "vstem" => &[3],
+            "vmoveto" => &[4],
+            "rlineto" => &[5],
+            "hlineto" => &[6],
+            "vlineto" => &[7],
+            "rrcurveto" => &[8],
+            "callsubr" => &[10],
+            "return" => &[11],
+            "endchar" => &[14],
+            "vsindex" => &[15],
+            "blend" => &[16],
+            "hstemhm" => &[18],
+            "hintmask" => &[19],
+            "cntrmask" => &[20],
+            "rmoveto" => &[21],
+            "hmoveto" => &[22],
+            "vstemhm" => &[23],
+            "rcurveline" => &[24],
+            "rlinecurve" => &[25],
+            "vvcurveto" => &[26],
+            "hhcurveto" => &[27],
+            "callgsubr" => &[29],
+            "vhcurveto" => &[30],
+            "hvcurveto" => &[31],
+            "dotsection" => &[12, 0],
+            "hflex" => &[12, 34],
+            "flex" => &[12, 35],
+            "hflex1" => &[12, 36],
+            "flex1" => &[12, 37],
+            _ => panic!("unknown operator '{}'", name),
+        }
+    }
+
+    /// A pen that records commands as strings, like "M 0 0".
+    #[derive(Default)]
+    pub struct RecordingPen(pub Vec<String>);
+
+    impl crate::pen::Pen for RecordingPen {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.0.push(format!("M {} {}", x, y));
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            self.0.push(format!("L {} {}", x, y));
+        }
+
+        fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+            self.0.push(format!("Q {} {} {} {}", cx0, cy0, x, y));
+        }
+
+        fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+            self.0
+                .push(format!("C {} {} {} {} {} {}", cx0, cy0, cx1, cy1, x, y));
+        }
+
+        fn close(&mut self) {
+            self.0.push("Z".into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index() {
+        let data = test_helpers::index(&[b"one", b"", b"three"]);
+        let index = Index::read(&data).unwrap();
+        assert_eq!(index.count(), 3);
+        assert_eq!(index.len(), data.len());
+        assert_eq!(
+            index.iter().collect::<Vec<_>>(),
+            [&b"one"[..], b"", b"three"]
+        );
+        assert!(index.get(3).is_none());
+
+        let empty = Index::read(&[0, 0, 0xFF]).unwrap();
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.len(), 2);
+
+        // 32-bit count, one-byte offsets
+        let data = [0, 0, 0, 2, 1, 1, 2, 4, b'a', b'b', b'c'];
+        let index = Index::read_cff2(&data).unwrap();
+        assert_eq!(index.get(0), Some(&b"a"[..]));
+        assert_eq!(index.get(1), Some(&b"bc"[..]));
+        assert_eq!(index.len(), data.len());
+    }
+
+    #[test]
+    fn fd_select() {
+        let format0 = FdSelect::read(&[0, 0, 1, 1, 0], 4).unwrap();
+        let fds = (0..5)
+            .map(|gid| format0.font_index(gid))
+            .collect::<Vec<_>>();
+        assert_eq!(fds, [Some(0), Some(1), Some(1), Some(0), None]);
+
+        // glyphs 0-2 use FD 1, glyphs 3-4 use FD 0
+        let format3 = FdSelect::read(&[3, 0, 2, 0, 0, 1, 0, 3, 0, 0, 5], 5).unwrap();
+        let fds = (0..6)
+            .map(|gid| format3.font_index(gid))
+            .collect::<Vec<_>>();
+        assert_eq!(fds, [Some(1), Some(1), Some(1), Some(0), Some(0), None]);
+
+        // glyphs 0-1 use FD 0, glyphs 2-3 use FD 300
+        let data = [
+            4, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0x2C, 0, 0, 0, 4,
+        ];
+        let format4 = FdSelect::read(&data, 4).unwrap();
+        let fds = (0..5)
+            .map(|gid| format4.font_index(gid))
+            .collect::<Vec<_>>();
+        assert_eq!(fds, [Some(0), Some(0), Some(300), Some(300), None]);
+    }
+
+    #[test]
+    fn private_dict_blends() {
+        use test_helpers::dict_entry;
+
+        // a blended BlueValues is ignored
+        let data = [
+            dict_entry(&[10, 20, 1], operators::BLEND),
+            dict_entry(&[], 6),
+            dict_entry(&[5], operators::VSINDEX),
+        ]
+        .concat();
+        assert_eq!(PrivateDict::read(&data).unwrap().vsindex, 5);
+
+        // a blended value we use is an error
+        let data = [
+            dict_entry(&[10, 20, 1], operators::BLEND),
+            dict_entry(&[], operators::VSINDEX),
+        ]
+        .concat();
+        assert!(PrivateDict::read(&data).is_none());
+    }
+
+    #[test]
+    fn subr_bias_thresholds() {
+        assert_eq!(subr_bias(0), 107);
+        assert_eq!(subr_bias(1240), 1131);
+        assert_eq!(subr_bias(33900), 32768);
+    }
+}
@@ -0,0 +1,722 @@
+//! Evaluating [Type 2 charstrings](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf)
+
+use font_types::F2Dot14;
+
+use super::{subr_bias, Index};
+use crate::pen::Pen;
+use crate::tables::glyf::Cursor;
+use crate::variations::ItemVariationStore;
+
+/// The maximum depth of nested subroutine calls.
+const MAX_NESTING: usize = 10;
+
+/// The maximum number of operands on the stack.
+///
+/// This is 48 for CFF, and 513 for CFF2.
+const MAX_STACK: usize = 513;
+
+/// A problem encountered while evaluating a charstring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharstringError {
+    /// An operator had fewer operands than it requires.
+    StackUnderflow,
+    /// There were more operands than the stack can hold.
+    StackOverflow,
+    /// The charstring ended in the middle of an operand or operator.
+    UnexpectedEnd,
+    /// An operator that is reserved, or that we don't support.
+    InvalidOperator(u16),
+    /// A call to a subroutine that doesn't exist.
+    InvalidSubroutine(i32),
+    /// Subroutine calls were nested too deeply.
+    NestingLimit,
+    /// A glyph, or a component of an accented glyph, could not be found.
+    MissingGlyph(u16),
+    /// A component of an accented glyph was itself an accented glyph.
+    NestedSeac,
+    /// A table or structure needed to find the charstring is missing.
+    MissingData,
+    /// A `blend` referred to item variation data that doesn't exist.
+    InvalidVariationData(u16),
+}
+
+/// The data needed to evaluate a charstring, besides the charstring itself.
+#[derive(Default)]
+pub(crate) struct CharstringContext<'a> {
+    pub global_subrs: Index<'a>,
+    pub local_subrs: Index<'a>,
+    /// The offset of the glyph's origin, used to place seac accents.
+    pub origin: (f32, f32),
+    /// CFF2 charstrings have no widths, and may contain blends.
+    pub is_cff2: bool,
+    /// The variation store used by blends, in CFF2.
+    pub var_store: Option<ItemVariationStore<'a>>,
+    /// The item variation data used by blends, until changed by `vsindex`.
+    pub vsindex: u16,
+    /// Normalized coordinates, used to compute blends.
+    pub coords: &'a [F2Dot14],
+}
+
+/// The components of an accented glyph, from the `seac`-like form of
+/// `endchar`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Seac {
+    /// The offset of the accent from the base glyph.
+    pub accent_origin: (f32, f32),
+    /// The standard encoding code of the base glyph.
+    pub base_code: u8,
+    /// The standard encoding code of the accent glyph.
+    pub accent_code: u8,
+}
+
+/// Evaluate a charstring, sending the outline to the pen.
+///
+/// Hints are skipped. If the charstring describes an accented glyph, the
+/// components are returned, and the caller is responsible for drawing them.
+pub(crate) fn evaluate(
+    charstring: &[u8],
+    context: &CharstringContext,
+    pen: &mut impl Pen,
+) -> Result<Option<Seac>, CharstringError> {
+    let mut evaluator = Evaluator {
+        context,
+        pen,
+        stack: Stack::default(),
+        x: context.origin.0,
+        y: context.origin.1,
+        n_stems: 0,
+        have_width: context.is_cff2,
+        vsindex: context.vsindex,
+        is_open: false,
+        is_done: false,
+        seac: None,
+    };
+    evaluator.evaluate(charstring, 0)?;
+    if evaluator.is_open {
+        evaluator.pen.close();
+    }
+    Ok(evaluator.seac)
+}
+
+/// Type 2 charstring operators.
+mod operators {
+    pub const HSTEM: u16 = 1;
+    pub const VSTEM: u16 = 3;
+    pub const VMOVETO: u16 = 4;
+    pub const RLINETO: u16 = 5;
+    pub const HLINETO: u16 = 6;
+    pub const VLINETO: u16 = 7;
+    pub const RRCURVETO: u16 = 8;
+    pub const CALLSUBR: u16 = 10;
+    pub const RETURN: u16 = 11;
+    pub const ESCAPE: u16 = 12;
+    pub const ENDCHAR: u16 = 14;
+    pub const HSTEMHM: u16 = 18;
+    pub const HINTMASK: u16 = 19;
+    pub const CNTRMASK: u16 = 20;
+    pub const RMOVETO: u16 = 21;
+    pub const HMOVETO: u16 = 22;
+    pub const VSINDEX: u16 = 15;
+    pub const BLEND: u16 = 16;
+    pub const VSTEMHM: u16 = 23;
+    pub const RCURVELINE: u16 = 24;
+    pub const RLINECURVE: u16 = 25;
+    pub const VVCURVETO: u16 = 26;
+    pub const HHCURVETO: u16 = 27;
+    pub const SHORTINT: u16 = 28;
+    pub const CALLGSUBR: u16 = 29;
+    pub const VHCURVETO: u16 = 30;
+    pub const HVCURVETO: u16 = 31;
+    pub const DOTSECTION: u16 = ESCAPE << 8;
+    pub const HFLEX: u16 = ESCAPE << 8 | 34;
+    pub const FLEX: u16 = ESCAPE << 8 | 35;
+    pub const HFLEX1: u16 = ESCAPE << 8 | 36;
+    pub const FLEX1: u16 = ESCAPE << 8 | 37;
+}
+
+struct Stack {
+    values: [f32; MAX_STACK],
+    len: usize,
+}
+
+impl Default for Stack {
+    fn default() -> Self {
+        Stack {
+            values: [0.0; MAX_STACK],
+            len: 0,
+        }
+    }
+}
+
+impl Stack {
+    fn push(&mut self, value: f32) -> Result<(), CharstringError> {
+        let slot = self
+            .values
+            .get_mut(self.len)
+            .ok_or(CharstringError::StackOverflow)?;
+        *slot = value;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<f32, CharstringError> {
+        self.len = self
+            .len
+            .checked_sub(1)
+            .ok_or(CharstringError::StackUnderflow)?;
+        Ok(self.values[self.len])
+    }
+
+    fn values(&self) -> &[f32] {
+        &self.values[..self.len]
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+struct Evaluator<'a, 'p, P> {
+    context: &'p CharstringContext<'a>,
+    pen: &'p mut P,
+    stack: Stack,
+    /// The current point.
+    x: f32,
+    y: f32,
+    n_stems: usize,
+    /// Whether the first stack-clearing operator, which may be preceded by
+    /// the glyph's width, has been seen.
+    have_width: bool,
+    vsindex: u16,
+    is_open: bool,
+    /// Set by `endchar`, which ends the glyph even inside a subroutine.
+    is_done: bool,
+    seac: Option<Seac>,
+}
+
+impl<'a, 'p, P: Pen> Evaluator<'a, 'p, P> {
+    fn evaluate(&mut self, charstring: &[u8], depth: usize) -> Result<(), CharstringError> {
+        if depth > MAX_NESTING {
+            return Err(CharstringError::NestingLimit);
+        }
+        let mut cursor = Cursor::new(charstring);
+        while let Some(b0) = cursor.bump::<u8>() {
+            match b0 as u16 {
+                operators::SHORTINT => {
+                    let value = cursor.bump::<i16>().ok_or(CharstringError::UnexpectedEnd)?;
+                    self.stack.push(value as f32)?;
+                }
+                32..=246 => self.stack.push(b0 as f32 - 139.0)?,
+                247..=250 => {
+                    let b1 = cursor.bump::<u8>().ok_or(CharstringError::UnexpectedEnd)?;
+                    self.stack
+                        .push((b0 as f32 - 247.0) * 256.0 + b1 as f32 + 108.0)?;
+                }
+                251..=254 => {
+                    let b1 = cursor.bump::<u8>().ok_or(CharstringError::UnexpectedEnd)?;
+                    self.stack
+                        .push(-(b0 as f32 - 251.0) * 256.0 - b1 as f32 - 108.0)?;
+                }
+                255 => {
+                    // 16.16 fixed point
+                    let value = cursor.bump::<i32>().ok_or(CharstringError::UnexpectedEnd)?;
+                    self.stack.push(value as f32 / 65536.0)?;
+                }
+                operators::ESCAPE => {
+                    let b1 = cursor.bump::<u8>().ok_or(CharstringError::UnexpectedEnd)?;
+                    self.escaped_operator(operators::ESCAPE << 8 | b1 as u16)?;
+                }
+                operators::CALLSUBR | operators::CALLGSUBR => {
+                    let subrs = if b0 as u16 == operators::CALLSUBR {
+                        self.context.local_subrs
+                    } else {
+                        self.context.global_subrs
+                    };
+                    let idx = self.stack.pop()? as i32 + subr_bias(subrs.count());
+                    let subr = u32::try_from(idx)
+                        .ok()
+                        .and_then(|idx| subrs.get(idx))
+                        .ok_or(CharstringError::InvalidSubroutine(idx))?;
+                    self.evaluate(subr, depth + 1)?;
+                    if self.is_done {
+                        return Ok(());
+                    }
+                }
+                operators::RETURN => return Ok(()),
+                operators::ENDCHAR => {
+                    self.endchar()?;
+                    return Ok(());
+                }
+                operators::HINTMASK | operators::CNTRMASK => {
+                    // any operands are an implied vstemhm
+                    self.stems()?;
+                    let mask_len = self.n_stems.div_ceil(8);
+                    for _ in 0..mask_len {
+                        cursor.bump::<u8>().ok_or(CharstringError::UnexpectedEnd)?;
+                    }
+                }
+                op => self.operator(op)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the width from the stack, if this is the first
+    /// stack-clearing operator and it has an extra operand.
+    fn take_width(&mut self, has_extra_arg: bool) {
+        if !self.have_width && has_extra_arg {
+            self.stack.values.copy_within(1..self.stack.len, 0);
+            self.stack.len -= 1;
+        }
+        self.have_width = true;
+    }
+
+    fn stems(&mut self) -> Result<(), CharstringError> {
+        self.take_width(self.stack.len % 2 == 1);
+        self.n_stems += self.stack.len / 2;
+        self.stack.clear();
+        Ok(())
+    }
+
+    fn endchar(&mut self) -> Result<(), CharstringError> {
+        self.take_width(matches!(self.stack.len, 1 | 5));
+        if let [adx, ady, bchar, achar] = *self.stack.values() {
+            self.seac = Some(Seac {
+                accent_origin: (adx, ady),
+                base_code: bchar as u8,
+                accent_code: achar as u8,
+            });
+        }
+        self.stack.clear();
+        self.is_done = true;
+        Ok(())
+    }
+
+    fn operator(&mut self, op: u16) -> Result<(), CharstringError> {
+        use operators::*;
+        match op {
+            HSTEM | VSTEM | HSTEMHM | VSTEMHM => return self.stems(),
+            VSINDEX if self.context.is_cff2 => {
+                self.vsindex = self.stack.pop()? as u16;
+            }
+            // blend leaves its results on the stack
+            BLEND if self.context.is_cff2 => return self.blend(),
+            RMOVETO => {
+                self.take_width(self.stack.len > 2);
+                let [dx, dy] = self.args::<2>(0)?;
+                self.move_to(self.x + dx, self.y + dy);
+            }
+            HMOVETO => {
+                self.take_width(self.stack.len > 1);
+                let [dx] = self.args::<1>(0)?;
+                self.move_to(self.x + dx, self.y);
+            }
+            VMOVETO => {
+                self.take_width(self.stack.len > 1);
+                let [dy] = self.args::<1>(0)?;
+                self.move_to(self.x, self.y + dy);
+            }
+            RLINETO => {
+                for idx in (0..self.stack.len & !1).step_by(2) {
+                    let [dx, dy] = self.args::<2>(idx)?;
+                    self.line_to(self.x + dx, self.y + dy);
+                }
+            }
+            HLINETO | VLINETO => {
+                let mut is_horizontal = op == HLINETO;
+                for idx in 0..self.stack.len {
+                    let [delta] = self.args::<1>(idx)?;
+                    if is_horizontal {
+                        self.line_to(self.x + delta, self.y);
+                    } else {
+                        self.line_to(self.x, self.y + delta);
+                    }
+                    is_horizontal = !is_horizontal;
+                }
+            }
+            RRCURVETO => {
+                for idx in (0..self.stack.len - self.stack.len % 6).step_by(6) {
+                    self.rel_curve_to(self.args::<6>(idx)?);
+                }
+            }
+            RCURVELINE => {
+                let n_curves = self.stack.len.saturating_sub(2) / 6;
+                for idx in (0..n_curves * 6).step_by(6) {
+                    self.rel_curve_to(self.args::<6>(idx)?);
+                }
+                let [dx, dy] = self.args::<2>(n_curves * 6)?;
+                self.line_to(self.x + dx, self.y + dy);
+            }
+            RLINECURVE => {
+                let n_lines = self.stack.len.saturating_sub(6) / 2;
+                for idx in (0..n_lines * 2).step_by(2) {
+                    let [dx, dy] = self.args::<2>(idx)?;
+                    self.line_to(self.x + dx, self.y + dy);
+                }
+                self.rel_curve_to(self.args::<6>(n_lines * 2)?);
+            }
+            HHCURVETO => {
+                // an odd operand is the first curve's dy1
+                let mut idx = self.stack.len % 2;
+                let mut dy1 = match idx {
+                    1 => self.stack.values[0],
+                    _ => 0.0,
+                };
+                while idx + 4 <= self.stack.len {
+                    let [dx1, dx2, dy2, dx3] = self.args::<4>(idx)?;
+                    self.rel_curve_to([dx1, dy1, dx2, dy2, dx3, 0.0]);
+                    dy1 = 0.0;
+                    idx += 4;
+                }
+            }
+            VVCURVETO => {
+                // an odd operand is the first curve's dx1
+                let mut idx = self.stack.len % 2;
+                let mut dx1 = match idx {
+                    1 => self.stack.values[0],
+                    _ => 0.0,
+                };
+                while idx + 4 <= self.stack.len {
+                    let [dy1, dx2, dy2, dy3] = self.args::<4>(idx)?;
+                    self.rel_curve_to([dx1, dy1, dx2, dy2, 0.0, dy3]);
+                    dx1 = 0.0;
+                    idx += 4;
+                }
+            }
+            HVCURVETO | VHCURVETO => {
+                let mut is_horizontal = op == HVCURVETO;
+                let mut idx = 0;
+                while idx + 4 <= self.stack.len {
+                    let [d1, dx2, dy2, d3] = self.args::<4>(idx)?;
+                    // the last curve may have a final delta in the other
+                    // direction
+                    let is_last = self.stack.len - idx == 5;
+                    let d_last = match is_last {
+                        true => self.stack.values[idx + 4],
+                        false => 0.0,
+                    };
+                    if is_horizontal {
+                        self.rel_curve_to([d1, 0.0, dx2, dy2, d_last, d3]);
+                    } else {
+                        self.rel_curve_to([0.0, d1, dx2, dy2, d3, d_last]);
+                    }
+                    is_horizontal = !is_horizontal;
+                    idx += 4 + is_last as usize;
+                }
+            }
+            _ => return Err(CharstringError::InvalidOperator(op)),
+        }
+        self.stack.clear();
+        Ok(())
+    }
+
+    fn escaped_operator(&mut self, op: u16) -> Result<(), CharstringError> {
+        use operators::*;
+        let (start_x, start_y) = (self.x, self.y);
+        match op {
+            // deprecated hint, which is ignored
+            DOTSECTION => (),
+            HFLEX => {
+                let [dx1, dx2, dy2, dx3, dx4, dx5, dx6] = self.args::<7>(0)?;
+                self.rel_curve_to([dx1, 0.0, dx2, dy2, dx3, 0.0]);
+                self.rel_curve_to([dx4, 0.0, dx5, -dy2, dx6, 0.0]);
+            }
+            FLEX => {
+                let args = self.args::<12>(0)?;
+                self.rel_curve_to(args[..6].try_into().unwrap());
+                self.rel_curve_to(args[6..].try_into().unwrap());
+            }
+            HFLEX1 => {
+                let [dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6] = self.args::<9>(0)?;
+                self.rel_curve_to([dx1, dy1, dx2, dy2, dx3, 0.0]);
+                // the curves end at the starting height
+                let dy6 = start_y - (self.y + dy5);
+                self.rel_curve_to([dx4, 0.0, dx5, dy5, dx6, dy6]);
+            }
+            FLEX1 => {
+                let args = self.args::<11>(0)?;
+                self.rel_curve_to(args[..6].try_into().unwrap());
+                let [dx4, dy4, dx5, dy5, d6] = args[6..].try_into().unwrap();
+                let dx = self.x + dx4 + dx5 - start_x;
+                let dy = self.y + dy4 + dy5 - start_y;
+                // the last point is only moved along the longer dimension
+                let (dx6, dy6) = if abs(dx) > abs(dy) {
+                    (d6, start_y - (self.y + dy4 + dy5))
+                } else {
+                    (start_x - (self.x + dx4 + dx5), d6)
+                };
+                self.rel_curve_to([dx4, dy4, dx5, dy5, dx6, dy6]);
+            }
+            _ => return Err(CharstringError::InvalidOperator(op)),
+        }
+        self.stack.clear();
+        Ok(())
+    }
+
+    /// Replace the operands of a `blend` with the blended values.
+    ///
+    /// The operands are `n` default values, followed by the deltas for each
+    /// value, one for each region in the current item variation data, and
+    /// then `n`.
+    fn blend(&mut self) -> Result<(), CharstringError> {
+        let var_store = self
+            .context
+            .var_store
+            .as_ref()
+            .ok_or(CharstringError::MissingData)?;
+        let data = var_store
+            .item_variation_data(self.vsindex)
+            .ok_or(CharstringError::InvalidVariationData(self.vsindex))?;
+        let regions = var_store
+            .variation_region_list()
+            .ok_or(CharstringError::MissingData)?;
+        let region_indexes = data.region_indexes();
+        let n_values = self.stack.pop()? as usize;
+        let n_operands = n_values * (region_indexes.len() + 1);
+        let start = self
+            .stack
+            .len
+            .checked_sub(n_operands)
+            .ok_or(CharstringError::StackUnderflow)?;
+        let (values, deltas) = self.stack.values[start..start + n_operands].split_at_mut(n_values);
+        for (region_idx, region) in region_indexes.iter().enumerate() {
+            let scalar = regions
+                .region_scalar(region.get(), self.context.coords)
+                .unwrap_or(0.0);
+            if scalar == 0.0 {
+                continue;
+            }
+            let region_deltas = deltas.iter().skip(region_idx).step_by(region_indexes.len());
+            for (value, delta) in values.iter_mut().zip(region_deltas) {
+                *value += delta * scalar;
+            }
+        }
+        self.stack.len = start + n_values;
+        Ok(())
+    }
+
+    /// The `N` operands starting at `start`.
+    fn args<const N: usize>(&self, start: usize) -> Result<[f32; N], CharstringError> {
+        self.stack
+            .values()
+            .get(start..start + N)
+            .and_then(|args| args.try_into().ok())
+            .ok_or(CharstringError::StackUnderflow)
+    }
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        if self.is_open {
+            self.pen.close();
+        }
+        self.x = x;
+        self.y = y;
+        self.pen.move_to(x, y);
+        self.is_open = true;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+        self.pen.line_to(x, y);
+    }
+
+    /// A curve with each point relative to the previous one.
+    fn rel_curve_to(&mut self, [dx1, dy1, dx2, dy2, dx3, dy3]: [f32; 6]) {
+        let (x1, y1) = (self.x + dx1, self.y + dy1);
+        let (x2, y2) = (x1 + dx2, y1 + dy2);
+        self.x = x2 + dx3;
+        self.y = y2 + dy3;
+        self.pen.curve_to(x1, y1, x2, y2, self.x, self.y);
+    }
+}
+
+fn abs(value: f32) -> f32 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postscript::test_helpers::{charstring, index, RecordingPen};
+
+    fn draw(source: &str, context: &CharstringContext) -> Result<Vec<String>, CharstringError> {
+        let mut pen = RecordingPen::default();
+        evaluate(&charstring(source), context, &mut pen)?;
+        Ok(pen.0)
+    }
+
+    #[test]
+    fn lines_and_width() {
+        let context = CharstringContext::default();
+        // the first operand of rmoveto is the width
+        let commands = draw(
+            "500 10 20 rmoveto 100 50 -100 hlineto 30 vlineto endchar",
+            &context,
+        )
+        .unwrap();
+        assert_eq!(
+            commands,
+            ["M 10 20", "L 110 20", "L 110 70", "L 10 70", "L 10 100", "Z"]
+        );
+        let commands = draw("10 hmoveto 10 10 rlineto 20 vmoveto 5 5 rlineto", &context).unwrap();
+        assert_eq!(
+            commands,
+            ["M 10 0", "L 20 10", "Z", "M 20 30", "L 25 35", "Z"]
+        );
+    }
+
+    #[test]
+    fn curves() {
+        let context = CharstringContext::default();
+        let commands = draw(
+            "0 0 rmoveto 10 0 10 10 0 10 rrcurveto 5 10 0 10 10 hhcurveto \
+             10 10 0 10 vvcurveto 10 10 10 10 10 hvcurveto",
+            &context,
+        )
+        .unwrap();
+        assert_eq!(
+            commands,
+            [
+                "M 0 0",
+                "C 10 0 20 10 20 20",
+                "C 30 25 30 35 40 35",
+                "C 40 45 50 45 50 55",
+                "C 60 55 70 65 80 75",
+                "Z"
+            ]
+        );
+        let commands = draw(
+            "0 0 rmoveto 10 10 10 10 10 0 0 20 rcurveline 5 5 0 10 10 10 10 10 rlinecurve",
+            &context,
+        )
+        .unwrap();
+        assert_eq!(
+            commands,
+            [
+                "M 0 0",
+                "C 10 10 20 20 30 20",
+                "L 30 40",
+                "L 35 45",
+                "C 35 55 45 65 55 75",
+                "Z"
+            ]
+        );
+    }
+
+    #[test]
+    fn flex() {
+        let context = CharstringContext::default();
+        let commands = draw(
+            "0 0 rmoveto 10 10 10 10 10 10 10 hflex 10 10 10 0 10 10 10 10 10 hflex1",
+            &context,
+        )
+        .unwrap();
+        assert_eq!(
+            commands,
+            [
+                "M 0 0",
+                "C 10 0 20 10 30 10",
+                "C 40 10 50 0 60 0",
+                "C 70 10 80 10 90 10",
+                "C 100 10 110 20 120 0",
+                "Z"
+            ]
+        );
+        // flex1 ends at the starting height, as dx is larger than dy
+        let commands = draw("0 0 rmoveto 10 1 10 1 10 1 10 -1 10 -1 10 flex1", &context).unwrap();
+        assert_eq!(
+            commands,
+            ["M 0 0", "C 10 1 20 2 30 3", "C 40 2 50 1 60 0", "Z"]
+        );
+    }
+
+    #[test]
+    fn dotsection() {
+        let context = CharstringContext::default();
+        // the operands are discarded
+        let commands = draw("0 0 rmoveto 1 2 dotsection 10 hlineto", &context).unwrap();
+        assert_eq!(commands, ["M 0 0", "L 10 0", "Z"]);
+    }
+
+    #[test]
+    fn subroutines_and_hints() {
+        // with fewer than 1240 subroutines, the bias is 107
+        let local = index(&[&charstring("0 0 rmoveto 10 10 rlineto return")]);
+        let global = index(&[&charstring("20 vlineto endchar")]);
+        let context = CharstringContext {
+            global_subrs: Index::read(&global).unwrap(),
+            local_subrs: Index::read(&local).unwrap(),
+            ..Default::default()
+        };
+        // 9 stems need a two-byte mask
+        let source = "1 2 3 4 5 6 7 8 hstemhm 1 2 3 4 5 6 7 8 9 10 hintmask 0xFF 0x80 \
+                      -107 callsubr -107 callgsubr 5 hlineto";
+        let commands = draw(source, &context).unwrap();
+        assert_eq!(commands, ["M 0 0", "L 10 10", "L 10 30", "Z"]);
+
+        assert_eq!(
+            draw("-106 callsubr", &context),
+            Err(CharstringError::InvalidSubroutine(1))
+        );
+        let recursive = index(&[&charstring("-107 callsubr")]);
+        let context = CharstringContext {
+            local_subrs: Index::read(&recursive).unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            draw("-107 callsubr", &context),
+            Err(CharstringError::NestingLimit)
+        );
+    }
+
+    #[test]
+    fn seac() {
+        let context = CharstringContext {
+            origin: (50.0, 0.0),
+            ..Default::default()
+        };
+        let mut pen = RecordingPen::default();
+        let seac = evaluate(
+            &charstring("500 100 200 65 194 endchar"),
+            &context,
+            &mut pen,
+        );
+        assert_eq!(
+            seac,
+            Ok(Some(Seac {
+                accent_origin: (100.0, 200.0),
+                base_code: 65,
+                accent_code: 194,
+            }))
+        );
+        // the origin offsets the outline
+        assert_eq!(
+            draw("0 0 rmoveto 10 hlineto", &context).unwrap()[..2],
+            ["M 50 0", "L 60 0"]
+        );
+        assert_eq!(
+            draw("0 0 rmoveto 0x02", &context),
+            Err(CharstringError::InvalidOperator(2))
+        );
+    }
+
+    #[test]
+    fn blend_needs_cff2() {
+        assert_eq!(
+            draw("10 20 1 1 2 blend rmoveto", &CharstringContext::default()),
+            Err(CharstringError::InvalidOperator(16))
+        );
+        let context = CharstringContext {
+            is_cff2: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            draw("10 20 1 1 2 blend rmoveto", &context),
+            Err(CharstringError::MissingData)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Parsing [DICT data](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#dict-data)
+
+/// A DICT operator.
+///
+/// Two-byte operators, which start with the escape byte 12, are stored
+/// with the escape byte in the high byte.
+pub(crate) type Operator = u16;
+
+/// The DICT operators that we use.
+pub(crate) mod operators {
+    use super::Operator;
+
+    const fn escaped(op: u8) -> Operator {
+        (ESCAPE as u16) << 8 | op as u16
+    }
+
+    pub(crate) const ESCAPE: u8 = 12;
+
+    pub const CHARSET: Operator = 15;
+    pub const ENCODING: Operator = 16;
+    pub const CHAR_STRINGS: Operator = 17;
+    pub const PRIVATE: Operator = 18;
+    pub const SUBRS: Operator = 19;
+    pub const DEFAULT_WIDTH_X: Operator = 20;
+    pub const NOMINAL_WIDTH_X: Operator = 21;
+    pub const VSINDEX: Operator = 22;
+    pub const BLEND: Operator = 23;
+    pub const VSTORE: Operator = 24;
+    pub const ROS: Operator = escaped(30);
+    pub const FD_ARRAY: Operator = escaped(36);
+    pub const FD_SELECT: Operator = escaped(37);
+}
+
+/// An iterator over the entries in a DICT.
+///
+/// Each entry is an operator and the operands that precede it.
+#[derive(Clone)]
+pub struct DictIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> DictIter<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        DictIter { data, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for DictIter<'a> {
+    type Item = (u16, Operands<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+        loop {
+            let b0 = *self.data.get(self.pos)?;
+            match b0 {
+                0..=21 => {
+                    let operands = Operands(self.data.get(start..self.pos)?);
+                    self.pos += 1;
+                    let op = if b0 == operators::ESCAPE {
+                        let b1 = *self.data.get(self.pos)?;
+                        self.pos += 1;
+                        (b0 as u16) << 8 | b1 as u16
+                    } else {
+                        b0 as u16
+                    };
+                    return Some((op, operands));
+                }
+                // CFF2 adds vsindex, blend and vstore
+                22..=27 => {
+                    let operands = Operands(self.data.get(start..self.pos)?);
+                    self.pos += 1;
+                    return Some((b0 as u16, operands));
+                }
+                _ => self.pos += operand_len(&self.data[self.pos..])?,
+            }
+        }
+    }
+}
+
+/// The operands of a DICT entry.
+#[derive(Clone, Copy, Debug)]
+pub struct Operands<'a>(&'a [u8]);
+
+impl<'a> Operands<'a> {
+    /// Iterate over the values of the operands.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        let mut data = self.0;
+        core::iter::from_fn(move || {
+            let len = operand_len(data)?;
+            let value = parse_operand(data.get(..len)?);
+            data = data.get(len..)?;
+            value
+        })
+    }
+
+    /// The number of operands.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// The value of the operand at this index.
+    pub fn get(&self, idx: usize) -> Option<f64> {
+        self.iter().nth(idx)
+    }
+
+    /// The operand at this index, interpreted as an offset or size.
+    pub(crate) fn get_offset(&self, idx: usize) -> Option<usize> {
+        let value = self.get(idx)?;
+        (value >= 0.0).then_some(value as usize)
+    }
+}
+
+/// The length of the operand at the start of `data`.
+fn operand_len(data: &[u8]) -> Option<usize> {
+    let len = match *data.first()? {
+        28 => 3,
+        29 => 5,
+        30 => {
+            // real numbers end with a nibble of 0xF
+            data.iter()
+                .skip(1)
+                .position(|byte| byte & 0x0F == 0x0F || byte & 0xF0 == 0xF0)?
+                + 2
+        }
+        32..=246 => 1,
+        247..=254 => 2,
+        _ => return None,
+    };
+    (data.len() >= len).then_some(len)
+}
+
+fn parse_operand(data: &[u8]) -> Option<f64> {
+    let b0 = *data.first()?;
+    let value = match b0 {
+        28 => i16::from_be_bytes(data.get(1..3)?.try_into().ok()?) as f64,
+        29 => i32::from_be_bytes(data.get(1..5)?.try_into().ok()?) as f64,
+        30 => parse_real(data.get(1..)?)?,
+        32..=246 => b0 as f64 - 139.0,
+        247..=250 => ((b0 as f64 - 247.0) * 256.0) + *data.get(1)? as f64 + 108.0,
+        251..=254 => -((b0 as f64 - 251.0) * 256.0) - *data.get(1)? as f64 - 108.0,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Parse a real number, encoded as nibbles.
+fn parse_real(data: &[u8]) -> Option<f64> {
+    let mut buf = [0u8; 64];
+    let mut len = 0;
+    let nibbles = data.iter().flat_map(|byte| [byte >> 4, byte & 0x0F]);
+    for nibble in nibbles {
+        let text: &[u8] = match nibble {
+            0..=9 => &[b'0' + nibble],
+            0xA => b".",
+            0xB => b"E",
+            0xC => b"E-",
+            0xE => b"-",
+            0xF => break,
+            _ => return None,
+        };
+        buf.get_mut(len..len + text.len())?.copy_from_slice(text);
+        len += text.len();
+    }
+    core::str::from_utf8(&buf[..len]).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operands() {
+        // 0, 100, 1000, -1000, 10000, -100000, then the Private operator
+        let data = [
+            139, 239, 250, 124, 254, 124, 28, 0x27, 0x10, 29, 0xFF, 0xFE, 0x79, 0x60, 18,
+        ];
+        let mut iter = DictIter::new(&data);
+        let (op, operands) = iter.next().unwrap();
+        assert_eq!(op, operators::PRIVATE);
+        assert_eq!(
+            operands.iter().collect::<Vec<_>>(),
+            [0.0, 100.0, 1000.0, -1000.0, 10000.0, -100000.0]
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn real_numbers() {
+        // -2.25 and 0.140541E-3, from the CFF specification
+        let data = [
+            30, 0xE2, 0xA2, 0x5F, 30, 0x0A, 0x14, 0x05, 0x41, 0xC3, 0xFF, 12, 7,
+        ];
+        let (op, operands) = DictIter::new(&data).next().unwrap();
+        assert_eq!(op, 0x0C07);
+        assert_eq!(operands.len(), 2);
+        assert_eq!(operands.get(0), Some(-2.25));
+        assert_eq!(operands.get(1), Some(0.140541E-3));
+    }
+
+    #[test]
+    fn escaped_operators() {
+        let data = [139, 140, 141, 12, 30, 140, 12, 37];
+        let entries = DictIter::new(&data)
+            .map(|(op, operands)| (op, operands.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, [(operators::ROS, 3), (operators::FD_SELECT, 1)]);
+    }
+}
@@ -0,0 +1,1237 @@
+//! A minimal shaper, for simple scripts.
+//!
+//! This maps text to glyphs with `cmap`, applies the default GSUB and GPOS
+//! features for a script and language, and sets advances from `hmtx`.
+//!
+//! There is no reordering or other script-specific processing, so this is
+//! only suitable for scripts like Latin, Cyrillic and Greek. Only horizontal,
+//! left-to-right text is supported.
+
+use font_types::Tag;
+
+use crate::layout::{
+    ChainedSequenceContext, ClassDef, CoverageTable, SequenceContext, SequenceLookupRecord,
+    MAX_NESTING_DEPTH,
+};
+use crate::tables::{
+    cmap::CmapSubtable,
+    gdef::{Gdef, GlyphClassDef, GlyphFilter},
+    gpos::{AnchorTable, Gpos, PositioningLookup, PositioningSubtable, ValueRecord},
+    gsub::{Gsub, SingleSubst, SubstitutionLookup, SubstitutionSubtable},
+    hmtx::Hmtx,
+    TableProvider,
+};
+
+/// The GSUB features we apply, in stages.
+///
+/// All lookups for the features in a stage are applied in lookup list order
+/// before moving on to the next stage.
+const GSUB_STAGES: &[&[Tag]] = &[
+    &[Tag::new(b"ccmp"), Tag::new(b"locl")],
+    &[Tag::new(b"rlig"), Tag::new(b"liga"), Tag::new(b"calt")],
+];
+
+/// The GPOS features we apply.
+const GPOS_FEATURES: &[Tag] = &[Tag::new(b"kern"), Tag::new(b"mark"), Tag::new(b"mkmk")];
+
+const REVERSE_CHAIN_LOOKUP_TYPE: u16 = 8;
+
+/// A positioned glyph, the output of shaping.
+///
+/// All values are in font units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShapedGlyph {
+    pub glyph_id: u16,
+    /// The byte offset in the input text of the first character that
+    /// produced this glyph.
+    pub cluster: u32,
+    pub x_advance: i32,
+    pub y_advance: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+    /// For a mark that was skipped between the components of a ligature,
+    /// the index of the component it follows.
+    ligature_component: Option<u16>,
+}
+
+/// A simple shaper.
+///
+/// This loads the tables it needs up front; create it once per font.
+pub struct Shaper<'a> {
+    cmap: Option<CmapSubtable<'a>>,
+    hmtx: Option<Hmtx<'a>>,
+    gdef: Option<Gdef<'a>>,
+    glyph_class_def: Option<ClassDef<'a>>,
+    gsub: Option<Gsub<'a>>,
+    gpos: Option<Gpos<'a>>,
+}
+
+/// The positions of the glyphs matched by a contextual rule, and the lookups
+/// to apply at those positions.
+type ContextMatch = (Vec<usize>, Vec<SequenceLookupRecord>);
+
+impl<'a> Shaper<'a> {
+    pub fn new(font: &'a impl TableProvider) -> Self {
+        let gdef = font.gdef();
+        Shaper {
+            cmap: font.cmap().and_then(|cmap| cmap.best_unicode_subtable()),
+            hmtx: font.hmtx(),
+            glyph_class_def: gdef.as_ref().and_then(Gdef::glyph_class_def),
+            gdef,
+            gsub: font.gsub(),
+            gpos: font.gpos(),
+        }
+    }
+
+    /// Shape some text, using the features for this script and language.
+    ///
+    /// If the font has no entries for the script or language, the defaults
+    /// are used.
+    pub fn shape(&self, text: &str, script: Tag, language: Tag) -> Vec<ShapedGlyph> {
+        let mut glyphs = text
+            .char_indices()
+            .map(|(idx, chr)| ShapedGlyph {
+                glyph_id: self
+                    .cmap
+                    .as_ref()
+                    .and_then(|cmap| cmap.map_codepoint(chr as u32))
+                    .unwrap_or(0),
+                cluster: idx as u32,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(gsub) = &self.gsub {
+            for (idx, stage) in GSUB_STAGES.iter().enumerate() {
+                // the required feature is applied with the first stage
+                let required = match idx {
+                    0 => gsub.required_lookups(script, language),
+                    _ => Vec::new(),
+                };
+                let lookups = stage_lookups(required, stage, |feature| {
+                    gsub.feature_lookups(script, language, feature)
+                });
+                for lookup in lookups {
+                    self.apply_subst_lookup(gsub, lookup, &mut glyphs);
+                }
+            }
+        }
+
+        for glyph in &mut glyphs {
+            // marks are positioned by GPOS, and don't move the pen
+            glyph.x_advance = if self.is_mark(glyph.glyph_id) {
+                0
+            } else {
+                self.hmtx
+                    .as_ref()
+                    .and_then(|hmtx| hmtx.advance(glyph.glyph_id))
+                    .unwrap_or(0) as i32
+            };
+        }
+
+        if let Some(gpos) = &self.gpos {
+            let required = gpos.required_lookups(script, language);
+            let lookups = stage_lookups(required, GPOS_FEATURES, |feature| {
+                gpos.feature_lookups(script, language, feature)
+            });
+            for lookup in lookups {
+                self.apply_pos_lookup(gpos, lookup, &mut glyphs);
+            }
+        }
+        glyphs
+    }
+
+    fn is_mark(&self, glyph_id: u16) -> bool {
+        self.glyph_class_def
+            .as_ref()
+            .map(|class_def| {
+                matches!(
+                    GlyphClassDef::new(class_def.get(glyph_id)),
+                    GlyphClassDef::Mark
+                )
+            })
+            .unwrap_or(false)
+    }
+
+    fn apply_subst_lookup(
+        &self,
+        gsub: &Gsub<'a>,
+        lookup_index: u16,
+        glyphs: &mut Vec<ShapedGlyph>,
+    ) {
+        let lookup = match gsub.lookup(lookup_index) {
+            Some(lookup) => lookup,
+            None => return,
+        };
+        let filter = GlyphFilter::new(self.gdef.as_ref(), lookup.lookup());
+
+        if lookup.lookup_type() == REVERSE_CHAIN_LOOKUP_TYPE {
+            for pos in (0..glyphs.len()).rev() {
+                if !filter.skips(glyphs[pos].glyph_id) {
+                    self.apply_subst_at(gsub, &lookup, &filter, glyphs, pos, 0);
+                }
+            }
+            return;
+        }
+
+        let mut pos = 0;
+        while pos < glyphs.len() {
+            if filter.skips(glyphs[pos].glyph_id) {
+                pos += 1;
+                continue;
+            }
+            let len_before = glyphs.len();
+            pos = match self.apply_subst_at(gsub, &lookup, &filter, glyphs, pos, 0) {
+                // a deletion can leave us at the same position
+                Some(next) if next > pos || glyphs.len() < len_before => next,
+                _ => pos + 1,
+            };
+        }
+    }
+
+    /// Apply the first subtable of this lookup that matches at `pos`.
+    ///
+    /// Returns the position at which to continue, if anything was applied.
+    fn apply_subst_at(
+        &self,
+        gsub: &Gsub<'a>,
+        lookup: &SubstitutionLookup<'a>,
+        filter: &GlyphFilter,
+        glyphs: &mut Vec<ShapedGlyph>,
+        pos: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        lookup.subtables().find_map(|subtable| {
+            self.apply_subst_subtable(gsub, &subtable, filter, glyphs, pos, depth)
+        })
+    }
+
+    fn apply_subst_subtable(
+        &self,
+        gsub: &Gsub<'a>,
+        subtable: &SubstitutionSubtable<'a>,
+        filter: &GlyphFilter,
+        glyphs: &mut Vec<ShapedGlyph>,
+        pos: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let glyph_id = glyphs[pos].glyph_id;
+        match subtable {
+            SubstitutionSubtable::Single(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                glyphs[pos].glyph_id = match table {
+                    // addition is modulo 65536
+                    SingleSubst::Format1(table) => {
+                        (glyph_id as i32 + table.delta_glyph_id() as i32) as u16
+                    }
+                    SingleSubst::Format2(table) => table
+                        .substitute_glyph_ids()
+                        .get(coverage_idx as usize)?
+                        .get(),
+                };
+                Some(pos + 1)
+            }
+            SubstitutionSubtable::Multiple(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                let sequence = table.sequence(coverage_idx)?;
+                let template = glyphs[pos];
+                let replacement = sequence.substitute_glyph_ids();
+                glyphs.splice(
+                    pos..=pos,
+                    replacement.iter().map(|gid| ShapedGlyph {
+                        glyph_id: gid.get(),
+                        ..template
+                    }),
+                );
+                Some(pos + replacement.len())
+            }
+            SubstitutionSubtable::Alternate(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                let alternates = table.alternate_set(coverage_idx)?;
+                glyphs[pos].glyph_id = alternates.alternate_glyph_ids().first()?.get();
+                Some(pos + 1)
+            }
+            SubstitutionSubtable::Ligature(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                let ligature_set = table.ligature_set(coverage_idx)?;
+                for ligature in ligature_set.ligatures() {
+                    let components = ligature.component_glyph_ids();
+                    let matched =
+                        match match_input(glyphs, filter, pos, components.len(), |i, gid| {
+                            components[i].get() == gid
+                        }) {
+                            Some(matched) => matched,
+                            None => continue,
+                        };
+                    let last = matched.last().copied().unwrap_or(pos);
+                    merge_clusters(&mut glyphs[pos..=last]);
+                    glyphs[pos].glyph_id = ligature.ligature_glyph();
+                    // skipped glyphs between the components are kept, and
+                    // remember which component they follow
+                    for (component, window) in matched.windows(2).enumerate() {
+                        for glyph in &mut glyphs[window[0] + 1..window[1]] {
+                            glyph.ligature_component = Some(component as u16);
+                        }
+                    }
+                    for idx in matched[1..].iter().rev() {
+                        glyphs.remove(*idx);
+                    }
+                    return Some(pos + 1);
+                }
+                None
+            }
+            SubstitutionSubtable::Contextual(table) => {
+                let (matched, records) = match_sequence_context(table, glyphs, filter, pos)?;
+                Some(self.apply_subst_records(gsub, glyphs, matched, &records, depth))
+            }
+            SubstitutionSubtable::ChainContextual(table) => {
+                let (matched, records) = match_chained_context(table, glyphs, filter, pos)?;
+                Some(self.apply_subst_records(gsub, glyphs, matched, &records, depth))
+            }
+            SubstitutionSubtable::ReverseChainContextual(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                let backtrack = table.backtrack_coverages().collect::<Vec<_>>();
+                let lookahead = table.lookahead_coverages().collect::<Vec<_>>();
+                if !match_backtrack(glyphs, filter, pos, backtrack.len(), |i, gid| {
+                    covers(&backtrack[i], gid)
+                }) || !match_lookahead(glyphs, filter, pos, lookahead.len(), |i, gid| {
+                    covers(&lookahead[i], gid)
+                }) {
+                    return None;
+                }
+                glyphs[pos].glyph_id = table
+                    .substitute_glyph_ids()
+                    .get(coverage_idx as usize)?
+                    .get();
+                Some(pos + 1)
+            }
+        }
+    }
+
+    /// Apply the nested lookups of a matched contextual rule.
+    ///
+    /// Returns the position after the matched input sequence.
+    fn apply_subst_records(
+        &self,
+        gsub: &Gsub<'a>,
+        glyphs: &mut Vec<ShapedGlyph>,
+        mut matched: Vec<usize>,
+        records: &[SequenceLookupRecord],
+        depth: usize,
+    ) -> usize {
+        let start = matched.first().copied().unwrap_or_default();
+        let mut end = matched.last().copied().unwrap_or_default() as isize + 1;
+        if depth >= MAX_NESTING_DEPTH {
+            return end as usize;
+        }
+        for record in records {
+            let seq_idx = record.sequence_index() as usize;
+            let pos = match matched.get(seq_idx) {
+                Some(pos) if *pos < glyphs.len() => *pos,
+                _ => continue,
+            };
+            let lookup = match gsub.lookup(record.lookup_list_index()) {
+                Some(lookup) => lookup,
+                None => continue,
+            };
+            let filter = GlyphFilter::new(self.gdef.as_ref(), lookup.lookup());
+            let len_before = glyphs.len() as isize;
+            if self
+                .apply_subst_at(gsub, &lookup, &filter, glyphs, pos, depth + 1)
+                .is_none()
+            {
+                continue;
+            }
+            // this is approximate if the nested lookup removed matched glyphs
+            let delta = glyphs.len() as isize - len_before;
+            for later in matched.iter_mut().skip(seq_idx + 1) {
+                *later = (*later as isize + delta).max(pos as isize) as usize;
+            }
+            end += delta;
+        }
+        end.max(start as isize + 1) as usize
+    }
+
+    fn apply_pos_lookup(&self, gpos: &Gpos<'a>, lookup_index: u16, glyphs: &mut [ShapedGlyph]) {
+        let lookup = match gpos.lookup(lookup_index) {
+            Some(lookup) => lookup,
+            None => return,
+        };
+        let filter = GlyphFilter::new(self.gdef.as_ref(), lookup.lookup());
+        let mut pos = 0;
+        while pos < glyphs.len() {
+            if !filter.skips(glyphs[pos].glyph_id) {
+                if let Some(next) = self.apply_pos_at(gpos, &lookup, &filter, glyphs, pos, 0) {
+                    pos = next.max(pos + 1);
+                    continue;
+                }
+            }
+            pos += 1;
+        }
+    }
+
+    /// Apply the first subtable of this lookup that matches at `pos`.
+    ///
+    /// Returns the position at which to continue, if anything was applied.
+    fn apply_pos_at(
+        &self,
+        gpos: &Gpos<'a>,
+        lookup: &PositioningLookup<'a>,
+        filter: &GlyphFilter,
+        glyphs: &mut [ShapedGlyph],
+        pos: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        lookup.subtables().find_map(|subtable| {
+            self.apply_pos_subtable(gpos, &subtable, filter, glyphs, pos, depth)
+        })
+    }
+
+    fn apply_pos_subtable(
+        &self,
+        gpos: &Gpos<'a>,
+        subtable: &PositioningSubtable<'a>,
+        filter: &GlyphFilter,
+        glyphs: &mut [ShapedGlyph],
+        pos: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let glyph_id = glyphs[pos].glyph_id;
+        match subtable {
+            PositioningSubtable::Single(table) => {
+                let coverage_idx = table.coverage()?.get(glyph_id)?;
+                apply_value_record(&mut glyphs[pos], &table.value_record(coverage_idx)?);
+                Some(pos + 1)
+            }
+            PositioningSubtable::Pair(table) => {
+                table.coverage()?.get(glyph_id)?;
+                let next = next_unskipped(glyphs, filter, pos)?;
+                let (first, second) = table.adjustment(glyph_id, glyphs[next].glyph_id)?;
+                apply_value_record(&mut glyphs[pos], &first);
+                apply_value_record(&mut glyphs[next], &second);
+                Some(next)
+            }
+            // cursive attachment is only needed for complex scripts
+            PositioningSubtable::Cursive(_) => None,
+            PositioningSubtable::MarkToBase(table) => {
+                let mark_idx = table.mark_coverage()?.get(glyph_id)?;
+                let base = (0..pos)
+                    .rev()
+                    .find(|i| !self.is_mark(glyphs[*i].glyph_id))?;
+                let base_idx = table.base_coverage()?.get(glyphs[base].glyph_id)?;
+                let (class, mark_anchor) = table.mark_array()?.mark_anchor(mark_idx)?;
+                let base_anchor = table.base_array()?.base_anchor(base_idx, class)?;
+                attach_mark(glyphs, pos, base, &base_anchor, &mark_anchor);
+                Some(pos + 1)
+            }
+            PositioningSubtable::MarkToLigature(table) => {
+                let mark_idx = table.mark_coverage()?.get(glyph_id)?;
+                let lig = (0..pos)
+                    .rev()
+                    .find(|i| !self.is_mark(glyphs[*i].glyph_id))?;
+                let lig_idx = table.ligature_coverage()?.get(glyphs[lig].glyph_id)?;
+                let (class, mark_anchor) = table.mark_array()?.mark_anchor(mark_idx)?;
+                // marks that follow the whole ligature attach to the last
+                // component
+                let attach = table.ligature_attach(lig_idx)?;
+                let last = attach.component_count().checked_sub(1)?;
+                let component = glyphs[pos]
+                    .ligature_component
+                    .map_or(last, |component| component.min(last));
+                let lig_anchor = attach.ligature_anchor(component, class)?;
+                attach_mark(glyphs, pos, lig, &lig_anchor, &mark_anchor);
+                Some(pos + 1)
+            }
+            PositioningSubtable::MarkToMark(table) => {
+                let mark1_idx = table.mark1_coverage()?.get(glyph_id)?;
+                let prev = prev_unskipped(glyphs, filter, pos)?;
+                let mark2_idx = table.mark2_coverage()?.get(glyphs[prev].glyph_id)?;
+                let (class, mark_anchor) = table.mark1_array()?.mark_anchor(mark1_idx)?;
+                let mark2_anchor = table.mark2_array()?.mark2_anchor(mark2_idx, class)?;
+                attach_mark(glyphs, pos, prev, &mark2_anchor, &mark_anchor);
+                Some(pos + 1)
+            }
+            PositioningSubtable::Contextual(table) => {
+                let (matched, records) = match_sequence_context(table, glyphs, filter, pos)?;
+                Some(self.apply_pos_records(gpos, glyphs, &matched, &records, depth))
+            }
+            PositioningSubtable::ChainContextual(table) => {
+                let (matched, records) = match_chained_context(table, glyphs, filter, pos)?;
+                Some(self.apply_pos_records(gpos, glyphs, &matched, &records, depth))
+            }
+        }
+    }
+
+    /// Apply the nested lookups of a matched contextual rule.
+    ///
+    /// Returns the position after the matched input sequence.
+    fn apply_pos_records(
+        &self,
+        gpos: &Gpos<'a>,
+        glyphs: &mut [ShapedGlyph],
+        matched: &[usize],
+        records: &[SequenceLookupRecord],
+        depth: usize,
+    ) -> usize {
+        let end = matched.last().copied().unwrap_or_default() + 1;
+        if depth >= MAX_NESTING_DEPTH {
+            return end;
+        }
+        for record in records {
+            let pos = match matched.get(record.sequence_index() as usize) {
+                Some(pos) => *pos,
+                None => continue,
+            };
+            if let Some(lookup) = gpos.lookup(record.lookup_list_index()) {
+                let filter = GlyphFilter::new(self.gdef.as_ref(), lookup.lookup());
+                self.apply_pos_at(gpos, &lookup, &filter, glyphs, pos, depth + 1);
+            }
+        }
+        end
+    }
+}
+
+/// Collect the `required` lookups and those for all of the features in a
+/// stage, in the order they should be applied.
+fn stage_lookups(
+    mut lookups: Vec<u16>,
+    features: &[Tag],
+    lookups_for: impl Fn(Tag) -> Vec<u16>,
+) -> Vec<u16> {
+    lookups.extend(features.iter().flat_map(|feature| lookups_for(*feature)));
+    lookups.sort_unstable();
+    lookups.dedup();
+    lookups
+}
+
+fn next_unskipped(glyphs: &[ShapedGlyph], filter: &GlyphFilter, pos: usize) -> Option<usize> {
+    (pos + 1..glyphs.len()).find(|i| !filter.skips(glyphs[*i].glyph_id))
+}
+
+fn prev_unskipped(glyphs: &[ShapedGlyph], filter: &GlyphFilter, pos: usize) -> Option<usize> {
+    (0..pos).rev().find(|i| !filter.skips(glyphs[*i].glyph_id))
+}
+
+/// Match `count` glyphs following the glyph at `start`, skipping ignored
+/// glyphs.
+///
+/// `matches` is passed the index in the sequence (not counting the first
+/// glyph) and a glyph id. Returns the positions of all matched glyphs,
+/// including `start`.
+fn match_input(
+    glyphs: &[ShapedGlyph],
+    filter: &GlyphFilter,
+    start: usize,
+    count: usize,
+    matches: impl Fn(usize, u16) -> bool,
+) -> Option<Vec<usize>> {
+    let mut matched = Vec::with_capacity(count + 1);
+    matched.push(start);
+    let mut pos = start;
+    for i in 0..count {
+        pos = next_unskipped(glyphs, filter, pos)?;
+        if !matches(i, glyphs[pos].glyph_id) {
+            return None;
+        }
+        matched.push(pos);
+    }
+    Some(matched)
+}
+
+/// Match `count` glyphs before `start`, in reverse order.
+fn match_backtrack(
+    glyphs: &[ShapedGlyph],
+    filter: &GlyphFilter,
+    start: usize,
+    count: usize,
+    matches: impl Fn(usize, u16) -> bool,
+) -> bool {
+    let mut pos = start;
+    for i in 0..count {
+        match prev_unskipped(glyphs, filter, pos) {
+            Some(prev) if matches(i, glyphs[prev].glyph_id) => pos = prev,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Match `count` glyphs after `end`, the last glyph of the input sequence.
+fn match_lookahead(
+    glyphs: &[ShapedGlyph],
+    filter: &GlyphFilter,
+    end: usize,
+    count: usize,
+    matches: impl Fn(usize, u16) -> bool,
+) -> bool {
+    let mut pos = end;
+    for i in 0..count {
+        match next_unskipped(glyphs, filter, pos) {
+            Some(next) if matches(i, glyphs[next].glyph_id) => pos = next,
+            _ => return false,
+        }
+    }
+    true
+}
+
+fn covers(coverage: &Option<CoverageTable>, glyph_id: u16) -> bool {
+    coverage
+        .as_ref()
+        .and_then(|coverage| coverage.get(glyph_id))
+        .is_some()
+}
+
+/// The class of a glyph; a missing class def puts all glyphs in class 0.
+fn class_of(class_def: &Option<ClassDef>, glyph_id: u16) -> u16 {
+    class_def
+        .as_ref()
+        .map(|class_def| class_def.get(glyph_id))
+        .unwrap_or(0)
+}
+
+fn match_sequence_context(
+    table: &SequenceContext,
+    glyphs: &[ShapedGlyph],
+    filter: &GlyphFilter,
+    pos: usize,
+) -> Option<ContextMatch> {
+    let glyph_id = glyphs[pos].glyph_id;
+    match table {
+        SequenceContext::Format1(table) => {
+            let coverage_idx = table.coverage()?.get(glyph_id)?;
+            table
+                .seq_rule_set(coverage_idx)?
+                .seq_rules()
+                .find_map(|rule| {
+                    let input = rule.input_sequence();
+                    let matched = match_input(glyphs, filter, pos, input.len(), |i, gid| {
+                        input[i].get() == gid
+                    })?;
+                    Some((matched, rule.seq_lookup_records().to_vec()))
+                })
+        }
+        SequenceContext::Format2(table) => {
+            table.coverage()?.get(glyph_id)?;
+            let class_def = table.class_def();
+            table
+                .class_seq_rule_set(class_of(&class_def, glyph_id))?
+                .class_seq_rules()
+                .find_map(|rule| {
+                    let input = rule.input_sequence();
+                    let matched = match_input(glyphs, filter, pos, input.len(), |i, gid| {
+                        input[i].get() == class_of(&class_def, gid)
+                    })?;
+                    Some((matched, rule.seq_lookup_records().to_vec()))
+                })
+        }
+        SequenceContext::Format3(table) => {
+            let coverages = table.coverages().collect::<Vec<_>>();
+            let (first, rest) = coverages.split_first()?;
+            if !covers(first, glyph_id) {
+                return None;
+            }
+            let matched = match_input(glyphs, filter, pos, rest.len(), |i, gid| {
+                covers(&rest[i], gid)
+            })?;
+            Some((matched, table.seq_lookup_records().to_vec()))
+        }
+    }
+}
+
+fn match_chained_context(
+    table: &ChainedSequenceContext,
+    glyphs: &[ShapedGlyph],
+    filter: &GlyphFilter,
+    pos: usize,
+) -> Option<ContextMatch> {
+    let glyph_id = glyphs[pos].glyph_id;
+    match table {
+        ChainedSequenceContext::Format1(table) => {
+            let coverage_idx = table.coverage()?.get(glyph_id)?;
+            table
+                .chained_seq_rule_set(coverage_idx)?
+                .chained_seq_rules()
+                .find_map(|rule| {
+                    let input = rule.input_sequence();
+                    let backtrack = rule.backtrack_sequence();
+                    let lookahead = rule.lookahead_sequence();
+                    let matched = match_input(glyphs, filter, pos, input.len(), |i, gid| {
+                        input[i].get() == gid
+                    })?;
+                    let end = *matched.last()?;
+                    (match_backtrack(glyphs, filter, pos, backtrack.len(), |i, gid| {
+                        backtrack[i].get() == gid
+                    }) && match_lookahead(glyphs, filter, end, lookahead.len(), |i, gid| {
+                        lookahead[i].get() == gid
+                    }))
+                    .then(|| (matched, rule.seq_lookup_records().to_vec()))
+                })
+        }
+        ChainedSequenceContext::Format2(table) => {
+            table.coverage()?.get(glyph_id)?;
+            let backtrack_classes = table.backtrack_class_def();
+            let input_classes = table.input_class_def();
+            let lookahead_classes = table.lookahead_class_def();
+            table
+                .chained_class_seq_rule_set(class_of(&input_classes, glyph_id))?
+                .chained_class_seq_rules()
+                .find_map(|rule| {
+                    let input = rule.input_sequence();
+                    let backtrack = rule.backtrack_sequence();
+                    let lookahead = rule.lookahead_sequence();
+                    let matched = match_input(glyphs, filter, pos, input.len(), |i, gid| {
+                        input[i].get() == class_of(&input_classes, gid)
+                    })?;
+                    let end = *matched.last()?;
+                    (match_backtrack(glyphs, filter, pos, backtrack.len(), |i, gid| {
+                        backtrack[i].get() == class_of(&backtrack_classes, gid)
+                    }) && match_lookahead(glyphs, filter, end, lookahead.len(), |i, gid| {
+                        lookahead[i].get() == class_of(&lookahead_classes, gid)
+                    }))
+                    .then(|| (matched, rule.seq_lookup_records().to_vec()))
+                })
+        }
+        ChainedSequenceContext::Format3(table) => {
+            let input = table.input_coverages().collect::<Vec<_>>();
+            let backtrack = table.backtrack_coverages().collect::<Vec<_>>();
+            let lookahead = table.lookahead_coverages().collect::<Vec<_>>();
+            let (first, rest) = input.split_first()?;
+            if !covers(first, glyph_id) {
+                return None;
+            }
+            let matched = match_input(glyphs, filter, pos, rest.len(), |i, gid| {
+                covers(&rest[i], gid)
+            })?;
+            let end = *matched.last()?;
+            (match_backtrack(glyphs, filter, pos, backtrack.len(), |i, gid| {
+                covers(&backtrack[i], gid)
+            }) && match_lookahead(glyphs, filter, end, lookahead.len(), |i, gid| {
+                covers(&lookahead[i], gid)
+            }))
+            .then(|| (matched, table.seq_lookup_records().to_vec()))
+        }
+    }
+}
+
+/// Set all glyphs in the range to the smallest cluster in the range.
+fn merge_clusters(glyphs: &mut [ShapedGlyph]) {
+    if let Some(cluster) = glyphs.iter().map(|glyph| glyph.cluster).min() {
+        glyphs.iter_mut().for_each(|glyph| glyph.cluster = cluster);
+    }
+}
+
+fn apply_value_record(glyph: &mut ShapedGlyph, record: &ValueRecord) {
+    glyph.x_offset += record.x_placement.unwrap_or(0) as i32;
+    glyph.y_offset += record.y_placement.unwrap_or(0) as i32;
+    glyph.x_advance += record.x_advance.unwrap_or(0) as i32;
+    glyph.y_advance += record.y_advance.unwrap_or(0) as i32;
+}
+
+/// Position the mark at `mark` so that its anchor is aligned with the anchor
+/// of the glyph at `base`.
+fn attach_mark(
+    glyphs: &mut [ShapedGlyph],
+    mark: usize,
+    base: usize,
+    base_anchor: &AnchorTable,
+    mark_anchor: &AnchorTable,
+) {
+    // offsets are relative to the mark's own origin
+    let advance = glyphs[base..mark]
+        .iter()
+        .map(|glyph| glyph.x_advance)
+        .sum::<i32>();
+    let base = glyphs[base];
+    let glyph = &mut glyphs[mark];
+    glyph.x_offset = base.x_offset + base_anchor.x_coordinate() as i32
+        - mark_anchor.x_coordinate() as i32
+        - advance;
+    glyph.y_offset =
+        base.y_offset + base_anchor.y_coordinate() as i32 - mark_anchor.y_coordinate() as i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::layout::test_helpers::{
+        class_def, coverage, layout_table, layout_table_with_required, lookup, table, u16_array,
+    };
+    use crate::tables::{gdef, gpos, gsub};
+    use crate::FontRef;
+
+    static INCONSOLATA: &[u8] = include_bytes!("../../resources/Inconsolata-Regular.ttf");
+
+    // glyph ids in Inconsolata; all but the marks have an advance of 500
+    const A: u16 = 235;
+    const B: u16 = 262;
+    const C: u16 = 264;
+    const D: u16 = 271;
+    const E: u16 = 277;
+    const GRAVE: u16 = 717;
+    const ACUTE: u16 = 718;
+    const LIGATURE: u16 = 100;
+    // 'x', which we treat as a mark with a non-zero advance
+    const SPACING_MARK: u16 = 455;
+
+    const IGNORE_MARKS: u16 = 8;
+
+    /// Inconsolata's cmap and metrics, with our own layout tables.
+    struct TestFont<'a> {
+        font: FontRef<'a>,
+        gsub: Vec<u8>,
+        gpos: Vec<u8>,
+        gdef: Vec<u8>,
+    }
+
+    impl TableProvider for TestFont<'_> {
+        fn data_for_tag(&self, tag: Tag) -> Option<&[u8]> {
+            match tag {
+                gsub::TAG => Some(&self.gsub),
+                gpos::TAG => Some(&self.gpos),
+                gdef::TAG => Some(&self.gdef),
+                _ => self.font.table_data(tag),
+            }
+        }
+    }
+
+    /// Shape `text` with these GSUB and GPOS tables.
+    ///
+    /// The accents and `SPACING_MARK` are marks and `LIGATURE` is a ligature.
+    fn shape(gsub: Vec<u8>, gpos: Vec<u8>, text: &str) -> Vec<ShapedGlyph> {
+        let gdef = table(
+            |offsets| u16_array(&[1, 0, offsets[0], 0, 0, 0]),
+            &[class_def(&[
+                (LIGATURE, 2),
+                (SPACING_MARK, 3),
+                (GRAVE, 3),
+                (ACUTE, 3),
+            ])],
+        );
+        let font = TestFont {
+            font: FontRef::new(INCONSOLATA).unwrap(),
+            gsub,
+            gpos,
+            gdef,
+        };
+        Shaper::new(&font).shape(text, Tag::new(b"latn"), Tag::new(b"dflt"))
+    }
+
+    fn ids(glyphs: &[ShapedGlyph]) -> Vec<u16> {
+        glyphs.iter().map(|glyph| glyph.glyph_id).collect()
+    }
+
+    fn clusters(glyphs: &[ShapedGlyph]) -> Vec<u32> {
+        glyphs.iter().map(|glyph| glyph.cluster).collect()
+    }
+
+    /// The advance and offsets of each glyph.
+    fn positions(glyphs: &[ShapedGlyph]) -> Vec<(i32, i32, i32)> {
+        glyphs
+            .iter()
+            .map(|glyph| (glyph.x_advance, glyph.x_offset, glyph.y_offset))
+            .collect()
+    }
+
+    fn anchor(x: i16, y: i16) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(1u16);
+        buf.extend([x, y]);
+        buf.to_vec()
+    }
+
+    /// A format 1 single substitution for these glyphs.
+    fn single(glyphs: &[u16], delta: i16) -> Vec<u8> {
+        table(
+            |offsets| {
+                let mut buf = u16_array(&[1, offsets[0]]);
+                buf.push(delta);
+                buf
+            },
+            &[coverage(glyphs)],
+        )
+    }
+
+    /// A format 1 single positioning subtable, adjusting the x advance of
+    /// these glyphs.
+    fn single_pos(glyphs: &[u16], x_advance: i16) -> Vec<u8> {
+        table(
+            |offsets| {
+                let mut buf = u16_array(&[1, offsets[0], 4]);
+                buf.push(x_advance);
+                buf
+            },
+            &[coverage(glyphs)],
+        )
+    }
+
+    /// A format 3 contextual subtable for this input, applying `lookup` to
+    /// the last glyph.
+    fn context(input: &[u16], lookup: u16) -> Vec<u8> {
+        let coverages = input
+            .iter()
+            .map(|gid| coverage(&[*gid]))
+            .collect::<Vec<_>>();
+        table(
+            |offsets| {
+                let mut buf = u16_array(&[3, input.len() as u16, 1]);
+                buf.extend(offsets.iter().copied());
+                buf.extend([input.len() as u16 - 1, lookup]);
+                buf
+            },
+            &coverages,
+        )
+    }
+
+    /// A format 3 chained contextual subtable, applying `lookup` to the
+    /// first input glyph.
+    fn chained_context(
+        backtrack: &[u16],
+        input: &[u16],
+        lookahead: &[u16],
+        lookup: u16,
+    ) -> Vec<u8> {
+        let coverages = backtrack
+            .iter()
+            .chain(input)
+            .chain(lookahead)
+            .map(|gid| coverage(&[*gid]))
+            .collect::<Vec<_>>();
+        table(
+            |offsets| {
+                let (backtrack_offsets, rest) = offsets.split_at(backtrack.len());
+                let (input_offsets, lookahead_offsets) = rest.split_at(input.len());
+                let mut buf = u16_array(&[3]);
+                for offsets in [backtrack_offsets, input_offsets, lookahead_offsets] {
+                    buf.push(offsets.len() as u16);
+                    buf.extend(offsets.iter().copied());
+                }
+                buf.extend([1, 0, lookup]);
+                buf
+            },
+            &coverages,
+        )
+    }
+
+    fn ligature_gsub() -> Vec<u8> {
+        let ligature_set = table(
+            |offsets| u16_array(&[1, offsets[0]]),
+            &[u16_array(&[LIGATURE, 2, B]).to_vec()],
+        );
+        let ligature = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[A]), ligature_set],
+        );
+        layout_table(
+            &[(Tag::new(b"liga"), &[0])],
+            &[lookup(4, IGNORE_MARKS, &[ligature])],
+        )
+    }
+
+    #[test]
+    fn lookups_are_merged_in_order() {
+        let lookups = stage_lookups(vec![5, 2], &[Tag::new(b"liga"), Tag::new(b"calt")], |tag| {
+            if tag == Tag::new(b"liga") {
+                vec![4, 1]
+            } else {
+                vec![1, 2]
+            }
+        });
+        assert_eq!(lookups, [1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn marks_are_positioned() {
+        let font = FontRef::new(INCONSOLATA).unwrap();
+        let shaper = Shaper::new(&font);
+        let glyphs = shaper.shape("a\u{301}", Tag::new(b"latn"), Tag::new(b"dflt"));
+        let ids = glyphs.iter().map(|g| g.glyph_id).collect::<Vec<_>>();
+        assert_eq!(ids, [235, 718]);
+        assert_eq!(glyphs[0].cluster, 0);
+        assert_eq!(glyphs[1].cluster, 1);
+        assert_eq!(glyphs[0].x_advance, 500);
+        assert_eq!(glyphs[1].x_advance, 0);
+        assert_ne!(glyphs[1].x_offset, 0);
+    }
+
+    #[test]
+    fn single_substitution() {
+        let format2 = table(
+            |offsets| u16_array(&[2, offsets[0], 1, 300]),
+            &[coverage(&[B])],
+        );
+        // an extension lookup wrapping a single substitution
+        let mut extension = BeBuffer::new();
+        extension.extend([1u16, 1]);
+        extension.push(8u32);
+        extension.extend(single(&[C], 2).iter().copied());
+        let gsub = layout_table(
+            &[(Tag::new(b"ccmp"), &[0, 1, 2])],
+            &[
+                lookup(1, 0, &[single(&[A], -1)]),
+                lookup(1, 0, &[format2]),
+                lookup(7, 0, &[extension.to_vec()]),
+            ],
+        );
+        let glyphs = shape(gsub, Vec::new(), "abcd");
+        assert_eq!(ids(&glyphs), [A - 1, 300, C + 2, D]);
+        assert_eq!(clusters(&glyphs), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn multiple_and_alternate_substitution() {
+        let multiple = table(
+            |offsets| u16_array(&[1, offsets[0], 2, offsets[1], offsets[2]]),
+            &[
+                coverage(&[A, B]),
+                u16_array(&[2, D, E]).to_vec(),
+                u16_array(&[0]).to_vec(),
+            ],
+        );
+        let alternate = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[C]), u16_array(&[2, 300, 301]).to_vec()],
+        );
+        let gsub = layout_table(
+            &[(Tag::new(b"ccmp"), &[0, 1])],
+            &[lookup(2, 0, &[multiple]), lookup(3, 0, &[alternate])],
+        );
+        // 'b' is deleted, and the first alternate is used
+        let glyphs = shape(gsub, Vec::new(), "abc");
+        assert_eq!(ids(&glyphs), [D, E, 300]);
+        assert_eq!(clusters(&glyphs), [0, 0, 2]);
+        assert_eq!(positions(&glyphs), [(500, 0, 0); 3]);
+    }
+
+    #[test]
+    fn ligature_substitution() {
+        let glyphs = shape(ligature_gsub(), Vec::new(), "ab");
+        assert_eq!(ids(&glyphs), [LIGATURE]);
+        assert_eq!(clusters(&glyphs), [0]);
+
+        // marks are skipped, and kept after the ligature
+        let glyphs = shape(ligature_gsub(), Vec::new(), "a\u{301}bc");
+        assert_eq!(ids(&glyphs), [LIGATURE, ACUTE, C]);
+        assert_eq!(clusters(&glyphs), [0, 0, 4]);
+
+        let glyphs = shape(ligature_gsub(), Vec::new(), "ac");
+        assert_eq!(ids(&glyphs), [A, C]);
+    }
+
+    #[test]
+    fn contextual_substitution() {
+        let gsub = layout_table(
+            &[(Tag::new(b"calt"), &[0])],
+            &[
+                lookup(5, 0, &[context(&[A, B], 1)]),
+                lookup(1, 0, &[single(&[B], 1)]),
+            ],
+        );
+        let glyphs = shape(gsub.clone(), Vec::new(), "abb");
+        assert_eq!(ids(&glyphs), [A, B + 1, B]);
+        let glyphs = shape(gsub, Vec::new(), "cb");
+        assert_eq!(ids(&glyphs), [C, B]);
+    }
+
+    #[test]
+    fn chained_contextual_substitution() {
+        let gsub = layout_table(
+            &[(Tag::new(b"calt"), &[0])],
+            &[
+                lookup(6, 0, &[chained_context(&[A], &[B, C], &[D], 1)]),
+                lookup(1, 0, &[single(&[B], 1)]),
+            ],
+        );
+        let glyphs = shape(gsub.clone(), Vec::new(), "abcd");
+        assert_eq!(ids(&glyphs), [A, B + 1, C, D]);
+        // missing backtrack or lookahead
+        let glyphs = shape(gsub.clone(), Vec::new(), "bbcd");
+        assert_eq!(ids(&glyphs), [B, B, C, D]);
+        let glyphs = shape(gsub, Vec::new(), "abce");
+        assert_eq!(ids(&glyphs), [A, B, C, E]);
+    }
+
+    #[test]
+    fn reverse_chain_substitution() {
+        // 'a' before 'a' or 'b'
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], 0, 1, offsets[1], 1, 300]),
+            &[coverage(&[A]), coverage(&[A, B])],
+        );
+        let gsub = layout_table(&[(Tag::new(b"calt"), &[0])], &[lookup(8, 0, &[subtable])]);
+        // applied from the end, so the first 'a' sees the substituted glyph
+        let glyphs = shape(gsub, Vec::new(), "aab");
+        assert_eq!(ids(&glyphs), [A, 300, B]);
+    }
+
+    #[test]
+    fn required_features() {
+        // neither feature is one we apply, but both are required
+        let gsub = layout_table_with_required(
+            Some(0),
+            &[(Tag::new(b"abvs"), &[0])],
+            &[lookup(1, 0, &[single(&[A], 1)])],
+        );
+        let gpos = layout_table_with_required(
+            Some(0),
+            &[(Tag::new(b"dist"), &[0])],
+            &[lookup(1, 0, &[single_pos(&[B], -40)])],
+        );
+        let glyphs = shape(gsub, gpos, "ab");
+        assert_eq!(ids(&glyphs), [A + 1, B]);
+        assert_eq!(positions(&glyphs), [(500, 0, 0), (460, 0, 0)]);
+    }
+
+    #[test]
+    fn single_and_pair_positioning() {
+        let mut single = u16_array(&[1, 10, 3]);
+        single.extend([10i16, 20]);
+        single.extend(coverage(&[E]).iter().copied());
+        // 'a' before 'b'
+        let mut pair_set = u16_array(&[1, B]);
+        pair_set.push(-50i16);
+        let format1 = table(
+            |offsets| u16_array(&[1, offsets[0], 4, 0, 1, offsets[1]]),
+            &[coverage(&[A]), pair_set.to_vec()],
+        );
+        // class 1 ('c') before class 1 ('d')
+        let format2 = table(
+            |offsets| {
+                let mut buf = u16_array(&[2, offsets[0], 4, 0, offsets[1], offsets[2], 2, 2]);
+                buf.extend([0i16, 0, 0, -30]);
+                buf
+            },
+            &[coverage(&[C]), class_def(&[(C, 1)]), class_def(&[(D, 1)])],
+        );
+        let gpos = layout_table(
+            &[(Tag::new(b"kern"), &[0, 1, 2])],
+            &[
+                lookup(1, 0, &[single.to_vec()]),
+                lookup(2, 0, &[format1]),
+                lookup(2, 0, &[format2]),
+            ],
+        );
+        let glyphs = shape(Vec::new(), gpos, "abcdea");
+        assert_eq!(
+            positions(&glyphs),
+            [
+                (450, 0, 0),
+                (500, 0, 0),
+                (470, 0, 0),
+                (500, 0, 0),
+                (500, 10, 20),
+                (500, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn contextual_positioning() {
+        let gpos = layout_table(
+            &[(Tag::new(b"kern"), &[0, 1])],
+            &[
+                lookup(7, 0, &[context(&[A, B], 2)]),
+                lookup(8, 0, &[chained_context(&[C], &[B], &[], 2)]),
+                lookup(1, 0, &[single_pos(&[B], -40)]),
+            ],
+        );
+        let glyphs = shape(Vec::new(), gpos, "abcbbb");
+        let advances = glyphs
+            .iter()
+            .map(|glyph| glyph.x_advance)
+            .collect::<Vec<_>>();
+        assert_eq!(advances, [500, 460, 500, 460, 500, 500]);
+    }
+
+    #[test]
+    fn mark_to_base_and_mark_to_mark() {
+        let mark_array = table(|offsets| u16_array(&[1, 0, offsets[0]]), &[anchor(0, 600)]);
+        let base_array = table(|offsets| u16_array(&[1, offsets[0]]), &[anchor(250, 700)]);
+        let mark_base = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 1, offsets[2], offsets[3]]),
+            &[coverage(&[ACUTE]), coverage(&[A]), mark_array, base_array],
+        );
+        let mark1_array = table(|offsets| u16_array(&[1, 0, offsets[0]]), &[anchor(0, 500)]);
+        let mark2_array = table(|offsets| u16_array(&[1, offsets[0]]), &[anchor(-10, 800)]);
+        let mark_mark = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 1, offsets[2], offsets[3]]),
+            &[
+                coverage(&[GRAVE]),
+                coverage(&[ACUTE]),
+                mark1_array,
+                mark2_array,
+            ],
+        );
+        let gpos = layout_table(
+            &[(Tag::new(b"mark"), &[0]), (Tag::new(b"mkmk"), &[1])],
+            &[lookup(4, 0, &[mark_base]), lookup(6, 0, &[mark_mark])],
+        );
+        let glyphs = shape(Vec::new(), gpos, "a\u{301}\u{300}b");
+        assert_eq!(ids(&glyphs), [A, ACUTE, GRAVE, B]);
+        assert_eq!(
+            positions(&glyphs),
+            [(500, 0, 0), (0, -250, 100), (0, -260, 400), (500, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn mark_advances_are_zeroed() {
+        let mark_array = table(|offsets| u16_array(&[1, 0, offsets[0]]), &[anchor(0, 600)]);
+        let base_array = table(|offsets| u16_array(&[1, offsets[0]]), &[anchor(250, 700)]);
+        let mark_base = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 1, offsets[2], offsets[3]]),
+            &[
+                coverage(&[SPACING_MARK]),
+                coverage(&[A]),
+                mark_array,
+                base_array,
+            ],
+        );
+        let gpos = layout_table(&[(Tag::new(b"mark"), &[0])], &[lookup(4, 0, &[mark_base])]);
+        // the mark has an advance of 500 in hmtx
+        let glyphs = shape(Vec::new(), gpos, "axb");
+        assert_eq!(ids(&glyphs), [A, SPACING_MARK, B]);
+        assert_eq!(
+            positions(&glyphs),
+            [(500, 0, 0), (0, -250, 100), (500, 0, 0)]
+        );
+        // marks don't advance even without GPOS
+        let glyphs = shape(Vec::new(), Vec::new(), "xb");
+        assert_eq!(positions(&glyphs), [(0, 0, 0), (500, 0, 0)]);
+    }
+
+    #[test]
+    fn mark_to_ligature() {
+        let mark_array = table(
+            |offsets| u16_array(&[2, 0, offsets[0], 0, offsets[1]]),
+            &[anchor(0, 600), anchor(0, 600)],
+        );
+        let ligature_attach = table(
+            |offsets| u16_array(&[2, offsets[0], offsets[1]]),
+            &[anchor(100, 700), anchor(400, 700)],
+        );
+        let ligature_array = table(|offsets| u16_array(&[1, offsets[0]]), &[ligature_attach]);
+        let mark_ligature = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 1, offsets[2], offsets[3]]),
+            &[
+                coverage(&[GRAVE, ACUTE]),
+                coverage(&[LIGATURE]),
+                mark_array,
+                ligature_array,
+            ],
+        );
+        let gpos = layout_table(
+            &[(Tag::new(b"mark"), &[0])],
+            &[lookup(5, 0, &[mark_ligature])],
+        );
+        // the acute follows the first component, the grave the whole ligature
+        let glyphs = shape(ligature_gsub(), gpos, "a\u{301}b\u{300}");
+        assert_eq!(ids(&glyphs), [LIGATURE, ACUTE, GRAVE]);
+        assert_eq!(clusters(&glyphs), [0, 0, 4]);
+        assert_eq!(
+            positions(&glyphs),
+            [(500, 0, 0), (0, -400, 100), (0, -100, 100)]
+        );
+    }
+}
@@ -1,17 +1,32 @@
 //! Font tables.
 
+pub mod avar;
+pub mod cff;
+pub mod cff2;
 pub mod cmap;
+pub mod fvar;
 pub mod gdef;
 pub mod glyf;
+pub mod gpos;
+pub mod gsub;
+pub mod gvar;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
+pub mod hvar;
 pub mod loca;
 pub mod maxp;
+pub mod mvar;
 pub mod name;
+pub mod os2;
 pub mod post;
 pub mod stat;
+pub mod vhea;
+pub mod vmtx;
+pub mod vvar;
 
+#[cfg(feature = "std")]
+use font_types::{F2Dot14, OffsetHost};
 use font_types::{FontRead, Tag};
 
 /// An interface for accessing tables from a font (or font-like object)
@@ -35,7 +50,19 @@ pub trait TableProvider {
         let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
         let number_of_h_metrics = self.hhea().map(|hhea| hhea.number_of_h_metrics())?;
         self.data_for_tag(hmtx::TAG).and_then(|data| {
-            hmtx::Hmtx::read(data, num_glyphs as usize, number_of_h_metrics as usize)
+            hmtx::Hmtx::read(data, number_of_h_metrics as usize, num_glyphs as usize)
+        })
+    }
+
+    fn vhea(&self) -> Option<vhea::Vhea> {
+        self.data_for_tag(vhea::TAG).and_then(vhea::Vhea::read)
+    }
+
+    fn vmtx(&self) -> Option<vmtx::Vmtx<'_>> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        let number_of_metrics = self.vhea()?.number_of_long_ver_metrics();
+        self.data_for_tag(vmtx::TAG).and_then(|data| {
+            vmtx::Vmtx::read(data, number_of_metrics as usize, num_glyphs as usize)
         })
     }
 
@@ -51,9 +78,11 @@ pub trait TableProvider {
         self.data_for_tag(stat::TAG).and_then(stat::Stat::read)
     }
 
-    fn loca(&self, num_glyphs: u16, is_long: bool) -> Option<loca::Loca> {
+    fn loca(&self) -> Option<loca::Loca> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        let format = self.head().and_then(|head| head.loca_format())?;
         let bytes = self.data_for_tag(loca::TAG)?;
-        loca::Loca::read(bytes, num_glyphs, is_long)
+        loca::Loca::read(bytes, num_glyphs, format)
     }
 
     fn glyf(&self) -> Option<glyf::Glyf> {
@@ -61,11 +90,153 @@ pub trait TableProvider {
     }
 
     fn cmap(&self) -> Option<cmap::Cmap> {
-        self.data_for_tag(Tag::new(b"cmap"))
-            .and_then(cmap::Cmap::read)
+        self.data_for_tag(cmap::TAG).and_then(cmap::Cmap::read)
     }
 
     fn gdef(&self) -> Option<gdef::Gdef> {
         self.data_for_tag(gdef::TAG).and_then(gdef::Gdef::read)
     }
+
+    fn gsub(&self) -> Option<gsub::Gsub<'_>> {
+        self.data_for_tag(gsub::TAG).and_then(gsub::Gsub::read)
+    }
+
+    fn gpos(&self) -> Option<gpos::Gpos<'_>> {
+        self.data_for_tag(gpos::TAG).and_then(gpos::Gpos::read)
+    }
+
+    fn fvar(&self) -> Option<fvar::Fvar<'_>> {
+        self.data_for_tag(fvar::TAG).and_then(fvar::Fvar::read)
+    }
+
+    fn avar(&self) -> Option<avar::Avar<'_>> {
+        self.data_for_tag(avar::TAG).and_then(avar::Avar::read)
+    }
+
+    fn gvar(&self) -> Option<gvar::Gvar<'_>> {
+        self.data_for_tag(gvar::TAG).and_then(gvar::Gvar::read)
+    }
+
+    fn hvar(&self) -> Option<hvar::Hvar<'_>> {
+        self.data_for_tag(hvar::TAG).and_then(hvar::Hvar::read)
+    }
+
+    fn vvar(&self) -> Option<vvar::Vvar<'_>> {
+        self.data_for_tag(vvar::TAG).and_then(vvar::Vvar::read)
+    }
+
+    fn mvar(&self) -> Option<mvar::Mvar<'_>> {
+        self.data_for_tag(mvar::TAG).and_then(mvar::Mvar::read)
+    }
+
+    fn os2(&self) -> Option<os2::Os2<'_>> {
+        self.data_for_tag(os2::TAG).and_then(os2::Os2::read)
+    }
+
+    fn cff(&self) -> Option<cff::Cff<'_>> {
+        self.data_for_tag(cff::TAG).and_then(cff::Cff::read)
+    }
+
+    fn cff2(&self) -> Option<cff2::Cff2<'_>> {
+        self.data_for_tag(cff2::TAG).and_then(cff2::Cff2::read)
+    }
+
+    /// The advance width of a glyph, at a location in design space.
+    ///
+    /// Variations come from `HVAR` if it is present, and otherwise from the
+    /// glyph's varied phantom points in `gvar`.
+    #[cfg(feature = "std")]
+    fn advance(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let hmtx = self.hmtx()?;
+        let advance = hmtx.advance(glyph_id)?;
+        if let Some(hvar) = self.hvar() {
+            let delta = hvar.advance_width_delta(glyph_id, coords);
+            return Some(advance as f32 + delta.unwrap_or_default());
+        }
+        let gvar = match self.gvar() {
+            Some(gvar) => gvar,
+            None => return Some(advance as f32),
+        };
+        let range = self.loca()?.glyph_range(glyph_id)?;
+        // empty glyphs have no outline, but may still have varied metrics
+        let glyph = self.glyf()?.bytes().get(range).and_then(glyf::Glyph::read);
+        let lsb = hmtx.lsb(glyph_id).unwrap_or_default();
+        let phantom_points = glyf::phantom_points(glyph.as_ref(), advance, lsb, 0, 0);
+        let varied = gvar.varied_glyph(glyph_id, glyph.as_ref(), phantom_points, coords);
+        Some(varied.advance_width() as f32)
+    }
+
+    /// The advance height of a glyph, at a location in design space.
+    ///
+    /// Variations come from `VVAR` if it is present, and otherwise from the
+    /// glyph's varied phantom points in `gvar`.
+    #[cfg(feature = "std")]
+    fn vertical_advance(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let vmtx = self.vmtx()?;
+        let advance = vmtx.advance(glyph_id)?;
+        if let Some(vvar) = self.vvar() {
+            let delta = vvar.advance_height_delta(glyph_id, coords);
+            return Some(advance as f32 + delta.unwrap_or_default());
+        }
+        let gvar = match self.gvar() {
+            Some(gvar) => gvar,
+            None => return Some(advance as f32),
+        };
+        let range = self.loca()?.glyph_range(glyph_id)?;
+        let glyph = self.glyf()?.bytes().get(range).and_then(glyf::Glyph::read);
+        let tsb = vmtx.tsb(glyph_id).unwrap_or_default();
+        let phantom_points = glyf::phantom_points(glyph.as_ref(), 0, 0, advance, tsb);
+        let varied = gvar.varied_glyph(glyph_id, glyph.as_ref(), phantom_points, coords);
+        Some(varied.advance_height() as f32)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    //! Utilities for building fonts from individual tables in tests.
+
+    use font_types::{test_helpers::BeBuffer, Tag};
+
+    use super::TableProvider;
+
+    /// A font made from a list of tables.
+    pub struct TableList(pub Vec<(Tag, Vec<u8>)>);
+
+    impl TableProvider for TableList {
+        fn data_for_tag(&self, tag: Tag) -> Option<&[u8]> {
+            self.0
+                .iter()
+                .find(|(table_tag, _)| *table_tag == tag)
+                .map(|(_, data)| data.as_slice())
+        }
+    }
+
+    /// A version 0.5 maxp table.
+    pub fn maxp(num_glyphs: u16) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(0x00005000u32);
+        buf.push(num_glyphs);
+        buf.to_vec()
+    }
+
+    /// An hhea or vhea table, which share a layout; only the number of long
+    /// metrics is set.
+    pub fn metrics_header(number_of_long_metrics: u16) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16; 17]);
+        buf.push(number_of_long_metrics);
+        buf.to_vec()
+    }
+
+    /// An hmtx or vmtx table, with these long metrics as (advance, side
+    /// bearing) pairs, followed by these side bearings.
+    pub fn metrics(long_metrics: &[(u16, i16)], side_bearings: &[i16]) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        for (advance, side_bearing) in long_metrics {
+            buf.push(*advance);
+            buf.push(*side_bearing);
+        }
+        buf.extend(side_bearings.iter().copied());
+        buf.to_vec()
+    }
 }
@@ -0,0 +1,185 @@
+//! the [avar] table
+//!
+//! [avar]: https://docs.microsoft.com/en-us/typography/opentype/spec/avar
+
+#[path = "../../generated/generated_avar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{BigEndian, F2Dot14, FontRead, Offset32, OffsetHost, Tag, VarArray, VarSized};
+
+use crate::variations::{DeltaSetIndexMap, ItemVariationStore};
+
+/// 'avar'
+pub const TAG: Tag = Tag::new(b"avar");
+
+/// The length of the header, which is followed by the segment maps.
+const HEADER_LEN: usize = 8;
+
+impl<'a> Avar<'a> {
+    /// The segment maps, with one for each axis.
+    pub fn axis_segment_maps(&self) -> impl Iterator<Item = SegmentMaps<'a>> + 'a {
+        let data = self.bytes().get(HEADER_LEN..).unwrap_or_default();
+        VarArray::new(data).iter().take(self.axis_count() as usize)
+    }
+
+    /// The offsets to the axis index map and the item variation store,
+    /// which follow the segment maps in version 2.
+    fn version_2_offsets(&self) -> Option<(Offset32, Offset32)> {
+        if self.major_version() < 2 {
+            return None;
+        }
+        let segment_maps_len = self.axis_segment_maps().map(|map| map.len()).sum::<usize>();
+        let bytes = self.bytes().get(HEADER_LEN + segment_maps_len..)?;
+        let axis_index_map = BigEndian::<Offset32>::read(bytes)?;
+        let var_store = BigEndian::<Offset32>::read(bytes.get(4..)?)?;
+        Some((axis_index_map.get(), var_store.get()))
+    }
+
+    /// The map from axis indices to delta-set indices, in version 2.
+    ///
+    /// If this is missing, axis indices are used as inner indices.
+    pub fn axis_index_map(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.version_2_offsets()
+            .and_then(|(offset, _)| self.resolve_offset(offset))
+    }
+
+    /// The item variation store, in version 2.
+    pub fn var_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.version_2_offsets()
+            .and_then(|(_, offset)| self.resolve_offset(offset))
+    }
+
+    /// Modify coordinates that have been normalized using the default
+    /// normalization from `fvar`.
+    ///
+    /// The segment maps are applied first, followed by any version 2 deltas.
+    #[cfg(feature = "std")]
+    pub fn map_coords(&self, coords: &mut [F2Dot14]) {
+        for (coord, segment_map) in coords.iter_mut().zip(self.axis_segment_maps()) {
+            *coord = segment_map.apply(*coord);
+        }
+        let var_store = match self.var_store() {
+            Some(var_store) => var_store,
+            None => return,
+        };
+        let axis_index_map = self.axis_index_map();
+        // deltas are all computed from the coordinates after segment mapping
+        let mapped = coords.to_owned();
+        for (axis_idx, coord) in coords.iter_mut().enumerate() {
+            let delta =
+                var_store.compute_mapped_delta(axis_index_map.as_ref(), axis_idx as u32, &mapped);
+            if let Some(delta) = delta {
+                // deltas are in F2Dot14 units
+                let value = coord.to_f32() + delta / 16384.0;
+                *coord = F2Dot14::from_f32(value.clamp(-1.0, 1.0));
+            }
+        }
+    }
+}
+
+impl<'a> SegmentMaps<'a> {
+    /// Map a coordinate through the piecewise linear function defined by
+    /// this segment map.
+    pub fn apply(&self, coord: F2Dot14) -> F2Dot14 {
+        let maps = self.axis_value_maps();
+        let (first, last) = match (maps.first(), maps.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return coord,
+        };
+        let value = coord.to_f32();
+        // coordinates outside the map are shifted by the nearest delta
+        if coord < first.from_coordinate() {
+            let delta = first.to_coordinate().to_f32() - first.from_coordinate().to_f32();
+            return F2Dot14::from_f32(value + delta);
+        }
+        if coord > last.from_coordinate() {
+            let delta = last.to_coordinate().to_f32() - last.from_coordinate().to_f32();
+            return F2Dot14::from_f32(value + delta);
+        }
+        if let Some(map) = maps.iter().find(|map| map.from_coordinate() == coord) {
+            return map.to_coordinate();
+        }
+        let end = match maps.iter().position(|map| map.from_coordinate() > coord) {
+            Some(idx) if idx > 0 => idx,
+            _ => return coord,
+        };
+        let (start, end) = (&maps[end - 1], &maps[end]);
+        let (from_start, from_end) = (
+            start.from_coordinate().to_f32(),
+            end.from_coordinate().to_f32(),
+        );
+        let (to_start, to_end) = (start.to_coordinate().to_f32(), end.to_coordinate().to_f32());
+        let t = (value - from_start) / (from_end - from_start);
+        F2Dot14::from_f32(to_start + t * (to_end - to_start))
+    }
+}
+
+impl<'a> VarSized<'a> for SegmentMaps<'a> {
+    fn len(&self) -> usize {
+        2 + std::mem::size_of_val(self.axis_value_maps())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::variations::test_helpers::{f2dot14, var_store};
+
+    fn push_segment_map(buf: &mut BeBuffer, maps: &[(f32, f32)]) {
+        buf.push(maps.len() as u16);
+        for (from, to) in maps {
+            buf.extend([f2dot14(*from), f2dot14(*to)]);
+        }
+    }
+
+    #[test]
+    fn segment_maps() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 0, 2]);
+        push_segment_map(
+            &mut buf,
+            &[(-1.0, -1.0), (0.0, 0.0), (0.5, 0.75), (1.0, 1.0)],
+        );
+        push_segment_map(&mut buf, &[]);
+
+        let avar = Avar::read(&buf).unwrap();
+        assert!(avar.var_store().is_none());
+        let maps = avar.axis_segment_maps().collect::<Vec<_>>();
+        assert_eq!(maps.len(), 2);
+        let apply = |val: f32| maps[0].apply(f2dot14(val)).to_f32();
+        assert_eq!(apply(-0.5), -0.5);
+        assert_eq!(apply(0.5), 0.75);
+        assert_eq!(apply(0.25), 0.375);
+        assert_eq!(apply(0.75), 0.875);
+        assert_eq!(maps[1].apply(f2dot14(0.3)), f2dot14(0.3));
+    }
+
+    #[test]
+    fn version_2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0, 0, 2]);
+        push_segment_map(
+            &mut buf,
+            &[(-1.0, -1.0), (0.0, 0.0), (0.5, 0.75), (1.0, 1.0)],
+        );
+        push_segment_map(&mut buf, &[]);
+        // no axis index map, and a var store at 36
+        buf.extend([0u32, 36]);
+        // var store: one region, peaking at wght = 1.0, with one row for
+        // each axis: wght is unchanged, and wdth is moved by 0.25 at full
+        // weight
+        buf.extend(var_store(&[(0.0, 1.0, 1.0), (0.0, 0.0, 0.0)], &[0, 4096]));
+
+        let avar = Avar::read(&buf).unwrap();
+        assert!(avar.axis_index_map().is_none());
+        assert!(avar.var_store().is_some());
+        let mut coords = [f2dot14(0.5), f2dot14(0.5)];
+        avar.map_coords(&mut coords);
+        // 0.5 maps to 0.75, so the wdth delta is scaled by 0.75
+        assert_eq!(coords, [f2dot14(0.75), f2dot14(0.6875)]);
+    }
+}
@@ -0,0 +1,755 @@
+//! The [CFF (Compact Font Format)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff) table
+
+#[path = "../../generated/generated_cff.rs"]
+mod generated;
+
+pub use generated::*;
+
+use core::ops::Range;
+
+use font_types::{BigEndian, OffsetHost, Tag};
+use zerocopy::LayoutVerified;
+
+use crate::pen::Pen;
+use crate::postscript::{
+    self, operators, CharstringContext, CharstringError, DictIter, FdSelect, Index, PrivateDict,
+};
+
+/// 'CFF '
+pub const TAG: Tag = Tag::new(b"CFF");
+
+/// The number of glyphs in the predefined ISOAdobe charset.
+const ISO_ADOBE_LEN: u16 = 229;
+
+impl<'a> Cff<'a> {
+    /// The Name, Top DICT, String and Global Subr INDEXes, which follow the
+    /// header in that order.
+    fn indexes(&self) -> Option<[Index<'a>; 4]> {
+        let data = self.bytes();
+        let mut offset = self.hdr_size() as usize;
+        let mut next = || {
+            let index = Index::read(data.get(offset..)?)?;
+            offset += index.len();
+            Some(index)
+        };
+        Some([next()?, next()?, next()?, next()?])
+    }
+
+    /// The names of the fonts in this table.
+    pub fn name_index(&self) -> Option<Index<'a>> {
+        self.indexes().map(|[names, ..]| names)
+    }
+
+    pub fn top_dict_index(&self) -> Option<Index<'a>> {
+        self.indexes().map(|[_, top_dicts, ..]| top_dicts)
+    }
+
+    /// The strings for string ids that are not standard strings.
+    pub fn string_index(&self) -> Option<Index<'a>> {
+        self.indexes().map(|[_, _, strings, _]| strings)
+    }
+
+    /// The global subroutines, which are shared by all glyphs.
+    pub fn global_subrs(&self) -> Option<Index<'a>> {
+        self.indexes().map(|[.., global_subrs]| global_subrs)
+    }
+
+    /// The Top DICT of the font.
+    ///
+    /// A CFF table in an OpenType font contains exactly one font.
+    pub fn top_dict(&self) -> Option<TopDict> {
+        self.top_dict_index()?.get(0).and_then(TopDict::read)
+    }
+
+    /// The charstrings for each glyph.
+    pub fn charstrings(&self) -> Option<Index<'a>> {
+        self.charstrings_for(&self.top_dict()?)
+    }
+
+    fn charstrings_for(&self, top_dict: &TopDict) -> Option<Index<'a>> {
+        Index::read(self.bytes().get(top_dict.charstrings_offset?..)?)
+    }
+
+    /// The charset, which maps glyph ids to string ids, or to CIDs in
+    /// CID-keyed fonts.
+    pub fn charset(&self) -> Option<Charset<'a>> {
+        let top_dict = self.top_dict()?;
+        let num_glyphs = self.charstrings_for(&top_dict)?.count();
+        Charset::read(self.bytes(), top_dict.charset_offset, num_glyphs)
+    }
+
+    /// The encoding, which maps character codes to glyphs.
+    ///
+    /// CID-keyed fonts don't have an encoding.
+    pub fn encoding(&self) -> Option<Encoding<'a>> {
+        let top_dict = self.top_dict()?;
+        if top_dict.is_cid {
+            return None;
+        }
+        Encoding::read(self.bytes(), top_dict.encoding_offset)
+    }
+
+    /// The font DICTs of a CID-keyed font.
+    pub fn fd_array(&self) -> Option<Index<'a>> {
+        Index::read(self.bytes().get(self.top_dict()?.fd_array_offset?..)?)
+    }
+
+    /// The map from glyphs to font DICTs, in a CID-keyed font.
+    pub fn fd_select(&self) -> Option<FdSelect<'a>> {
+        self.fd_select_for(&self.top_dict()?)
+    }
+
+    fn fd_select_for(&self, top_dict: &TopDict) -> Option<FdSelect<'a>> {
+        let num_glyphs = self.charstrings_for(top_dict)?.count();
+        let data = self.bytes().get(top_dict.fd_select_offset?..)?;
+        FdSelect::read(data, num_glyphs)
+    }
+
+    /// The private DICT used by a glyph.
+    ///
+    /// All glyphs share a private DICT, except in CID-keyed fonts, where each
+    /// font DICT has its own.
+    pub fn private_dict(&self, glyph_id: u16) -> Option<PrivateDict> {
+        self.private_dict_for(&self.top_dict()?, glyph_id)
+            .map(|(dict, _)| dict)
+    }
+
+    /// The private DICT and local subroutines used by a glyph.
+    fn private_dict_for(
+        &self,
+        top_dict: &TopDict,
+        glyph_id: u16,
+    ) -> Option<(PrivateDict, Index<'a>)> {
+        let range = if top_dict.is_cid {
+            let font_idx = self.fd_select_for(top_dict)?.font_index(glyph_id)?;
+            let fd_array = Index::read(self.bytes().get(top_dict.fd_array_offset?..)?)?;
+            TopDict::read(fd_array.get(font_idx as u32)?)?.private_dict_range?
+        } else {
+            top_dict.private_dict_range.clone()?
+        };
+        postscript::private_dict_and_subrs(self.bytes(), range, Index::read)
+    }
+
+    /// Draw the outline of a glyph.
+    ///
+    /// Hints are ignored. Accented glyphs, which are built from components
+    /// with the `seac` form of `endchar`, are drawn with their components.
+    pub fn draw(&self, glyph_id: u16, pen: &mut impl Pen) -> Result<(), CharstringError> {
+        let top_dict = self.top_dict().ok_or(CharstringError::MissingData)?;
+        self.draw_impl(&top_dict, glyph_id, (0.0, 0.0), true, pen)
+    }
+
+    fn draw_impl(
+        &self,
+        top_dict: &TopDict,
+        glyph_id: u16,
+        origin: (f32, f32),
+        allow_seac: bool,
+        pen: &mut impl Pen,
+    ) -> Result<(), CharstringError> {
+        let charstring = self
+            .charstrings_for(top_dict)
+            .ok_or(CharstringError::MissingData)?
+            .get(glyph_id as u32)
+            .ok_or(CharstringError::MissingGlyph(glyph_id))?;
+        let (_, local_subrs) = self
+            .private_dict_for(top_dict, glyph_id)
+            .ok_or(CharstringError::MissingData)?;
+        let context = CharstringContext {
+            global_subrs: self.global_subrs().ok_or(CharstringError::MissingData)?,
+            local_subrs,
+            origin,
+            ..Default::default()
+        };
+        let seac = match postscript::evaluate(charstring, &context, pen)? {
+            Some(seac) => seac,
+            None => return Ok(()),
+        };
+        // components can't themselves be accented glyphs
+        if !allow_seac {
+            return Err(CharstringError::NestedSeac);
+        }
+        let charset = self.charset().ok_or(CharstringError::MissingData)?;
+        let component = |code: u8| {
+            Encoding::Standard
+                .glyph_id(code, &charset)
+                .ok_or(CharstringError::MissingGlyph(glyph_id))
+        };
+        let (base, accent) = (component(seac.base_code)?, component(seac.accent_code)?);
+        self.draw_impl(top_dict, base, origin, false, pen)?;
+        let accent_origin = (
+            origin.0 + seac.accent_origin.0,
+            origin.1 + seac.accent_origin.1,
+        );
+        self.draw_impl(top_dict, accent, accent_origin, false, pen)
+    }
+}
+
+/// The values we use from a [Top DICT], or from a font DICT in the FDArray
+/// of a CID-keyed font.
+///
+/// [Top DICT]: https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=14
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TopDict {
+    /// The offset to the charset, or a predefined charset id.
+    pub charset_offset: usize,
+    /// The offset to the encoding, or a predefined encoding id.
+    pub encoding_offset: usize,
+    pub charstrings_offset: Option<usize>,
+    /// The location of the private DICT in the table.
+    pub private_dict_range: Option<Range<usize>>,
+    pub fd_array_offset: Option<usize>,
+    pub fd_select_offset: Option<usize>,
+    /// Whether this is a CID-keyed font, which starts with the `ROS`
+    /// operator.
+    pub is_cid: bool,
+}
+
+impl TopDict {
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let mut dict = TopDict::default();
+        for (op, operands) in DictIter::new(data) {
+            match op {
+                operators::CHARSET => dict.charset_offset = operands.get_offset(0)?,
+                operators::ENCODING => dict.encoding_offset = operands.get_offset(0)?,
+                operators::CHAR_STRINGS => dict.charstrings_offset = operands.get_offset(0),
+                operators::PRIVATE => {
+                    let size = operands.get_offset(0)?;
+                    let offset = operands.get_offset(1)?;
+                    dict.private_dict_range = Some(offset..offset.checked_add(size)?);
+                }
+                operators::FD_ARRAY => dict.fd_array_offset = operands.get_offset(0),
+                operators::FD_SELECT => dict.fd_select_offset = operands.get_offset(0),
+                operators::ROS => dict.is_cid = true,
+                _ => (),
+            }
+        }
+        Some(dict)
+    }
+}
+
+/// A [charset], which maps glyph ids to string ids, or to CIDs in CID-keyed
+/// fonts.
+///
+/// The Expert and Expert Subset charsets are recognized, but we don't
+/// include their mappings.
+///
+/// [charset]: https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=21
+#[derive(Clone, Copy)]
+pub enum Charset<'a> {
+    /// The predefined charset where each glyph id is its string id.
+    IsoAdobe,
+    Expert,
+    ExpertSubset,
+    Format0(&'a [BigEndian<u16>]),
+    Format1(&'a [Charset1Range]),
+    Format2(&'a [Charset2Range]),
+}
+
+impl<'a> Charset<'a> {
+    /// Read a charset, for a font with `num_glyphs` glyphs.
+    ///
+    /// `offset` is from the start of `data`; offsets from 0 to 2 identify the
+    /// predefined charsets.
+    pub fn read(data: &'a [u8], offset: usize, num_glyphs: u32) -> Option<Self> {
+        match offset {
+            0 => return Some(Charset::IsoAdobe),
+            1 => return Some(Charset::Expert),
+            2 => return Some(Charset::ExpertSubset),
+            _ => (),
+        }
+        let format = *data.get(offset)?;
+        let data = data.get(offset + 1..)?;
+        // .notdef is not included
+        let n_glyphs = num_glyphs.saturating_sub(1) as usize;
+        match format {
+            0 => LayoutVerified::<_, [BigEndian<u16>]>::new_slice_unaligned_from_prefix(
+                data, n_glyphs,
+            )
+            .map(|(sids, _)| Charset::Format0(sids.into_slice())),
+            1 => {
+                let n_ranges = count_ranges(data, 3, n_glyphs, |range| range[2] as usize)?;
+                LayoutVerified::<_, [Charset1Range]>::new_slice_unaligned_from_prefix(
+                    data, n_ranges,
+                )
+                .map(|(ranges, _)| Charset::Format1(ranges.into_slice()))
+            }
+            2 => {
+                let n_ranges = count_ranges(data, 4, n_glyphs, |range| {
+                    u16::from_be_bytes([range[2], range[3]]) as usize
+                })?;
+                LayoutVerified::<_, [Charset2Range]>::new_slice_unaligned_from_prefix(
+                    data, n_ranges,
+                )
+                .map(|(ranges, _)| Charset::Format2(ranges.into_slice()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The string id, or CID, of this glyph.
+    pub fn string_id(&self, glyph_id: u16) -> Option<u16> {
+        if glyph_id == 0 {
+            return Some(0);
+        }
+        match self {
+            Charset::IsoAdobe => (glyph_id < ISO_ADOBE_LEN).then_some(glyph_id),
+            Charset::Expert | Charset::ExpertSubset => None,
+            Charset::Format0(sids) => sids.get(glyph_id as usize - 1).map(|sid| sid.get()),
+            _ => self.find_in_ranges(|first_gid, first_sid, n_left| {
+                let offset = (glyph_id as u32).checked_sub(first_gid)?;
+                if offset > n_left as u32 {
+                    return None;
+                }
+                first_sid.checked_add(offset as u16)
+            }),
+        }
+    }
+
+    /// The glyph with this string id, or CID.
+    pub fn glyph_id(&self, string_id: u16) -> Option<u16> {
+        if string_id == 0 {
+            return Some(0);
+        }
+        match self {
+            Charset::IsoAdobe => (string_id < ISO_ADOBE_LEN).then_some(string_id),
+            Charset::Expert | Charset::ExpertSubset => None,
+            Charset::Format0(sids) => sids
+                .iter()
+                .position(|sid| sid.get() == string_id)
+                .map(|idx| idx as u16 + 1),
+            _ => self.find_in_ranges(|first_gid, first_sid, n_left| {
+                let offset = string_id.checked_sub(first_sid)?;
+                (offset <= n_left).then(|| (first_gid + offset as u32) as u16)
+            }),
+        }
+    }
+
+    /// Call `f` with the first glyph id, the first string id and the number
+    /// of remaining glyphs in each range, until it returns a value.
+    fn find_in_ranges<T>(&self, mut f: impl FnMut(u32, u16, u16) -> Option<T>) -> Option<T> {
+        let mut first_gid = 1u32;
+        let mut visit = |first_sid: u16, n_left: u16| {
+            let result = f(first_gid, first_sid, n_left);
+            first_gid += n_left as u32 + 1;
+            result
+        };
+        match self {
+            Charset::Format1(ranges) => ranges
+                .iter()
+                .find_map(|range| visit(range.first(), range.n_left() as u16)),
+            Charset::Format2(ranges) => ranges
+                .iter()
+                .find_map(|range| visit(range.first(), range.n_left())),
+            _ => None,
+        }
+    }
+}
+
+/// The number of ranges of `range_len` bytes needed to cover `n_glyphs`.
+fn count_ranges(
+    data: &[u8],
+    range_len: usize,
+    n_glyphs: usize,
+    n_left: impl Fn(&[u8]) -> usize,
+) -> Option<usize> {
+    let mut n_ranges = 0;
+    let mut n_covered = 0;
+    while n_covered < n_glyphs {
+        let range = data.get(n_ranges * range_len..(n_ranges + 1) * range_len)?;
+        n_covered += n_left(range) + 1;
+        n_ranges += 1;
+    }
+    Some(n_ranges)
+}
+
+/// An [encoding], which maps character codes to glyphs.
+///
+/// The Expert encoding is recognized, but we don't include its mapping.
+///
+/// [encoding]: https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=18
+#[derive(Clone, Copy)]
+pub enum Encoding<'a> {
+    Standard,
+    Expert,
+    Format0 {
+        codes: &'a [u8],
+        supplements: &'a [EncodingSupplement],
+    },
+    Format1 {
+        ranges: &'a [EncodingRange],
+        supplements: &'a [EncodingSupplement],
+    },
+}
+
+/// Set in the format of encodings that have supplements.
+const HAS_SUPPLEMENTS: u8 = 0x80;
+
+impl<'a> Encoding<'a> {
+    /// Read an encoding.
+    ///
+    /// `offset` is from the start of `data`; offsets 0 and 1 identify the
+    /// predefined encodings.
+    pub fn read(data: &'a [u8], offset: usize) -> Option<Self> {
+        match offset {
+            0 => return Some(Encoding::Standard),
+            1 => return Some(Encoding::Expert),
+            _ => (),
+        }
+        let format = *data.get(offset)?;
+        let count = *data.get(offset + 1)? as usize;
+        let data = data.get(offset + 2..)?;
+        let (encoding, rest) = match format & !HAS_SUPPLEMENTS {
+            0 => {
+                let codes = data.get(..count)?;
+                let encoding = Encoding::Format0 {
+                    codes,
+                    supplements: &[],
+                };
+                (encoding, data.get(count..)?)
+            }
+            1 => {
+                let (ranges, rest) =
+                    LayoutVerified::<_, [EncodingRange]>::new_slice_unaligned_from_prefix(
+                        data, count,
+                    )?;
+                let encoding = Encoding::Format1 {
+                    ranges: ranges.into_slice(),
+                    supplements: &[],
+                };
+                (encoding, rest)
+            }
+            _ => return None,
+        };
+        if format & HAS_SUPPLEMENTS == 0 {
+            return Some(encoding);
+        }
+        let n_supplements = *rest.first()? as usize;
+        let supplements =
+            LayoutVerified::<_, [EncodingSupplement]>::new_slice_unaligned_from_prefix(
+                rest.get(1..)?,
+                n_supplements,
+            )?
+            .0
+            .into_slice();
+        match encoding {
+            Encoding::Format0 { codes, .. } => Some(Encoding::Format0 { codes, supplements }),
+            Encoding::Format1 { ranges, .. } => Some(Encoding::Format1 {
+                ranges,
+                supplements,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The glyph for this character code.
+    ///
+    /// The charset is used for encodings that map codes to string ids.
+    pub fn glyph_id(&self, code: u8, charset: &Charset) -> Option<u16> {
+        let (glyph_id, supplements) = match self {
+            Encoding::Standard => {
+                let string_id = STANDARD_ENCODING[code as usize] as u16;
+                return (string_id != 0)
+                    .then(|| charset.glyph_id(string_id))
+                    .flatten();
+            }
+            Encoding::Expert => return None,
+            Encoding::Format0 { codes, supplements } => {
+                let glyph_id = codes
+                    .iter()
+                    .position(|c| *c == code)
+                    .map(|idx| idx as u16 + 1);
+                (glyph_id, supplements)
+            }
+            Encoding::Format1 {
+                ranges,
+                supplements,
+            } => {
+                let mut first_gid = 1u16;
+                let glyph_id = ranges.iter().find_map(|range| {
+                    let range_gid = first_gid;
+                    first_gid += range.n_left() as u16 + 1;
+                    let offset = code.checked_sub(range.first())?;
+                    (offset <= range.n_left()).then(|| range_gid + offset as u16)
+                });
+                (glyph_id, supplements)
+            }
+        };
+        glyph_id.or_else(|| {
+            supplements
+                .iter()
+                .find(|supplement| supplement.code() == code)
+                .and_then(|supplement| charset.glyph_id(supplement.glyph()))
+        })
+    }
+}
+
+/// The string ids of the glyphs in the Standard encoding, by code.
+#[rustfmt::skip]
+const STANDARD_ENCODING: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110,
+    0, 111, 112, 113, 114, 0, 115, 116, 117, 118, 119, 120, 121, 122, 0, 123,
+    0, 124, 125, 126, 127, 128, 129, 130, 131, 0, 132, 133, 0, 134, 135, 136,
+    137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 138, 0, 139, 0, 0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0,
+    0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0, 0, 0, 0,
+];
+
+#[cfg(test)]
+mod tests {
+    use font_types::FontRead;
+
+    use super::*;
+    use crate::postscript::test_helpers::{
+        charstring, dict_entry, index, table_with_sections, RecordingPen,
+    };
+
+    /// Build a CFF table with a single font.
+    ///
+    /// The Top DICT and the sections that follow the INDEXes are built from
+    /// the offsets of those sections, as in [`table_with_sections`].
+    fn make_cff(
+        global_subrs: &[&[u8]],
+        top_dict: impl Fn(&[i32]) -> Vec<u8>,
+        sections: impl Fn(&[i32]) -> Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        let global_subrs = index(global_subrs);
+        table_with_sections(
+            |offsets| {
+                let mut data = vec![1, 0, 4, 4];
+                data.extend(index(&[b"Test"]));
+                data.extend(index(&[&top_dict(offsets)]));
+                data.extend(index(&[]));
+                data.extend(&global_subrs);
+                data
+            },
+            sections,
+        )
+    }
+
+    /// A private DICT, followed by its local subroutines.
+    fn private_dict(subrs: &[&str]) -> Vec<u8> {
+        let subrs = subrs
+            .iter()
+            .map(|subr| charstring(subr))
+            .collect::<Vec<_>>();
+        let subrs = subrs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let mut data = dict_entry(&[PRIVATE_DICT_LEN], operators::SUBRS);
+        data.extend(index(&subrs));
+        data
+    }
+
+    /// The size of the DICT built by `private_dict`.
+    const PRIVATE_DICT_LEN: i32 = 6;
+
+    fn draw(cff: &Cff, glyph_id: u16) -> Result<Vec<String>, CharstringError> {
+        let mut pen = RecordingPen::default();
+        cff.draw(glyph_id, &mut pen)?;
+        Ok(pen.0)
+    }
+
+    fn make_font() -> Vec<u8> {
+        let glyphs = [
+            "endchar",
+            // A
+            "500 0 0 rmoveto 100 hlineto 100 vlineto -100 hlineto endchar",
+            // acute
+            "0 100 rmoveto 20 20 rlineto -107 callsubr",
+            // Aacute
+            "500 30 80 65 194 endchar",
+            "0 0 rmoveto -107 callgsubr endchar",
+        ];
+        let charstrings = glyphs
+            .iter()
+            .map(|glyph| charstring(glyph))
+            .collect::<Vec<_>>();
+        let charstrings = charstrings.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        make_cff(
+            &[&charstring("50 50 rlineto return")],
+            |offsets| {
+                [
+                    dict_entry(&[offsets[0]], operators::CHARSET),
+                    dict_entry(&[offsets[1]], operators::CHAR_STRINGS),
+                    dict_entry(&[PRIVATE_DICT_LEN, offsets[2]], operators::PRIVATE),
+                ]
+                .concat()
+            },
+            |_| {
+                // format 0, with A, acute, Aacute and a custom string
+                let charset = [0, 0, 34, 0, 125, 0, 203, 1, 135].to_vec();
+                let private = private_dict(&["-20 hlineto endchar"]);
+                vec![charset, index(&charstrings), private]
+            },
+        )
+    }
+
+    #[test]
+    fn read_font() {
+        let data = make_font();
+        let cff = Cff::read(&data).unwrap();
+        assert_eq!(cff.name_index().unwrap().get(0), Some(&b"Test"[..]));
+        assert_eq!(cff.string_index().unwrap().count(), 0);
+        assert_eq!(cff.global_subrs().unwrap().count(), 1);
+        assert_eq!(cff.charstrings().unwrap().count(), 5);
+        let top_dict = cff.top_dict().unwrap();
+        assert!(!top_dict.is_cid);
+        assert_eq!(top_dict.encoding_offset, 0);
+        assert!(cff.fd_select().is_none());
+        assert_eq!(cff.private_dict(1).unwrap().subrs_offset, Some(6));
+
+        let charset = cff.charset().unwrap();
+        assert_eq!(charset.string_id(3), Some(203));
+        assert_eq!(charset.glyph_id(391), Some(4));
+        let encoding = cff.encoding().unwrap();
+        assert_eq!(encoding.glyph_id(b'A', &charset), Some(1));
+        assert_eq!(encoding.glyph_id(b'B', &charset), None);
+    }
+
+    #[test]
+    fn draw_glyphs() {
+        let data = make_font();
+        let cff = Cff::read(&data).unwrap();
+        assert!(draw(&cff, 0).unwrap().is_empty());
+        assert_eq!(
+            draw(&cff, 1).unwrap(),
+            ["M 0 0", "L 100 0", "L 100 100", "L 0 100", "Z"]
+        );
+        // with a local subroutine
+        assert_eq!(
+            draw(&cff, 2).unwrap(),
+            ["M 0 100", "L 20 120", "L 0 120", "Z"]
+        );
+        // with a global subroutine
+        assert_eq!(draw(&cff, 4).unwrap(), ["M 0 0", "L 50 50", "Z"]);
+        assert_eq!(draw(&cff, 5), Err(CharstringError::MissingGlyph(5)));
+    }
+
+    #[test]
+    fn draw_seac() {
+        let data = make_font();
+        let cff = Cff::read(&data).unwrap();
+        assert_eq!(
+            draw(&cff, 3).unwrap(),
+            [
+                "M 0 0",
+                "L 100 0",
+                "L 100 100",
+                "L 0 100",
+                "Z",
+                "M 30 180",
+                "L 50 200",
+                "L 30 200",
+                "Z"
+            ]
+        );
+    }
+
+    #[test]
+    fn cid_keyed() {
+        let glyph = charstring("0 0 rmoveto -107 callsubr endchar");
+        let data = make_cff(
+            &[],
+            |offsets| {
+                [
+                    dict_entry(&[391, 392, 0], operators::ROS),
+                    dict_entry(&[offsets[0]], operators::CHARSET),
+                    dict_entry(&[offsets[1]], operators::CHAR_STRINGS),
+                    dict_entry(&[offsets[2]], operators::FD_SELECT),
+                    dict_entry(&[offsets[5]], operators::FD_ARRAY),
+                ]
+                .concat()
+            },
+            |offsets| {
+                // CIDs 1 and 2
+                let charset = vec![0, 0, 1, 0, 2];
+                let charstrings = index(&[&charstring("endchar"), &glyph, &glyph]);
+                // glyphs 0 and 1 use the first font DICT
+                let fd_select = vec![3, 0, 2, 0, 0, 0, 0, 2, 1, 0, 3];
+                let font_dicts = [
+                    dict_entry(&[PRIVATE_DICT_LEN, offsets[3]], operators::PRIVATE),
+                    dict_entry(&[PRIVATE_DICT_LEN, offsets[4]], operators::PRIVATE),
+                ];
+                vec![
+                    charset,
+                    charstrings,
+                    fd_select,
+                    private_dict(&["10 hlineto return"]),
+                    private_dict(&["20 vlineto return"]),
+                    index(&[&font_dicts[0], &font_dicts[1]]),
+                ]
+            },
+        );
+        let cff = Cff::read(&data).unwrap();
+        assert!(cff.top_dict().unwrap().is_cid);
+        assert!(cff.encoding().is_none());
+        assert_eq!(cff.fd_array().unwrap().count(), 2);
+        assert_eq!(cff.charset().unwrap().string_id(2), Some(2));
+        assert_eq!(draw(&cff, 1).unwrap(), ["M 0 0", "L 10 0", "Z"]);
+        assert_eq!(draw(&cff, 2).unwrap(), ["M 0 0", "L 0 20", "Z"]);
+    }
+
+    #[test]
+    fn charsets() {
+        let charset = Charset::read(&[], 0, 4).unwrap();
+        assert!(matches!(charset, Charset::IsoAdobe));
+        assert_eq!(charset.string_id(5), Some(5));
+
+        // format 1: string ids 34-35, then 200
+        let mut data = vec![0, 0, 0];
+        data.extend([1, 0, 34, 1, 0, 200, 0]);
+        let charset = Charset::read(&data, 3, 4).unwrap();
+        let sids = (0..5).map(|gid| charset.string_id(gid)).collect::<Vec<_>>();
+        assert_eq!(sids, [Some(0), Some(34), Some(35), Some(200), None]);
+        assert_eq!(charset.glyph_id(35), Some(2));
+        assert_eq!(charset.glyph_id(200), Some(3));
+        assert_eq!(charset.glyph_id(36), None);
+
+        // format 2: string ids 1000-1299
+        let data = [0, 0, 0, 2, 0x03, 0xE8, 0x01, 0x2B];
+        let charset = Charset::read(&data, 3, 301).unwrap();
+        assert_eq!(charset.string_id(300), Some(1299));
+        assert_eq!(charset.glyph_id(1100), Some(101));
+
+        // a range that runs past the last string id
+        let data = [0, 0, 0, 2, 0xFF, 0xFF, 0x00, 0x01];
+        let charset = Charset::read(&data, 3, 3).unwrap();
+        assert_eq!(charset.string_id(1), Some(0xFFFF));
+        assert_eq!(charset.string_id(2), None);
+    }
+
+    #[test]
+    fn encodings() {
+        let charset = Charset::IsoAdobe;
+        // format 0 with a supplement: 'A' and 'B' are glyphs 1 and 2, and
+        // 'C' is the glyph with string id 34
+        let data = [0, 0, 0x80, 2, b'A', b'B', 1, b'C', 0, 34];
+        let encoding = Encoding::read(&data, 2).unwrap();
+        assert_eq!(encoding.glyph_id(b'A', &charset), Some(1));
+        assert_eq!(encoding.glyph_id(b'B', &charset), Some(2));
+        assert_eq!(encoding.glyph_id(b'C', &charset), Some(34));
+        assert_eq!(encoding.glyph_id(b'D', &charset), None);
+
+        // format 1: 'a' to 'c', then 'x'
+        let data = [0, 0, 1, 2, b'a', 2, b'x', 0];
+        let encoding = Encoding::read(&data, 2).unwrap();
+        let glyphs = b"abcxy"
+            .iter()
+            .map(|code| encoding.glyph_id(*code, &charset))
+            .collect::<Vec<_>>();
+        assert_eq!(glyphs, [Some(1), Some(2), Some(3), Some(4), None]);
+
+        // the standard encoding uses string ids
+        let encoding = Encoding::read(&data, 0).unwrap();
+        assert_eq!(encoding.glyph_id(0xE1, &charset), Some(138), "AE");
+    }
+}
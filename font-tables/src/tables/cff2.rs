@@ -0,0 +1,305 @@
+//! The [CFF2 (Compact Font Format Version 2)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2) table
+
+#[path = "../../generated/generated_cff2.rs"]
+mod generated;
+
+pub use generated::*;
+
+use core::ops::Range;
+
+use font_types::{F2Dot14, FontRead, OffsetHost, Tag};
+
+use crate::pen::Pen;
+use crate::postscript::{
+    self, operators, CharstringContext, CharstringError, DictIter, FdSelect, Index, PrivateDict,
+};
+use crate::variations::ItemVariationStore;
+
+/// 'CFF2'
+pub const TAG: Tag = Tag::new(b"CFF2");
+
+impl<'a> Cff2<'a> {
+    fn top_dict_range(&self) -> Range<usize> {
+        let start = self.header_size() as usize;
+        start..start + self.top_dict_length() as usize
+    }
+
+    /// The Top DICT, which follows the header.
+    pub fn top_dict(&self) -> Option<TopDict> {
+        self.bytes()
+            .get(self.top_dict_range())
+            .and_then(TopDict::read)
+    }
+
+    /// The global subroutines, which are shared by all glyphs.
+    pub fn global_subrs(&self) -> Option<Index<'a>> {
+        Index::read_cff2(self.bytes().get(self.top_dict_range().end..)?)
+    }
+
+    /// The charstrings for each glyph.
+    pub fn charstrings(&self) -> Option<Index<'a>> {
+        self.charstrings_for(&self.top_dict()?)
+    }
+
+    fn charstrings_for(&self, top_dict: &TopDict) -> Option<Index<'a>> {
+        Index::read_cff2(self.bytes().get(top_dict.charstrings_offset?..)?)
+    }
+
+    /// The font DICTs, each of which has a private DICT.
+    pub fn fd_array(&self) -> Option<Index<'a>> {
+        Index::read_cff2(self.bytes().get(self.top_dict()?.fd_array_offset?..)?)
+    }
+
+    /// The map from glyphs to font DICTs.
+    ///
+    /// This is only present if there is more than one font DICT.
+    pub fn fd_select(&self) -> Option<FdSelect<'a>> {
+        self.fd_select_for(&self.top_dict()?)
+    }
+
+    fn fd_select_for(&self, top_dict: &TopDict) -> Option<FdSelect<'a>> {
+        let num_glyphs = self.charstrings_for(top_dict)?.count();
+        let data = self.bytes().get(top_dict.fd_select_offset?..)?;
+        FdSelect::read(data, num_glyphs)
+    }
+
+    /// The variation store, used by blends in charstrings.
+    ///
+    /// Blends in DICTs are not applied, so a private DICT with blended values
+    /// we use fails to read.
+    pub fn var_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.var_store_for(&self.top_dict()?)
+    }
+
+    fn var_store_for(&self, top_dict: &TopDict) -> Option<ItemVariationStore<'a>> {
+        // the store is preceded by its length
+        let offset = top_dict.var_store_offset?.checked_add(2)?;
+        ItemVariationStore::read(self.bytes().get(offset..)?)
+    }
+
+    /// The private DICT used by a glyph.
+    pub fn private_dict(&self, glyph_id: u16) -> Option<PrivateDict> {
+        self.private_dict_for(&self.top_dict()?, glyph_id)
+            .map(|(dict, _)| dict)
+    }
+
+    /// The private DICT and local subroutines used by a glyph.
+    fn private_dict_for(
+        &self,
+        top_dict: &TopDict,
+        glyph_id: u16,
+    ) -> Option<(PrivateDict, Index<'a>)> {
+        let font_idx = match top_dict.fd_select_offset {
+            Some(_) => self.fd_select_for(top_dict)?.font_index(glyph_id)?,
+            None => 0,
+        };
+        let fd_array = Index::read_cff2(self.bytes().get(top_dict.fd_array_offset?..)?)?;
+        let range = TopDict::read(fd_array.get(font_idx as u32)?)?.private_dict_range?;
+        postscript::private_dict_and_subrs(self.bytes(), range, Index::read_cff2)
+    }
+
+    /// Draw the outline of a glyph, at a location in design space.
+    ///
+    /// `coords` are normalized coordinates, in axis order; missing
+    /// coordinates are treated as 0. Hints are ignored.
+    pub fn draw(
+        &self,
+        glyph_id: u16,
+        coords: &[F2Dot14],
+        pen: &mut impl Pen,
+    ) -> Result<(), CharstringError> {
+        let top_dict = self.top_dict().ok_or(CharstringError::MissingData)?;
+        let charstring = self
+            .charstrings_for(&top_dict)
+            .ok_or(CharstringError::MissingData)?
+            .get(glyph_id as u32)
+            .ok_or(CharstringError::MissingGlyph(glyph_id))?;
+        let (private_dict, local_subrs) = self
+            .private_dict_for(&top_dict, glyph_id)
+            .ok_or(CharstringError::MissingData)?;
+        let context = CharstringContext {
+            global_subrs: self.global_subrs().ok_or(CharstringError::MissingData)?,
+            local_subrs,
+            is_cff2: true,
+            var_store: self.var_store_for(&top_dict),
+            vsindex: private_dict.vsindex,
+            coords,
+            ..Default::default()
+        };
+        // CFF2 has no accented glyphs
+        postscript::evaluate(charstring, &context, pen).map(|_| ())
+    }
+}
+
+/// The values we use from a [Top DICT], or from a font DICT in the FDArray.
+///
+/// [Top DICT]: https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#top-dict-data
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TopDict {
+    pub charstrings_offset: Option<usize>,
+    pub fd_array_offset: Option<usize>,
+    pub fd_select_offset: Option<usize>,
+    /// The offset to the variation store, which starts with its length.
+    pub var_store_offset: Option<usize>,
+    /// The location of the private DICT in the table, in font DICTs.
+    pub private_dict_range: Option<Range<usize>>,
+}
+
+impl TopDict {
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let mut dict = TopDict::default();
+        for (op, operands) in DictIter::new(data) {
+            match op {
+                operators::CHAR_STRINGS => dict.charstrings_offset = operands.get_offset(0),
+                operators::FD_ARRAY => dict.fd_array_offset = operands.get_offset(0),
+                operators::FD_SELECT => dict.fd_select_offset = operands.get_offset(0),
+                operators::VSTORE => dict.var_store_offset = operands.get_offset(0),
+                operators::PRIVATE => {
+                    let size = operands.get_offset(0)?;
+                    let offset = operands.get_offset(1)?;
+                    dict.private_dict_range = Some(offset..offset.checked_add(size)?);
+                }
+                _ => (),
+            }
+        }
+        Some(dict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::postscript::test_helpers::{
+        charstring, dict_entry, index_cff2, table_with_sections, RecordingPen,
+    };
+    use crate::variations::test_helpers::f2dot14;
+
+    /// Build a CFF2 table.
+    ///
+    /// The Top DICT and the sections that follow the global subroutines are
+    /// built from the offsets of those sections, as in
+    /// [`table_with_sections`].
+    fn make_cff2(
+        top_dict: impl Fn(&[i32]) -> Vec<u8>,
+        sections: impl Fn(&[i32]) -> Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        table_with_sections(
+            |offsets| {
+                let top_dict = top_dict(offsets);
+                let mut data = vec![2, 0, 5];
+                data.extend((top_dict.len() as u16).to_be_bytes());
+                data.extend(top_dict);
+                data.extend(index_cff2(&[]));
+                data
+            },
+            sections,
+        )
+    }
+
+    /// A variation store for one axis, with regions for increasing and
+    /// decreasing values, preceded by its length.
+    fn make_var_store() -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(50u16);
+        buf.push(1u16);
+        buf.push(16u32);
+        buf.push(2u16);
+        buf.extend([32u32, 40]);
+        buf.extend([1u16, 2]);
+        buf.extend([f2dot14(0.0), f2dot14(1.0), f2dot14(1.0)]);
+        buf.extend([f2dot14(-1.0), f2dot14(-1.0), f2dot14(0.0)]);
+        // the first item variation data only uses the first region
+        buf.extend([0u16, 0, 1, 0]);
+        buf.extend([0u16, 0, 2, 0, 1]);
+        buf.to_vec()
+    }
+
+    fn make_font() -> Vec<u8> {
+        let glyphs = [
+            "",
+            "10 20 100 50 2 blend rmoveto 100 hlineto",
+            "1 vsindex 10 100 200 1 blend 0 rmoveto -107 callsubr",
+            // the private DICT for this glyph sets the vsindex
+            "10 100 200 1 blend 0 rmoveto 10 hlineto",
+        ];
+        let charstrings = glyphs
+            .iter()
+            .map(|glyph| charstring(glyph))
+            .collect::<Vec<_>>();
+        let charstrings = charstrings.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        make_cff2(
+            |offsets| {
+                [
+                    dict_entry(&[offsets[0]], operators::CHAR_STRINGS),
+                    dict_entry(&[offsets[1]], operators::FD_SELECT),
+                    dict_entry(&[offsets[2]], operators::VSTORE),
+                    dict_entry(&[offsets[5]], operators::FD_ARRAY),
+                ]
+                .concat()
+            },
+            |offsets| {
+                // subroutines don't need to return
+                let mut private_0 = dict_entry(&[6], operators::SUBRS);
+                private_0.extend(index_cff2(&[&charstring("10 vlineto")]));
+                let private_1 = dict_entry(&[1], operators::VSINDEX);
+                let font_dicts = [
+                    dict_entry(&[private_0.len() as i32, offsets[3]], operators::PRIVATE),
+                    dict_entry(&[private_1.len() as i32, offsets[4]], operators::PRIVATE),
+                ];
+                vec![
+                    index_cff2(&charstrings),
+                    vec![0, 0, 0, 0, 1],
+                    make_var_store(),
+                    private_0,
+                    private_1,
+                    index_cff2(&[&font_dicts[0], &font_dicts[1]]),
+                ]
+            },
+        )
+    }
+
+    fn draw(cff2: &Cff2, glyph_id: u16, coord: f32) -> Vec<String> {
+        let mut pen = RecordingPen::default();
+        cff2.draw(glyph_id, &[f2dot14(coord)], &mut pen).unwrap();
+        pen.0
+    }
+
+    #[test]
+    fn read_font() {
+        let data = make_font();
+        let cff2 = Cff2::read(&data).unwrap();
+        assert_eq!(cff2.global_subrs().unwrap().count(), 0);
+        assert_eq!(cff2.charstrings().unwrap().count(), 4);
+        assert_eq!(cff2.fd_array().unwrap().count(), 2);
+        assert_eq!(cff2.fd_select().unwrap().font_index(3), Some(1));
+        assert_eq!(cff2.var_store().unwrap().item_variation_data_count(), 2);
+        assert_eq!(cff2.private_dict(0).unwrap().subrs_offset, Some(6));
+        assert_eq!(cff2.private_dict(3).unwrap().vsindex, 1);
+    }
+
+    #[test]
+    fn blends() {
+        let data = make_font();
+        let cff2 = Cff2::read(&data).unwrap();
+        assert!(draw(&cff2, 0, 0.5).is_empty());
+        assert_eq!(draw(&cff2, 1, 0.0), ["M 10 20", "L 110 20", "Z"]);
+        assert_eq!(draw(&cff2, 1, 0.5), ["M 60 45", "L 160 45", "Z"]);
+        // only the first region is used
+        assert_eq!(draw(&cff2, 1, -0.5), ["M 10 20", "L 110 20", "Z"]);
+
+        // with a vsindex in the charstring
+        assert_eq!(draw(&cff2, 2, 0.5), ["M 60 0", "L 60 10", "Z"]);
+        assert_eq!(draw(&cff2, 2, -0.5), ["M 110 0", "L 110 10", "Z"]);
+        // with a vsindex in the private DICT
+        assert_eq!(draw(&cff2, 3, 0.5), ["M 60 0", "L 70 0", "Z"]);
+        assert_eq!(draw(&cff2, 3, -1.0), ["M 210 0", "L 220 0", "Z"]);
+
+        let mut pen = RecordingPen::default();
+        assert_eq!(
+            cff2.draw(4, &[], &mut pen),
+            Err(CharstringError::MissingGlyph(4))
+        );
+    }
+}
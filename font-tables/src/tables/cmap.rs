@@ -4,3 +4,376 @@
 mod generated;
 
 pub use generated::*;
+
+use font_types::{OffsetHost, Tag};
+
+/// 'cmap'
+pub const TAG: Tag = Tag::new(b"cmap");
+
+/// The (platform, encoding) pairs of unicode subtables, in order of preference.
+const UNICODE_ENCODINGS: &[(u16, u16)] = &[
+    (3, 10),
+    (0, 4),
+    (3, 1),
+    (0, 3),
+    (0, 2),
+    (0, 1),
+    (0, 0),
+    // format 13 last resort subtables map many codepoints to one glyph
+    (0, 6),
+];
+
+impl<'a> Cmap<'a> {
+    /// Iterate over the encoding records and their subtables.
+    ///
+    /// Records whose subtables cannot be read are skipped.
+    pub fn subtables(&self) -> impl Iterator<Item = (EncodingRecord, CmapSubtable<'a>)> + '_ {
+        self.encoding_records().iter().filter_map(|record| {
+            self.resolve_offset(record.subtable_offset())
+                .map(|subtable| (*record, subtable))
+        })
+    }
+
+    /// Return the subtable for a given platform and encoding, if present.
+    pub fn subtable(&self, platform_id: u16, encoding_id: u16) -> Option<CmapSubtable<'a>> {
+        self.encoding_records()
+            .iter()
+            .find(|rec| rec.platform_id() == platform_id && rec.encoding_id() == encoding_id)
+            .and_then(|rec| self.resolve_offset(rec.subtable_offset()))
+    }
+
+    /// Return the preferred subtable for mapping unicode codepoints.
+    ///
+    /// Subtables covering the full unicode repertoire are preferred to those
+    /// that only cover the BMP.
+    pub fn best_unicode_subtable(&self) -> Option<CmapSubtable<'a>> {
+        UNICODE_ENCODINGS
+            .iter()
+            .find_map(|(platform, encoding)| self.subtable(*platform, *encoding))
+    }
+
+    /// Map a unicode codepoint to a glyph id, using the best unicode subtable.
+    ///
+    /// If you are mapping many codepoints, prefer to find the subtable
+    /// once, with [`Cmap::best_unicode_subtable`].
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<u16> {
+        self.best_unicode_subtable()?
+            .map_codepoint(codepoint.into())
+    }
+}
+
+impl<'a> CmapSubtable<'a> {
+    /// Map a codepoint to a glyph id.
+    ///
+    /// Returns `None` if the codepoint is unmapped (or is mapped to glyph 0)
+    /// or if the subtable format does not support mapping.
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let gid = match self {
+            CmapSubtable::Format0(table) => table.map_codepoint(codepoint),
+            CmapSubtable::Format4(table) => table.map_codepoint(codepoint),
+            CmapSubtable::Format6(table) => table.map_codepoint(codepoint),
+            CmapSubtable::Format10(table) => table.map_codepoint(codepoint),
+            CmapSubtable::Format12(table) => table.map_codepoint(codepoint),
+            CmapSubtable::Format13(table) => table.map_codepoint(codepoint),
+            _ => None,
+        };
+        gid.filter(|gid| *gid != 0)
+    }
+
+    /// Iterate over all `(codepoint, glyph_id)` pairs in this subtable.
+    ///
+    /// Codepoints mapped to glyph 0 are skipped. Format 13 groups map a range
+    /// of codepoints to a single glyph, so only the first codepoint of each
+    /// group is yielded. Formats 2, 8 and 14 are not currently supported, and
+    /// will produce an empty iterator.
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        let format0 = match self {
+            CmapSubtable::Format0(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        let format4 = match self {
+            CmapSubtable::Format4(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        let format6 = match self {
+            CmapSubtable::Format6(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        let format10 = match self {
+            CmapSubtable::Format10(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        let format12 = match self {
+            CmapSubtable::Format12(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        let format13 = match self {
+            CmapSubtable::Format13(table) => Some(table.iter_mappings()),
+            _ => None,
+        };
+        format0
+            .into_iter()
+            .flatten()
+            .chain(format4.into_iter().flatten())
+            .chain(format6.into_iter().flatten())
+            .chain(format10.into_iter().flatten())
+            .chain(format12.into_iter().flatten())
+            .chain(format13.into_iter().flatten())
+            .filter(|(_, gid)| *gid != 0)
+    }
+}
+
+impl<'a> Cmap0<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        self.glyph_id_array()
+            .get(usize::try_from(codepoint).ok()?)
+            .map(|gid| gid.get() as u16)
+    }
+
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        self.glyph_id_array()
+            .iter()
+            .enumerate()
+            .map(|(cp, gid)| (cp as u32, gid.get() as u16))
+    }
+}
+
+impl<'a> Cmap4<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let codepoint = u16::try_from(codepoint).ok()?;
+        let end_codes = self.end_code();
+        // segments are sorted by end code; find the first that could contain us
+        let idx = match end_codes.binary_search_by(|end| end.get().cmp(&codepoint)) {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+        let start = self.start_code().get(idx)?.get();
+        if codepoint < start {
+            return None;
+        }
+        self.lookup_in_segment(idx, codepoint)
+    }
+
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        let n_segments = self.end_code().len().min(self.start_code().len());
+        (0..n_segments).flat_map(move |idx| {
+            let start = self.start_code()[idx].get();
+            let end = self.end_code()[idx].get();
+            // the final segment maps 0xFFFF to .notdef, and is required
+            (start..=end)
+                .filter(|cp| *cp != 0xFFFF)
+                .filter_map(move |cp| Some((cp as u32, self.lookup_in_segment(idx, cp)?)))
+        })
+    }
+
+    /// Map a codepoint known to be in the segment at `idx`.
+    fn lookup_in_segment(&self, idx: usize, codepoint: u16) -> Option<u16> {
+        let start = self.start_code().get(idx)?.get();
+        let delta = self.id_delta().get(idx)?.get();
+        let range_offset = self.id_range_offsets().get(idx)?.get();
+        if range_offset == 0 {
+            return Some(codepoint.wrapping_add(delta as u16));
+        }
+        // the range offset is relative to its own position in the
+        // idRangeOffsets array; convert it to an index into glyphIdArray.
+        let n_segments = self.id_range_offsets().len();
+        let glyph_idx = (range_offset as usize / 2 + (codepoint - start) as usize)
+            .checked_sub(n_segments - idx)?;
+        let gid = self.glyph_id_array().get(glyph_idx)?.get();
+        (gid != 0).then(|| gid.wrapping_add(delta as u16))
+    }
+}
+
+impl<'a> Cmap6<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let idx = codepoint.checked_sub(self.first_code() as u32)?;
+        self.glyph_id_array().get(idx as usize).map(|gid| gid.get())
+    }
+
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        let first = self.first_code() as u32;
+        self.glyph_id_array()
+            .iter()
+            .enumerate()
+            .map(move |(i, gid)| (first + i as u32, gid.get()))
+    }
+}
+
+impl<'a> Cmap10<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let idx = codepoint.checked_sub(self.start_char_code())?;
+        if idx >= self.num_chars() {
+            return None;
+        }
+        self.glyph_id_array().get(idx as usize).map(|gid| gid.get())
+    }
+
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        let first = self.start_char_code();
+        self.glyph_id_array()
+            .iter()
+            .take(self.num_chars() as usize)
+            .enumerate()
+            .map(move |(i, gid)| (first + i as u32, gid.get()))
+    }
+}
+
+impl<'a> Cmap12<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let group = find_group(self.groups(), codepoint, |group| {
+            (group.start_char_code(), group.end_char_code())
+        })?;
+        let gid = group
+            .start_glyph_id()
+            .checked_add(codepoint - group.start_char_code())?;
+        u16::try_from(gid).ok()
+    }
+
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        self.groups().iter().flat_map(|group| {
+            let start = group.start_char_code();
+            let start_gid = group.start_glyph_id();
+            (start..=group.end_char_code()).map_while(move |cp| {
+                let gid = start_gid.checked_add(cp - start)?;
+                Some((cp, u16::try_from(gid).ok()?))
+            })
+        })
+    }
+}
+
+impl<'a> Cmap13<'a> {
+    pub fn map_codepoint(&self, codepoint: u32) -> Option<u16> {
+        let group = find_group(self.groups(), codepoint, |group| {
+            (group.start_char_code(), group.end_char_code())
+        })?;
+        u16::try_from(group.glyph_id()).ok()
+    }
+
+    /// Iterate over the first codepoint and the glyph of each group.
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, u16)> + '_ {
+        self.groups().iter().filter_map(|group| {
+            let gid = u16::try_from(group.glyph_id()).ok()?;
+            Some((group.start_char_code(), gid))
+        })
+    }
+}
+
+/// Binary search a list of groups, sorted by start code.
+///
+/// The `range` closure returns the (inclusive) start and end of a group.
+fn find_group<T>(groups: &[T], codepoint: u32, range: impl Fn(&T) -> (u32, u32)) -> Option<&T> {
+    groups
+        .binary_search_by(|group| {
+            let (start, end) = range(group);
+            if codepoint < start {
+                std::cmp::Ordering::Greater
+            } else if codepoint > end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .and_then(|idx| groups.get(idx))
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+
+    #[test]
+    fn format_4() {
+        let mut buf = BeBuffer::new();
+        // three segments: 'A'..='C' by delta, 'a'..='b' by glyph array, and 0xFFFF
+        buf.extend([4u16, 0, 0, 6, 4, 1, 2]); // format, len, language, seg_count_x2, ...
+        buf.extend([0x43u16, 0x62, 0xFFFF]); // end codes
+        buf.push(0u16); // reserved pad
+        buf.extend([0x41u16, 0x61, 0xFFFF]); // start codes
+        buf.extend([-0x40i16, 0, 1]); // id deltas
+        buf.extend([0u16, 4, 0]); // id range offsets
+        buf.extend([7u16, 0]); // glyph id array
+
+        let table = CmapSubtable::read(&buf).unwrap();
+        assert_eq!(table.map_codepoint(0x40), None);
+        assert_eq!(table.map_codepoint(0x41), Some(1));
+        assert_eq!(table.map_codepoint(0x43), Some(3));
+        assert_eq!(table.map_codepoint(0x44), None);
+        assert_eq!(table.map_codepoint(0x61), Some(7));
+        assert_eq!(table.map_codepoint(0x62), None);
+        assert_eq!(table.map_codepoint(0xFFFF), None);
+        assert_eq!(
+            table.iter_mappings().collect::<Vec<_>>(),
+            [(0x41, 1), (0x42, 2), (0x43, 3), (0x61, 7)]
+        );
+    }
+
+    #[test]
+    fn format_12() {
+        let mut buf = BeBuffer::new();
+        buf.extend([12u16, 0]);
+        buf.extend([0u32, 0, 2]); // length, language, num_groups
+        buf.extend([0x20u32, 0x21, 3]);
+        buf.extend([0x1F600u32, 0x1F601, 10]);
+
+        let table = CmapSubtable::read(&buf).unwrap();
+        assert_eq!(table.map_codepoint(0x21), Some(4));
+        assert_eq!(table.map_codepoint(0x22), None);
+        assert_eq!(table.map_codepoint(0x1F601), Some(11));
+        assert_eq!(table.iter_mappings().count(), 4);
+    }
+
+    #[test]
+    fn format_12_overflow() {
+        let mut buf = BeBuffer::new();
+        buf.extend([12u16, 0]);
+        buf.extend([0u32, 0, 1]);
+        buf.extend([0x20u32, 0x30, u32::MAX]);
+
+        let table = CmapSubtable::read(&buf).unwrap();
+        assert_eq!(table.map_codepoint(0x21), None);
+        assert_eq!(table.iter_mappings().count(), 0);
+    }
+
+    fn format_13_last_resort() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([13u16, 0]);
+        buf.extend([28u32, 0, 1]); // length, language, num_groups
+        buf.extend([0u32, u32::MAX, 1]);
+        buf
+    }
+
+    #[test]
+    fn format_13() {
+        let buf = format_13_last_resort();
+        let table = CmapSubtable::read(&buf).unwrap();
+        assert_eq!(table.map_codepoint(0x41), Some(1));
+        assert_eq!(table.map_codepoint(u32::MAX), Some(1));
+        assert_eq!(table.iter_mappings().collect::<Vec<_>>(), [(0, 1)]);
+    }
+
+    #[test]
+    fn last_resort_subtable_is_least_preferred() {
+        let mut format4 = BeBuffer::new();
+        // 'A' to glyph 5
+        format4.extend([4u16, 32, 0, 4, 2, 0, 2]); // format, len, language, seg_count_x2, ...
+        format4.extend([0x41u16, 0xFFFF]); // end codes
+        format4.push(0u16); // reserved pad
+        format4.extend([0x41u16, 0xFFFF]); // start codes
+        format4.extend([5 - 0x41i16, 1]); // id deltas
+        format4.extend([0u16, 0]); // id range offsets
+
+        let mut header = BeBuffer::new();
+        header.extend([0u16, 2]); // version, num_tables
+        header.extend([0u16, 6]);
+        header.push(20u32);
+        header.extend([3u16, 1]);
+        header.push(48u32);
+        let data = [&header[..], &format_13_last_resort(), &format4].concat();
+
+        let cmap = Cmap::read(&data).unwrap();
+        assert_eq!(cmap.map_codepoint(0x41u32), Some(5));
+        assert_eq!(cmap.map_codepoint(0x42u32), None);
+    }
+}
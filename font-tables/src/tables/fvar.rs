@@ -0,0 +1,269 @@
+//! the [fvar] table
+//!
+//! [fvar]: https://docs.microsoft.com/en-us/typography/opentype/spec/fvar
+
+#[path = "../../generated/generated_fvar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{BigEndian, F2Dot14, Fixed, FontRead, OffsetHost, Tag};
+use zerocopy::LayoutVerified;
+
+#[cfg(feature = "std")]
+use super::avar::Avar;
+use super::name::{Entry, Name};
+
+/// 'fvar'
+pub const TAG: Tag = Tag::new(b"fvar");
+
+impl<'a> Fvar<'a> {
+    /// The variation axes, in the order used for instance and normalized
+    /// coordinates.
+    pub fn axes(&self) -> impl Iterator<Item = VariationAxisRecord> + '_ {
+        (0..self.axis_count()).filter_map(|idx| self.axis(idx))
+    }
+
+    /// Return the axis record at this index.
+    pub fn axis(&self, index: u16) -> Option<VariationAxisRecord> {
+        if index >= self.axis_count() {
+            return None;
+        }
+        let record_len = self.axis_size() as usize;
+        let start = index as usize * record_len;
+        self.bytes_at_offset(self.axes_array_offset())
+            .get(start..start + record_len)
+            .and_then(VariationAxisRecord::read)
+    }
+
+    /// The named instances.
+    pub fn instances(&self) -> impl Iterator<Item = InstanceRecord<'a>> + '_ {
+        (0..self.instance_count()).filter_map(|idx| self.instance(idx))
+    }
+
+    /// Return the named instance at this index.
+    pub fn instance(&self, index: u16) -> Option<InstanceRecord<'a>> {
+        if index >= self.instance_count() {
+            return None;
+        }
+        // instances immediately follow the axes
+        let axes_len = self.axis_count() as usize * self.axis_size() as usize;
+        let record_len = self.instance_size() as usize;
+        let start = axes_len + index as usize * record_len;
+        let bytes = self
+            .bytes_at_offset(self.axes_array_offset())
+            .get(start..start + record_len)?;
+        InstanceRecord::read(bytes, self.axis_count())
+    }
+
+    /// Convert user coordinates, such as `wght=650`, to normalized
+    /// coordinates.
+    ///
+    /// The result has one coordinate for each axis, in axis order. Axes
+    /// without a user coordinate are at their default. If there is an
+    /// `avar` table, it is used to modify the default normalization.
+    #[cfg(feature = "std")]
+    pub fn normalize(&self, user_coords: &[(Tag, Fixed)], avar: Option<&Avar>) -> Vec<F2Dot14> {
+        let mut coords = self
+            .axes()
+            .map(|axis| {
+                let value = user_coords
+                    .iter()
+                    .rev()
+                    .find(|(tag, _)| *tag == axis.axis_tag())
+                    .map(|(_, value)| *value)
+                    .unwrap_or_else(|| axis.default_value());
+                axis.normalize(value)
+            })
+            .collect::<Vec<_>>();
+        if let Some(avar) = avar {
+            avar.map_coords(&mut coords);
+        }
+        coords
+    }
+}
+
+impl VariationAxisRecord {
+    /// Returns `true` if this axis should be hidden in user interfaces.
+    pub fn is_hidden(&self) -> bool {
+        self.flags().contains(VariationAxisFlags::HIDDEN_AXIS)
+    }
+
+    /// Normalize a user coordinate for this axis, using the default
+    /// normalization.
+    ///
+    /// The value is clamped to the axis range, and then mapped to -1.0 at
+    /// the minimum, 0.0 at the default, and 1.0 at the maximum.
+    pub fn normalize(&self, value: Fixed) -> F2Dot14 {
+        let (min, default, max) = (self.min_value(), self.default_value(), self.max_value());
+        let value = value.max(min).min(max).to_f64();
+        // differences can overflow Fixed, so compute them in f64
+        let (min, default, max) = (min.to_f64(), default.to_f64(), max.to_f64());
+        let normalized = if value < default {
+            -(default - value) / (default - min)
+        } else if value > default {
+            (value - default) / (max - default)
+        } else {
+            0.0
+        };
+        F2Dot14::from_f32(normalized as f32)
+    }
+
+    /// The display name of this axis.
+    pub fn name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.axis_name_id())
+    }
+}
+
+/// The size of the fields in an [`InstanceRecord`] that precede the
+/// coordinates.
+const INSTANCE_HEADER_LEN: usize = 4;
+
+/// A named instance, from the [fvar](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#instancerecord) table.
+pub struct InstanceRecord<'a> {
+    /// The name ID for entries in the 'name' table that provide
+    /// subfamily names for this instance.
+    pub subfamily_name_id: u16,
+    /// Reserved for future use — set to 0.
+    pub flags: u16,
+    /// The coordinates of this instance, with one value for each axis.
+    pub coordinates: &'a [BigEndian<Fixed>],
+    /// The name ID for entries in the 'name' table that provide
+    /// PostScript names for this instance.
+    ///
+    /// This is `None` if the record has no such field, or if it is 0xFFFF.
+    pub post_script_name_id: Option<u16>,
+}
+
+impl<'a> InstanceRecord<'a> {
+    /// Read an instance record.
+    ///
+    /// The record's length determines whether it has a PostScript name id.
+    pub fn read(bytes: &'a [u8], axis_count: u16) -> Option<Self> {
+        let subfamily_name_id = BigEndian::<u16>::read(bytes)?.get();
+        let flags = BigEndian::<u16>::read(bytes.get(2..)?)?.get();
+        let (coordinates, rest) = LayoutVerified::new_slice_unaligned_from_prefix(
+            bytes.get(INSTANCE_HEADER_LEN..)?,
+            axis_count as usize,
+        )?;
+        let post_script_name_id = BigEndian::<u16>::read(rest)
+            .map(|id| id.get())
+            .filter(|id| *id != 0xFFFF);
+        Some(InstanceRecord {
+            subfamily_name_id,
+            flags,
+            coordinates: coordinates.into_slice(),
+            post_script_name_id,
+        })
+    }
+
+    /// The subfamily name of this instance, such as "Bold Condensed".
+    pub fn subfamily_name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.subfamily_name_id)
+    }
+
+    /// The PostScript name of this instance, if it has one.
+    pub fn post_script_name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.post_script_name_id?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+
+    #[test]
+    fn axes_and_instances() {
+        let mut buf = BeBuffer::new();
+        // two axes, with records padded to 24 bytes; instances of 16 bytes,
+        // with a postscript name id
+        buf.extend([1u16, 0, 16, 2, 2, 24, 2, 16]);
+        buf.push(Tag::new(b"wght"));
+        buf.extend([Fixed::from_f64(100.0), Fixed::from_f64(400.0)]);
+        buf.push(Fixed::from_f64(900.0));
+        buf.extend([0u16, 256, 0xFFFF, 0xFFFF]);
+        buf.push(Tag::new(b"wdth"));
+        buf.extend([Fixed::from_f64(75.0), Fixed::from_f64(100.0)]);
+        buf.push(Fixed::from_f64(100.0));
+        buf.extend([1u16, 257, 0xFFFF, 0xFFFF]);
+        for (subfamily, weight, ps_name) in [(258u16, 700.0, 0xFFFFu16), (259, 300.0, 261)] {
+            buf.extend([subfamily, 0]);
+            buf.extend([Fixed::from_f64(weight), Fixed::from_f64(75.0)]);
+            buf.push(ps_name);
+            buf.push(0u16);
+        }
+
+        let fvar = Fvar::read(&buf).unwrap();
+        let axes = fvar.axes().collect::<Vec<_>>();
+        assert_eq!(axes.len(), 2);
+        assert_eq!(axes[0].axis_tag(), Tag::new(b"wght"));
+        assert_eq!(axes[0].max_value(), Fixed::from_f64(900.0));
+        assert!(!axes[0].is_hidden());
+        assert_eq!(axes[1].axis_tag(), Tag::new(b"wdth"));
+        assert_eq!(axes[1].min_value(), Fixed::from_f64(75.0));
+        assert_eq!(axes[1].axis_name_id(), 257);
+        assert!(axes[1].is_hidden());
+
+        let instances = fvar.instances().collect::<Vec<_>>();
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[1].subfamily_name_id, 259);
+        assert_eq!(instances[1].coordinates[0].get(), Fixed::from_f64(300.0));
+        // the record is padded, so it has a postscript name id
+        assert_eq!(instances[1].post_script_name_id, Some(261));
+        // 0xFFFF means there is no postscript name
+        assert_eq!(instances[0].post_script_name_id, None);
+
+        let coords = fvar.normalize(
+            &[
+                (Tag::new(b"wght"), Fixed::from_f64(650.0)),
+                (Tag::new(b"wdth"), Fixed::from_f64(50.0)),
+            ],
+            None,
+        );
+        assert_eq!(coords, [F2Dot14::from_f32(0.5), F2Dot14::from_f32(-1.0)]);
+        let coords = fvar.normalize(&[(Tag::new(b"wght"), Fixed::from_f64(250.0))], None);
+        assert_eq!(coords, [F2Dot14::from_f32(-0.5), F2Dot14::from_f32(0.0)]);
+
+        let short = InstanceRecord::read(&buf[buf.len() - 16..][..12], 2).unwrap();
+        assert_eq!(short.post_script_name_id, None);
+    }
+
+    #[test]
+    fn normalize_full_range() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 16, 2, 1, 20, 0, 8]);
+        buf.push(Tag::new(b"wght"));
+        buf.extend([Fixed::from_f64(-32768.0), Fixed::from_f64(32767.0)]);
+        buf.push(Fixed::from_f64(32767.0));
+        buf.extend([0u16, 256]);
+
+        let fvar = Fvar::read(&buf).unwrap();
+        let coords = fvar.normalize(&[(Tag::new(b"wght"), Fixed::from_f64(-32768.0))], None);
+        assert_eq!(coords, [F2Dot14::from_f32(-1.0)]);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut buf = BeBuffer::new();
+        // one axis and one instance, followed by data for more instances
+        buf.extend([1u16, 0, 16, 2, 1, 20, 1, 8]);
+        buf.push(Tag::new(b"wght"));
+        buf.extend([Fixed::from_f64(100.0), Fixed::from_f64(400.0)]);
+        buf.push(Fixed::from_f64(900.0));
+        buf.extend([0u16, 256]);
+        for subfamily in [257u16, 258, 259] {
+            buf.extend([subfamily, 0]);
+            buf.push(Fixed::from_f64(700.0));
+        }
+
+        let fvar = Fvar::read(&buf).unwrap();
+        assert!(fvar.axis(0).is_some());
+        assert!(fvar.axis(1).is_none());
+        assert_eq!(fvar.instance(0).unwrap().subfamily_name_id, 257);
+        assert!(fvar.instance(1).is_none());
+        assert_eq!(fvar.axes().count(), 1);
+        assert_eq!(fvar.instances().count(), 1);
+    }
+}
@@ -7,31 +7,415 @@ mod generated;
 
 pub use generated::*;
 
-use crate::layout::ClassDef;
-use font_types::{OffsetHost, Tag};
+use crate::layout::{ClassDef, CoverageTable, DeviceOrVariationIndex, Lookup, LookupFlag};
+use crate::variations::ItemVariationStore;
+use font_types::{FontRead, Offset, OffsetHost, Tag};
 
 /// 'GDEF'
 pub const TAG: Tag = Tag::new(b"GDEF");
 
 impl<'a> Gdef<'a> {
-    pub fn glyph_class_def(&self) -> Option<ClassDef> {
+    pub fn glyph_class_def(&self) -> Option<ClassDef<'a>> {
         self.resolve_offset(self.glyph_class_def_offset())
     }
 
-    pub fn attach_list(&self) -> Option<AttachList> {
+    pub fn attach_list(&self) -> Option<AttachList<'a>> {
         self.resolve_offset(self.attach_list_offset())
     }
 
-    pub fn lig_caret_list(&self) -> Option<LigCaretList> {
+    pub fn lig_caret_list(&self) -> Option<LigCaretList<'a>> {
         self.resolve_offset(self.lig_caret_list_offset())
     }
 
-    pub fn mark_attach_class_def(&self) -> Option<ClassDef> {
+    pub fn mark_attach_class_def(&self) -> Option<ClassDef<'a>> {
         self.resolve_offset(self.mark_attach_class_def_offset())
     }
 
-    pub fn mark_glyph_sets_def(&self) -> Option<MarkGlyphSets> {
+    pub fn mark_glyph_sets_def(&self) -> Option<MarkGlyphSets<'a>> {
         self.mark_glyph_sets_def_offset()
             .and_then(|off| self.resolve_offset(off))
     }
+
+    /// The item variation store, used to resolve the [`VariationIndex`]
+    /// tables in GDEF and GPOS.
+    ///
+    /// This is only present in version 1.3.
+    ///
+    /// [`VariationIndex`]: crate::layout::VariationIndex
+    pub fn item_var_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.item_var_store_offset()
+            .and_then(|off| self.resolve_offset(off))
+    }
+
+    /// The carets for a ligature glyph, in the order they are stored.
+    ///
+    /// This is empty if the glyph has no carets defined.
+    #[cfg(feature = "std")]
+    pub fn ligature_carets(&self, glyph_id: u16) -> Vec<LigatureCaret<'a>> {
+        self.lig_caret_list()
+            .and_then(|list| list.lig_glyph(glyph_id))
+            .map(|lig_glyph| lig_glyph.carets().collect())
+            .unwrap_or_default()
+    }
+
+    /// The caret positions for a ligature glyph, in design units, in
+    /// increasing order.
+    ///
+    /// Carets at a contour point are resolved with `point_coordinate`, and
+    /// skipped if it returns `None`. Device tables adjust carets by a number
+    /// of pixels at a given size, which is converted to design units using
+    /// `ppem` and `units_per_em`; they are ignored if `ppem` is `None`.
+    /// Variation index tables are resolved at the normalized `coords`.
+    #[cfg(feature = "std")]
+    pub fn ligature_caret_positions(
+        &self,
+        glyph_id: u16,
+        ppem: Option<u16>,
+        units_per_em: u16,
+        coords: &[font_types::F2Dot14],
+        point_coordinate: impl Fn(u16) -> Option<i16>,
+    ) -> Vec<f32> {
+        let var_store = self.item_var_store();
+        let mut positions = self
+            .ligature_carets(glyph_id)
+            .iter()
+            .filter_map(|caret| {
+                let coordinate = match caret {
+                    LigatureCaret::Coordinate(coordinate)
+                    | LigatureCaret::DeviceCoordinate(coordinate, _) => *coordinate,
+                    LigatureCaret::ContourPoint(point) => point_coordinate(*point)?,
+                };
+                let delta = match caret.device() {
+                    Some(DeviceOrVariationIndex::Device(device)) => ppem
+                        .filter(|ppem| *ppem != 0)
+                        .map(|ppem| device.delta(ppem) as f32 * units_per_em as f32 / ppem as f32)
+                        .unwrap_or_default(),
+                    Some(DeviceOrVariationIndex::VariationIndex(index)) => var_store
+                        .as_ref()
+                        .and_then(|store| index.compute_delta(store, coords))
+                        .unwrap_or_default(),
+                    None => 0.0,
+                };
+                Some(coordinate as f32 + delta)
+            })
+            .collect::<Vec<_>>();
+        positions.sort_unstable_by(|a, b| a.total_cmp(b));
+        positions
+    }
+
+    /// The attachment point contour indices for a glyph, in increasing
+    /// order.
+    ///
+    /// This is empty if the glyph has no attachment points.
+    #[cfg(feature = "std")]
+    pub fn attach_points(&self, glyph_id: u16) -> Vec<u16> {
+        self.attach_list()
+            .and_then(|list| list.attach_point(glyph_id))
+            .map(|point| point.point_indices().iter().map(|idx| idx.get()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> AttachList<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The attachment points for this glyph, if it has any.
+    pub fn attach_point(&self, glyph_id: u16) -> Option<AttachPoint<'a>> {
+        let coverage_idx = self.coverage()?.get(glyph_id)?;
+        self.attach_point_offsets()
+            .get(coverage_idx as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> LigCaretList<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The caret table for this ligature glyph, if it has one.
+    pub fn lig_glyph(&self, glyph_id: u16) -> Option<LigGlyph<'a>> {
+        let coverage_idx = self.coverage()?.get(glyph_id)?;
+        self.lig_glyph_offsets()
+            .get(coverage_idx as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+/// The position of a caret within a ligature.
+///
+/// Positions are x coordinates for horizontal text, and y coordinates for
+/// vertical text.
+#[derive(Clone, Debug)]
+pub enum LigatureCaret<'a> {
+    /// A coordinate, in design units.
+    Coordinate(i16),
+    /// The caret is at this contour point of the glyph outline.
+    ContourPoint(u16),
+    /// A coordinate, in design units, and the data of a device or variation
+    /// index table to adjust it; see [`LigatureCaret::device`].
+    DeviceCoordinate(i16, Option<&'a [u8]>),
+}
+
+impl<'a> LigatureCaret<'a> {
+    /// The device or variation index table adjusting this caret, if any.
+    pub fn device(&self) -> Option<DeviceOrVariationIndex<'a>> {
+        match self {
+            LigatureCaret::DeviceCoordinate(_, Some(data)) => DeviceOrVariationIndex::read(data),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> LigGlyph<'a> {
+    /// The caret at this index, where there is one caret between each pair
+    /// of ligature components.
+    pub fn caret(&self, idx: usize) -> Option<LigatureCaret<'a>> {
+        let off = self.caret_value_offsets().get(idx)?.get();
+        let data = self.bytes_at_offset(off);
+        let caret = match CaretValue::read(data)? {
+            CaretValue::Format1(table) => LigatureCaret::Coordinate(table.coordinate()),
+            CaretValue::Format2(table) => {
+                LigatureCaret::ContourPoint(table.caret_value_point_index())
+            }
+            CaretValue::Format3(table) => {
+                // the device offset is relative to the CaretValue table
+                let device = table
+                    .device_offset()
+                    .non_null()
+                    .and_then(|off| data.get(off..));
+                LigatureCaret::DeviceCoordinate(table.coordinate(), device)
+            }
+        };
+        Some(caret)
+    }
+
+    /// Iterate over the carets, in the order they are stored.
+    ///
+    /// Carets that cannot be read are skipped.
+    pub fn carets(&self) -> impl Iterator<Item = LigatureCaret<'a>> + '_ {
+        (0..self.caret_value_offsets().len()).filter_map(|idx| self.caret(idx))
+    }
+}
+
+impl<'a> MarkGlyphSets<'a> {
+    /// The coverage table for the mark glyph set at this index.
+    pub fn coverage(&self, set_index: u16) -> Option<CoverageTable<'a>> {
+        self.coverage_offsets()
+            .get(set_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+
+    /// Returns `true` if the given set contains this glyph.
+    pub fn contains(&self, set_index: u16, glyph_id: u16) -> bool {
+        self.coverage(set_index)
+            .and_then(|coverage| coverage.get(glyph_id))
+            .is_some()
+    }
+}
+
+/// Decides which glyphs are skipped by a lookup.
+///
+/// Lookups can ignore base glyphs, ligatures or marks, based on the glyph
+/// classes defined in GDEF. Marks can additionally be filtered by their mark
+/// attachment class, or by membership in a mark glyph set.
+pub struct GlyphFilter<'a> {
+    flags: LookupFlag,
+    glyph_class_def: Option<ClassDef<'a>>,
+    mark_attach_class_def: Option<ClassDef<'a>>,
+    mark_glyph_set: Option<CoverageTable<'a>>,
+}
+
+impl<'a> GlyphFilter<'a> {
+    /// Create a filter for the given lookup.
+    ///
+    /// If there is no GDEF table, no glyphs have a class, and so no glyphs
+    /// are skipped.
+    pub fn new(gdef: Option<&Gdef<'a>>, lookup: &Lookup) -> Self {
+        Self::with_flags(gdef, lookup.lookup_flag(), lookup.mark_filtering_set())
+    }
+
+    /// Create a filter from lookup flags and a mark filtering set index.
+    pub fn with_flags(
+        gdef: Option<&Gdef<'a>>,
+        flags: LookupFlag,
+        mark_filtering_set: Option<u16>,
+    ) -> Self {
+        let mark_glyph_set = if flags.contains(LookupFlag::USE_MARK_FILTERING_SET) {
+            gdef.and_then(Gdef::mark_glyph_sets_def)
+                .zip(mark_filtering_set)
+                .and_then(|(sets, idx)| sets.coverage(idx))
+        } else {
+            None
+        };
+        GlyphFilter {
+            flags,
+            glyph_class_def: gdef.and_then(Gdef::glyph_class_def),
+            mark_attach_class_def: gdef.and_then(Gdef::mark_attach_class_def),
+            mark_glyph_set,
+        }
+    }
+
+    /// Returns `true` if this glyph should be skipped by the lookup.
+    pub fn skips(&self, glyph_id: u16) -> bool {
+        let class = self
+            .glyph_class_def
+            .as_ref()
+            .map(|class_def| class_def.get(glyph_id))
+            .unwrap_or(0);
+        match GlyphClassDef::new(class) {
+            GlyphClassDef::Base => self.flags.contains(LookupFlag::IGNORE_BASE_GLYPHS),
+            GlyphClassDef::Ligature => self.flags.contains(LookupFlag::IGNORE_LIGATURES),
+            GlyphClassDef::Mark => self.skips_mark(glyph_id),
+            _ => false,
+        }
+    }
+
+    fn skips_mark(&self, glyph_id: u16) -> bool {
+        if self.flags.contains(LookupFlag::IGNORE_MARKS) {
+            return true;
+        }
+        // the mark filtering set takes precedence over the attachment type.
+        // A missing set contains no glyphs, so all marks are skipped.
+        if self.flags.contains(LookupFlag::USE_MARK_FILTERING_SET) {
+            return self
+                .mark_glyph_set
+                .as_ref()
+                .and_then(|coverage| coverage.get(glyph_id))
+                .is_none();
+        }
+        match self.flags.mark_attachment_type() {
+            Some(attach_type) => {
+                let class = self
+                    .mark_attach_class_def
+                    .as_ref()
+                    .map(|class_def| class_def.get(glyph_id))
+                    .unwrap_or(0);
+                class != attach_type
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+    use crate::variations::test_helpers::{f2dot14, var_store};
+
+    #[test]
+    fn carets_and_attach_points() {
+        let mut buf = BeBuffer::new();
+        // version 1.0, with attach list & lig caret list
+        buf.extend([1u16, 0, 0, 12, 30, 0]);
+        // attach list: glyph 7 has points 3 & 9
+        buf.extend([6u16, 1, 12]);
+        buf.extend([1u16, 1, 7]);
+        buf.extend([2u16, 3, 9]);
+        // lig caret list: glyph 20 has one caret of each format
+        buf.extend([6u16, 1, 12]);
+        buf.extend([1u16, 1, 20]);
+        buf.extend([3u16, 8, 12, 16]);
+        buf.extend([1u16, 250]);
+        buf.extend([2u16, 5]);
+        buf.extend([3u16, 600, 6]);
+        // device table: +2 at 10ppem
+        buf.extend([10u16, 10, 3, 0x0200]);
+
+        let gdef = Gdef::read(&buf).unwrap();
+        assert_eq!(gdef.attach_points(7), [3, 9]);
+        assert!(gdef.attach_points(20).is_empty());
+        assert!(gdef.ligature_carets(7).is_empty());
+
+        let carets = gdef.ligature_carets(20);
+        assert_eq!(carets.len(), 3);
+        assert!(matches!(carets[0], LigatureCaret::Coordinate(250)));
+        assert!(matches!(carets[1], LigatureCaret::ContourPoint(5)));
+        assert!(matches!(
+            carets[2],
+            LigatureCaret::DeviceCoordinate(600, Some(_))
+        ));
+        match carets[2].device() {
+            Some(DeviceOrVariationIndex::Device(device)) => assert_eq!(device.delta(10), 2),
+            _ => panic!("expected a caret with a device table"),
+        }
+        assert!(carets[0].device().is_none());
+
+        // the contour point is resolved, and the positions sorted
+        let positions =
+            gdef.ligature_caret_positions(20, None, 1000, &[], |point| (point == 5).then_some(-10));
+        assert_eq!(positions, [-10.0, 250.0, 600.0]);
+        let positions = gdef.ligature_caret_positions(20, None, 1000, &[], |_| None);
+        assert_eq!(positions, [250.0, 600.0]);
+        assert!(gdef
+            .ligature_caret_positions(7, None, 1000, &[], |_| Some(0))
+            .is_empty());
+        // 2 pixels at 10ppem is 200 units
+        let positions = gdef.ligature_caret_positions(20, Some(10), 1000, &[], |_| None);
+        assert_eq!(positions, [250.0, 800.0]);
+        let positions = gdef.ligature_caret_positions(20, Some(12), 1000, &[], |_| None);
+        assert_eq!(positions, [250.0, 600.0]);
+    }
+
+    #[test]
+    fn caret_variations() {
+        let mut buf = BeBuffer::new();
+        // version 1.3, with lig caret list & item variation store
+        buf.extend([1u16, 3, 0, 0, 18, 0, 0]);
+        buf.push(46u32);
+        // lig caret list: glyph 20 has one format 3 caret
+        buf.extend([6u16, 1, 12]);
+        buf.extend([1u16, 1, 20]);
+        buf.extend([1u16, 4]);
+        buf.extend([3u16, 600, 6]);
+        // variation index: outer 0, inner 0
+        buf.extend([0u16, 0, 0x8000]);
+        assert_eq!(buf.len(), 46, "sanity check");
+        let data = [&buf[..], &var_store(&[(0.0, 1.0, 1.0)], &[100])].concat();
+
+        let gdef = Gdef::read(&data).unwrap();
+        let positions =
+            |coord| gdef.ligature_caret_positions(20, Some(10), 1000, &[f2dot14(coord)], |_| None);
+        assert_eq!(positions(0.0), [600.0]);
+        assert_eq!(positions(0.5), [650.0]);
+        assert_eq!(positions(1.0), [700.0]);
+    }
+
+    #[test]
+    fn glyph_filter() {
+        let mut buf = BeBuffer::new();
+        // version 1.2, with glyph classes, mark attach classes & mark sets
+        buf.extend([1u16, 2, 14, 0, 0, 36, 48]);
+        // glyph 1 is a base, 2 is a ligature, 3..=5 are marks
+        buf.extend([2u16, 3, 1, 1, 1, 2, 2, 2, 3, 5, 3]);
+        // mark attachment classes for glyphs 3..=5
+        buf.extend([1u16, 3, 3, 1, 2, 1]);
+        // one mark glyph set, containing glyph 4
+        buf.extend([1u16, 1]);
+        buf.push(8u32);
+        buf.extend([1u16, 1, 4]);
+
+        let gdef = Gdef::read(&buf).unwrap();
+        let skipped = |flags: u16, mark_set: Option<u16>| {
+            let flags = LookupFlag::from_bits_truncate(flags);
+            let filter = GlyphFilter::with_flags(Some(&gdef), flags, mark_set);
+            (1..=6).filter(|gid| filter.skips(*gid)).collect::<Vec<_>>()
+        };
+        assert!(skipped(0, None).is_empty());
+        assert_eq!(skipped(0x0002, None), [1]);
+        assert_eq!(skipped(0x0004, None), [2]);
+        assert_eq!(skipped(0x0008, None), [3, 4, 5]);
+        // mark attachment type 2
+        assert_eq!(skipped(0x0200, None), [3, 5]);
+        // mark filtering set takes precedence over the attachment type
+        assert_eq!(skipped(0x0210, Some(0)), [3, 5]);
+        assert_eq!(skipped(0x0010, Some(1)), [3, 4, 5]);
+
+        let no_gdef = GlyphFilter::with_flags(None, LookupFlag::all(), Some(0));
+        assert!(!no_gdef.skips(3));
+    }
 }
@@ -5,14 +5,16 @@ mod generated;
 
 pub use generated::*;
 
-use font_types::{BigEndian, FontRead, Offset32, OffsetHost, Tag};
+use font_types::{BigEndian, F2Dot14, FontRead, Offset, Offset32, OffsetHost, Tag};
 
 /// 'glyf'
 pub const TAG: Tag = Tag::new(b"glyf");
 
 impl<'a> Glyf<'a> {
     pub fn resolve_glyph(&self, offset: Offset32) -> Option<Glyph<'a>> {
-        self.resolve_offset(offset)
+        // offsets from loca are never null; the first glyph is at offset 0
+        let offset = offset.non_null().unwrap_or_default();
+        Glyph::read(self.bytes().get(offset..)?)
     }
 }
 
@@ -45,6 +47,36 @@ impl<'a> Glyph<'a> {
     }
 }
 
+/// The four "phantom points" of a glyph, which encode its metrics.
+///
+/// These are the left and right side bearing points, followed by the top
+/// and bottom, as used by variations and hinting. Glyphs without an
+/// outline have the same phantom points as a glyph with an `x_min` and
+/// `y_max` of 0.
+pub fn phantom_points(
+    glyph: Option<&Glyph>,
+    advance_width: u16,
+    lsb: i16,
+    advance_height: u16,
+    tsb: i16,
+) -> [Point; 4] {
+    let (x_min, y_max) = glyph.map(|g| (g.x_min(), g.y_max())).unwrap_or_default();
+    let left = x_min.wrapping_sub(lsb);
+    let top = y_max.wrapping_add(tsb);
+    [
+        Point { x: left, y: 0 },
+        Point {
+            x: left.wrapping_add(advance_width as i16),
+            y: 0,
+        },
+        Point { x: 0, y: top },
+        Point {
+            x: 0,
+            y: top.wrapping_sub(advance_height as i16),
+        },
+    ]
+}
+
 impl<'a> SimpleGlyph<'a> {
     pub fn iter_points(&self) -> PointIter<'_> {
         self.iter_points_impl()
@@ -68,26 +100,171 @@ impl<'a> SimpleGlyph<'a> {
     }
 }
 
+impl<'a> CompositeGlyph<'a> {
+    /// Iterate over the components of this glyph.
+    pub fn components(&self) -> ComponentIter<'_> {
+        ComponentIter {
+            next: Some((self.flags(), self.glyph_index())),
+            cursor: Cursor::new(self.offset_data()),
+        }
+    }
+
+    /// The instructions for this glyph, if any.
+    pub fn instructions(&self) -> Option<&[u8]> {
+        let mut iter = self.components();
+        let mut have_instructions = false;
+        let mut more_components = false;
+        for component in iter.by_ref() {
+            have_instructions |= component
+                .flags
+                .contains(CompositeGlyphFlags::WE_HAVE_INSTRUCTIONS);
+            more_components = component
+                .flags
+                .contains(CompositeGlyphFlags::MORE_COMPONENTS);
+        }
+        // if we didn't get through all the components, the data is bad
+        if !have_instructions || more_components {
+            return None;
+        }
+        let len = iter.cursor.bump::<u16>()? as usize;
+        iter.cursor.remaining().get(..len)
+    }
+}
+
+/// A component of a [`CompositeGlyph`].
 #[derive(Clone, Copy, Debug)]
+pub struct Component {
+    pub flags: CompositeGlyphFlags,
+    /// The id of the component glyph.
+    pub glyph: u16,
+    /// How this component is positioned relative to the parent.
+    pub anchor: Anchor,
+    /// The 2x2 transform to apply to the component, as `[xx, yx, xy, yy]`.
+    pub transform: [F2Dot14; 4],
+}
+
+/// The positioning of a component in a composite glyph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    /// An x/y offset.
+    Offset { x: i16, y: i16 },
+    /// A point in the parent glyph, and a point in the component, which
+    /// should be aligned.
+    Point { base: u16, component: u16 },
+}
+
+/// An iterator over the components of a composite glyph.
+pub struct ComponentIter<'a> {
+    next: Option<(CompositeGlyphFlags, u16)>,
+    cursor: Cursor<'a>,
+}
+
+impl Iterator for ComponentIter<'_> {
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Component> {
+        let (flags, glyph) = self.next.take()?;
+        let anchor = self.read_anchor(flags)?;
+        let transform = self.read_transform(flags)?;
+        if flags.contains(CompositeGlyphFlags::MORE_COMPONENTS) {
+            let next_flags = self.cursor.bump::<CompositeGlyphFlags>()?;
+            let next_glyph = self.cursor.bump::<u16>()?;
+            self.next = Some((next_flags, next_glyph));
+        }
+        Some(Component {
+            flags,
+            glyph,
+            anchor,
+            transform,
+        })
+    }
+}
+
+impl ComponentIter<'_> {
+    fn read_anchor(&mut self, flags: CompositeGlyphFlags) -> Option<Anchor> {
+        let are_words = flags.contains(CompositeGlyphFlags::ARG_1_AND_2_ARE_WORDS);
+        let are_xy = flags.contains(CompositeGlyphFlags::ARGS_ARE_XY_VALUES);
+        Some(match (are_xy, are_words) {
+            (true, true) => Anchor::Offset {
+                x: self.cursor.bump::<i16>()?,
+                y: self.cursor.bump::<i16>()?,
+            },
+            (true, false) => Anchor::Offset {
+                x: self.cursor.bump::<i8>()? as i16,
+                y: self.cursor.bump::<i8>()? as i16,
+            },
+            (false, true) => Anchor::Point {
+                base: self.cursor.bump::<u16>()?,
+                component: self.cursor.bump::<u16>()?,
+            },
+            (false, false) => Anchor::Point {
+                base: self.cursor.bump::<u8>()? as u16,
+                component: self.cursor.bump::<u8>()? as u16,
+            },
+        })
+    }
+
+    fn read_transform(&mut self, flags: CompositeGlyphFlags) -> Option<[F2Dot14; 4]> {
+        let one = F2Dot14::from_f32(1.0);
+        let zero = F2Dot14::from_f32(0.0);
+        Some(if flags.contains(CompositeGlyphFlags::WE_HAVE_A_SCALE) {
+            let scale = self.cursor.bump()?;
+            [scale, zero, zero, scale]
+        } else if flags.contains(CompositeGlyphFlags::WE_HAVE_AN_X_AND_Y_SCALE) {
+            let x_scale = self.cursor.bump()?;
+            let y_scale = self.cursor.bump()?;
+            [x_scale, zero, zero, y_scale]
+        } else if flags.contains(CompositeGlyphFlags::WE_HAVE_A_TWO_BY_TWO) {
+            [
+                self.cursor.bump()?,
+                self.cursor.bump()?,
+                self.cursor.bump()?,
+                self.cursor.bump()?,
+            ]
+        } else {
+            [one, zero, zero, one]
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Point {
     pub x: i16,
     pub y: i16,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GlyphPoint {
     OffCurve(Point),
     OnCurve(Point),
     End(Point),
 }
 
+impl GlyphPoint {
+    /// The location of this point.
+    pub fn point(&self) -> Point {
+        match self {
+            Self::OffCurve(point) | Self::OnCurve(point) | Self::End(point) => *point,
+        }
+    }
+
+    /// Return a copy of this point, moved to a new location.
+    pub fn with_point(self, point: Point) -> Self {
+        match self {
+            Self::OffCurve(_) => Self::OffCurve(point),
+            Self::OnCurve(_) => Self::OnCurve(point),
+            Self::End(_) => Self::End(point),
+        }
+    }
+}
+
 pub struct PointIter<'a> {
     end_points: &'a [BigEndian<u16>],
     cur_point: u16,
     flags: Cursor<'a>,
     x_coords: Cursor<'a>,
     y_coords: Cursor<'a>,
-    flag_repeats: u8,
+    flag_repeats: u16,
     cur_flags: SimpleGlyphFlags,
     cur_x: i16,
     cur_y: i16,
@@ -147,8 +324,10 @@ impl<'a> PointIter<'a> {
             self.flag_repeats = self
                 .cur_flags
                 .contains(SimpleGlyphFlags::REPEAT_FLAG)
-                .then(|| self.flags.bump())
+                .then(|| self.flags.bump::<u8>())
                 .flatten()
+                // the repeat count doesn't include the first use of the flag
+                .map(|repeats| repeats as u16 + 1)
                 .unwrap_or(1);
         }
         self.flag_repeats -= 1;
@@ -234,10 +413,10 @@ fn resolve_coords_len(data: &[u8], points_total: u16) -> Option<FieldLengths> {
         let y_long = SimpleGlyphFlags::Y_SHORT_VECTOR
             | SimpleGlyphFlags::Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR;
         x_coords_len += ((flags & x_short).bits() != 0) as u32 * repeats;
-        x_coords_len += ((flags & x_long).bits() == 0) as u32 * repeats;
+        x_coords_len += ((flags & x_long).bits() == 0) as u32 * repeats * 2;
 
         y_coords_len += ((flags & y_short).bits() != 0) as u32 * repeats;
-        y_coords_len += ((flags & y_long).bits() == 0) as u32 * repeats;
+        y_coords_len += ((flags & y_long).bits() == 0) as u32 * repeats * 2;
 
         flags_left -= repeats;
     }
@@ -257,20 +436,62 @@ struct FieldLengths {
 }
 
 /// A slice of bytes and an index into them.
-struct Cursor<'a> {
+#[derive(Clone, Default)]
+pub(crate) struct Cursor<'a> {
     data: &'a [u8],
     pos: usize,
 }
 
 impl<'a> Cursor<'a> {
-    fn new(data: &'a [u8]) -> Self {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
         Self { data, pos: 0 }
     }
 
     /// Attempt to read `T` at the current location, advancing if successful.
-    fn bump<T: font_types::Scalar>(&mut self) -> Option<T> {
-        let r = BigEndian::<T>::read(self.data)?;
+    pub(crate) fn bump<T: font_types::Scalar>(&mut self) -> Option<T> {
+        let r = BigEndian::<T>::read(self.data.get(self.pos..)?)?;
         self.pos += std::mem::size_of::<T::Raw>();
         Some(r.get())
     }
+
+    /// The bytes after the current location.
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        self.data.get(self.pos..).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::TableProvider;
+    use crate::FontRef;
+
+    static INCONSOLATA: &[u8] = include_bytes!("../../../resources/Inconsolata-Regular.ttf");
+
+    #[test]
+    fn simple_glyph_points_match_bounds() {
+        let font = FontRef::new(INCONSOLATA).unwrap();
+        let loca = font.loca().unwrap();
+        let glyf = font.glyf().unwrap();
+        let mut n_simple = 0;
+        for offset in loca.iter() {
+            let glyph = match glyf.resolve_glyph(offset) {
+                Some(Glyph::Simple(glyph)) => glyph,
+                _ => continue,
+            };
+            let points = glyph
+                .iter_points()
+                .map(|point| point.point())
+                .collect::<Vec<_>>();
+            let n_points = glyph.end_pts_of_contours().last().unwrap().get() + 1;
+            assert_eq!(points.len(), n_points as usize);
+            let header = glyph.header();
+            assert_eq!(points.iter().map(|p| p.x).min(), Some(header.x_min()));
+            assert_eq!(points.iter().map(|p| p.x).max(), Some(header.x_max()));
+            assert_eq!(points.iter().map(|p| p.y).min(), Some(header.y_min()));
+            assert_eq!(points.iter().map(|p| p.y).max(), Some(header.y_max()));
+            n_simple += 1;
+        }
+        assert!(n_simple > 0);
+    }
 }
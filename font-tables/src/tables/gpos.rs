@@ -0,0 +1,1081 @@
+//! the [GPOS] table
+//!
+//! [GPOS]: https://docs.microsoft.com/en-us/typography/opentype/spec/gpos
+
+#[path = "../../generated/generated_gpos.rs"]
+mod generated;
+
+pub use generated::*;
+
+use crate::layout::{
+    ChainedSequenceContext, ClassDef, CoverageTable, DeviceOrVariationIndex, FeatureList,
+    FeatureVariations, Lookup, LookupList, ScriptList, SequenceContext,
+};
+use font_types::{BigEndian, FontRead, Offset16, OffsetHost, Tag};
+
+#[cfg(feature = "std")]
+use font_types::F2Dot14;
+
+/// 'GPOS'
+pub const TAG: Tag = Tag::new(b"GPOS");
+
+/// The lookup type of [`ExtensionPosFormat1`] subtables.
+const EXTENSION_LOOKUP_TYPE: u16 = 9;
+
+impl<'a> Gpos<'a> {
+    pub fn script_list(&self) -> Option<ScriptList<'a>> {
+        self.resolve_offset(self.script_list_offset())
+    }
+
+    pub fn feature_list(&self) -> Option<FeatureList<'a>> {
+        self.resolve_offset(self.feature_list_offset())
+    }
+
+    pub fn lookup_list(&self) -> Option<LookupList<'a>> {
+        self.resolve_offset(self.lookup_list_offset())
+    }
+
+    pub fn feature_variations(&self) -> Option<FeatureVariations<'a>> {
+        self.feature_variations_offset()
+            .and_then(|off| self.resolve_offset(off))
+    }
+
+    /// Return the lookup at the given index in the lookup list.
+    pub fn lookup(&self, index: u16) -> Option<PositioningLookup<'a>> {
+        self.lookup_list()?.get(index).map(PositioningLookup)
+    }
+
+    /// The indices of the lookups enabled by a feature, for a given script
+    /// and language, in the order they should be applied.
+    ///
+    /// Missing scripts fall back to `DFLT`, and missing languages to the
+    /// script's default language system.
+    #[cfg(feature = "std")]
+    pub fn feature_lookups(&self, script: Tag, lang: Tag, feature: Tag) -> Vec<u16> {
+        crate::layout::feature_lookups(
+            self.script_list(),
+            self.feature_list(),
+            None,
+            script,
+            lang,
+            feature,
+        )
+    }
+
+    /// The indices of the lookups of the required feature, for a given
+    /// script and language, in the order they should be applied.
+    ///
+    /// These are always applied, along with the lookups of any enabled
+    /// features.
+    #[cfg(feature = "std")]
+    pub fn required_lookups(&self, script: Tag, lang: Tag) -> Vec<u16> {
+        crate::layout::required_lookups(self.script_list(), self.feature_list(), None, script, lang)
+    }
+
+    /// The indices of the lookups enabled by a feature at a location in a
+    /// variable font's design space.
+    ///
+    /// This is like [`feature_lookups`](Self::feature_lookups), except that
+    /// feature tables are replaced with the alternates from the first
+    /// matching [`FeatureVariations`] record, if any. `coords` are
+    /// normalized coordinates, in axis order.
+    #[cfg(feature = "std")]
+    pub fn feature_lookups_at(
+        &self,
+        script: Tag,
+        lang: Tag,
+        feature: Tag,
+        coords: &[F2Dot14],
+    ) -> Vec<u16> {
+        let substitution = self
+            .feature_variations()
+            .and_then(|variations| variations.matching_record(coords))
+            .and_then(|(_, substitution)| substitution);
+        crate::layout::feature_lookups(
+            self.script_list(),
+            self.feature_list(),
+            substitution.as_ref(),
+            script,
+            lang,
+            feature,
+        )
+    }
+}
+
+impl ValueFormat {
+    /// The size in bytes of a [`ValueRecord`] with this format.
+    pub fn record_byte_len(self) -> usize {
+        self.bits().count_ones() as usize * 2
+    }
+}
+
+/// A [ValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#value-record)
+///
+/// Value records are variable in size; which fields are present is determined
+/// by the [`ValueFormat`] of the subtable that contains them. Fields that are
+/// not present are `None`.
+///
+/// Device offsets are relative to the immediate parent table: the `SinglePos`
+/// or `PairPosFormat2` subtable, or the [`PairSet`] in a `PairPosFormat1`
+/// subtable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValueRecord {
+    pub x_placement: Option<i16>,
+    pub y_placement: Option<i16>,
+    pub x_advance: Option<i16>,
+    pub y_advance: Option<i16>,
+    pub x_placement_device: Option<Offset16>,
+    pub y_placement_device: Option<Offset16>,
+    pub x_advance_device: Option<Offset16>,
+    pub y_advance_device: Option<Offset16>,
+}
+
+impl ValueRecord {
+    /// Read a value record with the given format from the front of `bytes`.
+    pub fn read(bytes: &[u8], format: ValueFormat) -> Option<Self> {
+        let bytes = bytes.get(..format.record_byte_len())?;
+        let mut words = bytes
+            .chunks_exact(2)
+            .map(|word| u16::from_be_bytes([word[0], word[1]]));
+        let mut next = |flag| format.contains(flag).then(|| words.next()).flatten();
+        Some(ValueRecord {
+            x_placement: next(ValueFormat::X_PLACEMENT).map(|x| x as i16),
+            y_placement: next(ValueFormat::Y_PLACEMENT).map(|x| x as i16),
+            x_advance: next(ValueFormat::X_ADVANCE).map(|x| x as i16),
+            y_advance: next(ValueFormat::Y_ADVANCE).map(|x| x as i16),
+            x_placement_device: next(ValueFormat::X_PLACEMENT_DEVICE).map(Offset16::new),
+            y_placement_device: next(ValueFormat::Y_PLACEMENT_DEVICE).map(Offset16::new),
+            x_advance_device: next(ValueFormat::X_ADVANCE_DEVICE).map(Offset16::new),
+            y_advance_device: next(ValueFormat::Y_ADVANCE_DEVICE).map(Offset16::new),
+        })
+    }
+
+    /// The device or variation index table for the horizontal placement.
+    ///
+    /// `parent` is the table that contains this record.
+    pub fn x_placement_device<'a>(
+        &self,
+        parent: &impl OffsetHost<'a>,
+    ) -> Option<DeviceOrVariationIndex<'a>> {
+        parent.resolve_offset(self.x_placement_device?)
+    }
+
+    /// The device or variation index table for the vertical placement.
+    ///
+    /// `parent` is the table that contains this record.
+    pub fn y_placement_device<'a>(
+        &self,
+        parent: &impl OffsetHost<'a>,
+    ) -> Option<DeviceOrVariationIndex<'a>> {
+        parent.resolve_offset(self.y_placement_device?)
+    }
+
+    /// The device or variation index table for the horizontal advance.
+    ///
+    /// `parent` is the table that contains this record.
+    pub fn x_advance_device<'a>(
+        &self,
+        parent: &impl OffsetHost<'a>,
+    ) -> Option<DeviceOrVariationIndex<'a>> {
+        parent.resolve_offset(self.x_advance_device?)
+    }
+
+    /// The device or variation index table for the vertical advance.
+    ///
+    /// `parent` is the table that contains this record.
+    pub fn y_advance_device<'a>(
+        &self,
+        parent: &impl OffsetHost<'a>,
+    ) -> Option<DeviceOrVariationIndex<'a>> {
+        parent.resolve_offset(self.y_advance_device?)
+    }
+}
+
+/// A [PairValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#pair-adjustment-positioning-format-1-adjustments-for-glyph-pairs),
+/// part of a [`PairSet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PairValueRecord {
+    /// Glyph ID of the second glyph in the pair.
+    pub second_glyph: u16,
+    /// Positioning data for the first glyph in the pair.
+    pub value_record1: ValueRecord,
+    /// Positioning data for the second glyph in the pair.
+    pub value_record2: ValueRecord,
+}
+
+/// A lookup in the GPOS table.
+///
+/// This is a thin wrapper around [`Lookup`] that knows how to interpret
+/// its subtables.
+pub struct PositioningLookup<'a>(Lookup<'a>);
+
+/// A GPOS lookup subtable, of any lookup type.
+///
+/// Extension subtables (lookup type 9) are resolved to the subtable they
+/// point to, and never appear here.
+pub enum PositioningSubtable<'a> {
+    /// Lookup type 1
+    Single(SinglePos<'a>),
+    /// Lookup type 2
+    Pair(PairPos<'a>),
+    /// Lookup type 3
+    Cursive(CursivePosFormat1<'a>),
+    /// Lookup type 4
+    MarkToBase(MarkBasePosFormat1<'a>),
+    /// Lookup type 5
+    MarkToLigature(MarkLigPosFormat1<'a>),
+    /// Lookup type 6
+    MarkToMark(MarkMarkPosFormat1<'a>),
+    /// Lookup type 7
+    Contextual(SequenceContext<'a>),
+    /// Lookup type 8
+    ChainContextual(ChainedSequenceContext<'a>),
+}
+
+impl<'a> PositioningLookup<'a> {
+    /// The underlying lookup table.
+    pub fn lookup(&self) -> &Lookup<'a> {
+        &self.0
+    }
+
+    /// The type of this lookup.
+    ///
+    /// For extension lookups, this is the type of the extension subtables.
+    pub fn lookup_type(&self) -> u16 {
+        let lookup_type = self.0.lookup_type();
+        if lookup_type != EXTENSION_LOOKUP_TYPE {
+            return lookup_type;
+        }
+        // all subtables of an extension lookup must have the same type
+        self.0
+            .subtable_data()
+            .next()
+            .and_then(ExtensionPosFormat1::read)
+            .map(|ext| ext.extension_lookup_type())
+            .unwrap_or(lookup_type)
+    }
+
+    /// Iterate over this lookup's subtables.
+    ///
+    /// Subtables that cannot be read are skipped.
+    pub fn subtables(&self) -> impl Iterator<Item = PositioningSubtable<'a>> + '_ {
+        let lookup_type = self.0.lookup_type();
+        self.0
+            .subtable_data()
+            .filter_map(move |data| PositioningSubtable::read(lookup_type, data))
+    }
+}
+
+impl<'a> PositioningSubtable<'a> {
+    /// Read a subtable of the given lookup type.
+    ///
+    /// If `lookup_type` is 9 (extension) the extension subtable is resolved.
+    pub fn read(lookup_type: u16, data: &'a [u8]) -> Option<Self> {
+        match lookup_type {
+            1 => SinglePos::read(data).map(Self::Single),
+            2 => PairPos::read(data).map(Self::Pair),
+            3 => CursivePosFormat1::read(data).map(Self::Cursive),
+            4 => MarkBasePosFormat1::read(data).map(Self::MarkToBase),
+            5 => MarkLigPosFormat1::read(data).map(Self::MarkToLigature),
+            6 => MarkMarkPosFormat1::read(data).map(Self::MarkToMark),
+            7 => SequenceContext::read(data).map(Self::Contextual),
+            8 => ChainedSequenceContext::read(data).map(Self::ChainContextual),
+            EXTENSION_LOOKUP_TYPE => ExtensionPosFormat1::read(data)?.subtable(),
+            _ => None,
+        }
+    }
+
+    /// The coverage table for the first glyph matched by this subtable.
+    ///
+    /// For mark attachment subtables, this is the mark coverage.
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            Self::Single(table) => table.coverage(),
+            Self::Pair(table) => table.coverage(),
+            Self::Cursive(table) => table.coverage(),
+            Self::MarkToBase(table) => table.mark_coverage(),
+            Self::MarkToLigature(table) => table.mark_coverage(),
+            Self::MarkToMark(table) => table.mark1_coverage(),
+            Self::Contextual(table) => table.coverage(),
+            Self::ChainContextual(table) => table.coverage(),
+        }
+    }
+}
+
+impl<'a> AnchorTable<'a> {
+    /// Horizontal value, in design units
+    pub fn x_coordinate(&self) -> i16 {
+        match self {
+            AnchorTable::Format1(table) => table.x_coordinate(),
+            AnchorTable::Format2(table) => table.x_coordinate(),
+            AnchorTable::Format3(table) => table.x_coordinate(),
+        }
+    }
+
+    /// Vertical value, in design units
+    pub fn y_coordinate(&self) -> i16 {
+        match self {
+            AnchorTable::Format1(table) => table.y_coordinate(),
+            AnchorTable::Format2(table) => table.y_coordinate(),
+            AnchorTable::Format3(table) => table.y_coordinate(),
+        }
+    }
+
+    /// Index to glyph contour point, if this is a format 2 anchor.
+    pub fn anchor_point(&self) -> Option<u16> {
+        match self {
+            AnchorTable::Format2(table) => Some(table.anchor_point()),
+            _ => None,
+        }
+    }
+
+    /// The device or variation index table for the x coordinate, if any.
+    pub fn x_device(&self) -> Option<DeviceOrVariationIndex<'a>> {
+        match self {
+            AnchorTable::Format3(table) => table.resolve_offset(table.x_device_offset()),
+            _ => None,
+        }
+    }
+
+    /// The device or variation index table for the y coordinate, if any.
+    pub fn y_device(&self) -> Option<DeviceOrVariationIndex<'a>> {
+        match self {
+            AnchorTable::Format3(table) => table.resolve_offset(table.y_device_offset()),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> MarkArray<'a> {
+    /// The class and anchor for the mark with the given coverage index.
+    pub fn mark_anchor(&self, coverage_index: u16) -> Option<(u16, AnchorTable<'a>)> {
+        let record = self.mark_records().get(coverage_index as usize)?;
+        let anchor = self.resolve_offset(record.mark_anchor_offset())?;
+        Some((record.mark_class(), anchor))
+    }
+}
+
+impl<'a> OffsetHost<'a> for SinglePos<'a> {
+    fn bytes(&self) -> &'a [u8] {
+        match self {
+            SinglePos::Format1(table) => table.bytes(),
+            SinglePos::Format2(table) => table.bytes(),
+        }
+    }
+}
+
+impl<'a> SinglePos<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            SinglePos::Format1(table) => table.resolve_offset(table.coverage_offset()),
+            SinglePos::Format2(table) => table.resolve_offset(table.coverage_offset()),
+        }
+    }
+
+    /// The value record for the glyph with the given coverage index.
+    ///
+    /// Device offsets in the record are relative to this subtable.
+    pub fn value_record(&self, coverage_index: u16) -> Option<ValueRecord> {
+        match self {
+            SinglePos::Format1(table) => table.value_record(),
+            SinglePos::Format2(table) => table.value_record(coverage_index),
+        }
+    }
+}
+
+impl<'a> SinglePosFormat1<'a> {
+    /// The value record applied to all covered glyphs.
+    pub fn value_record(&self) -> Option<ValueRecord> {
+        ValueRecord::read(self.value_record_data(), self.value_format())
+    }
+}
+
+impl<'a> SinglePosFormat2<'a> {
+    /// The value record for the glyph with the given coverage index.
+    pub fn value_record(&self, coverage_index: u16) -> Option<ValueRecord> {
+        let format = self.value_format();
+        let len = format.record_byte_len();
+        let start = coverage_index as usize * len;
+        ValueRecord::read(self.value_records_data().get(start..)?, format)
+    }
+}
+
+impl<'a> PairPos<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            PairPos::Format1(table) => table.resolve_offset(table.coverage_offset()),
+            PairPos::Format2(table) => table.resolve_offset(table.coverage_offset()),
+        }
+    }
+
+    /// The adjustments for a pair of glyphs, if this subtable covers them.
+    pub fn adjustment(&self, first: u16, second: u16) -> Option<(ValueRecord, ValueRecord)> {
+        let coverage_index = self.coverage()?.get(first)?;
+        match self {
+            PairPos::Format1(table) => {
+                let record = table.pair_value_record(coverage_index, second)?;
+                Some((record.value_record1, record.value_record2))
+            }
+            PairPos::Format2(table) => {
+                let class1 = table.class_def1()?.get(first);
+                let class2 = table.class_def2()?.get(second);
+                table.class2_record(class1, class2)
+            }
+        }
+    }
+}
+
+impl<'a> PairPosFormat1<'a> {
+    /// The [`PairSet`] for the glyph with the given coverage index.
+    pub fn pair_set(&self, coverage_index: u16) -> Option<PairSet<'a>> {
+        let offset = self.pair_set_offsets().get(coverage_index as usize)?.get();
+        PairSet::read(self.bytes_at_offset(offset), self.pair_value_record_len())
+    }
+
+    /// Iterate over the records in a [`PairSet`] from this subtable.
+    ///
+    /// Device offsets in these records are relative to the pair set.
+    pub fn pair_value_records<'b>(
+        &self,
+        pair_set: &'b PairSet<'a>,
+    ) -> impl Iterator<Item = PairValueRecord> + 'b {
+        let format1 = self.value_format1();
+        let format2 = self.value_format2();
+        let record_len = self.pair_value_record_len();
+        pair_set
+            .pair_value_record_data()
+            .chunks_exact(record_len)
+            .filter_map(move |bytes| read_pair_value_record(bytes, format1, format2))
+    }
+
+    /// Find the record for the given second glyph, in the pair set for the
+    /// given coverage index.
+    pub fn pair_value_record(&self, coverage_index: u16, second: u16) -> Option<PairValueRecord> {
+        let pair_set = self.pair_set(coverage_index)?;
+        let data = pair_set.pair_value_record_data();
+        let record_len = self.pair_value_record_len();
+        // the records in a pair set have the same size, and are sorted by
+        // the second glyph
+        let (mut lo, mut hi) = (0, data.len() / record_len);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let bytes = &data[mid * record_len..(mid + 1) * record_len];
+            match u16::from_be_bytes([bytes[0], bytes[1]]).cmp(&second) {
+                core::cmp::Ordering::Less => lo = mid + 1,
+                core::cmp::Ordering::Greater => hi = mid,
+                core::cmp::Ordering::Equal => {
+                    return read_pair_value_record(
+                        bytes,
+                        self.value_format1(),
+                        self.value_format2(),
+                    )
+                }
+            }
+        }
+        None
+    }
+
+    /// The size of a record in this subtable's pair sets.
+    fn pair_value_record_len(&self) -> usize {
+        2 + self.value_format1().record_byte_len() + self.value_format2().record_byte_len()
+    }
+}
+
+fn read_pair_value_record(
+    bytes: &[u8],
+    format1: ValueFormat,
+    format2: ValueFormat,
+) -> Option<PairValueRecord> {
+    Some(PairValueRecord {
+        second_glyph: u16::from_be_bytes([*bytes.first()?, *bytes.get(1)?]),
+        value_record1: ValueRecord::read(bytes.get(2..)?, format1)?,
+        value_record2: ValueRecord::read(bytes.get(2 + format1.record_byte_len()..)?, format2)?,
+    })
+}
+
+impl<'a> PairPosFormat2<'a> {
+    pub fn class_def1(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.class_def1_offset())
+    }
+
+    pub fn class_def2(&self) -> Option<ClassDef<'a>> {
+        self.resolve_offset(self.class_def2_offset())
+    }
+
+    /// The value records for the given pair of classes.
+    ///
+    /// Device offsets in these records are relative to this subtable.
+    pub fn class2_record(&self, class1: u16, class2: u16) -> Option<(ValueRecord, ValueRecord)> {
+        if class1 >= self.class1_count() || class2 >= self.class2_count() {
+            return None;
+        }
+        let format1 = self.value_format1();
+        let format2 = self.value_format2();
+        let len1 = format1.record_byte_len();
+        let record_len = len1 + format2.record_byte_len();
+        let index = class1 as usize * self.class2_count() as usize + class2 as usize;
+        let bytes = self.class1_record_data().get(index * record_len..)?;
+        Some((
+            ValueRecord::read(bytes, format1)?,
+            ValueRecord::read(bytes.get(len1..)?, format2)?,
+        ))
+    }
+}
+
+impl<'a> CursivePosFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The entry anchor for the glyph with the given coverage index.
+    pub fn entry_anchor(&self, coverage_index: u16) -> Option<AnchorTable<'a>> {
+        let record = self.entry_exit_record().get(coverage_index as usize)?;
+        self.resolve_offset(record.entry_anchor_offset())
+    }
+
+    /// The exit anchor for the glyph with the given coverage index.
+    pub fn exit_anchor(&self, coverage_index: u16) -> Option<AnchorTable<'a>> {
+        let record = self.entry_exit_record().get(coverage_index as usize)?;
+        self.resolve_offset(record.exit_anchor_offset())
+    }
+}
+
+impl<'a> MarkBasePosFormat1<'a> {
+    pub fn mark_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.mark_coverage_offset())
+    }
+
+    pub fn base_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.base_coverage_offset())
+    }
+
+    pub fn mark_array(&self) -> Option<MarkArray<'a>> {
+        self.resolve_offset(self.mark_array_offset())
+    }
+
+    pub fn base_array(&self) -> Option<BaseArray<'a>> {
+        let data = self.bytes_at_offset(self.base_array_offset());
+        BaseArray::read(data, self.mark_class_count() as usize)
+    }
+}
+
+impl<'a> BaseArray<'a> {
+    /// The anchor for the given mark class on the base with the given
+    /// coverage index.
+    pub fn base_anchor(&self, coverage_index: u16, mark_class: u16) -> Option<AnchorTable<'a>> {
+        let offset = anchor_offset(
+            self.base_anchor_offsets(),
+            self.base_count(),
+            coverage_index,
+            mark_class,
+        )?;
+        self.resolve_offset(offset)
+    }
+}
+
+impl<'a> MarkLigPosFormat1<'a> {
+    pub fn mark_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.mark_coverage_offset())
+    }
+
+    pub fn ligature_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.ligature_coverage_offset())
+    }
+
+    pub fn mark_array(&self) -> Option<MarkArray<'a>> {
+        self.resolve_offset(self.mark_array_offset())
+    }
+
+    pub fn ligature_array(&self) -> Option<LigatureArray<'a>> {
+        self.resolve_offset(self.ligature_array_offset())
+    }
+
+    /// The [`LigatureAttach`] table for the ligature with the given
+    /// coverage index.
+    pub fn ligature_attach(&self, coverage_index: u16) -> Option<LigatureAttach<'a>> {
+        let array = self.ligature_array()?;
+        let offset = array
+            .ligature_attach_offsets()
+            .get(coverage_index as usize)?
+            .get();
+        LigatureAttach::read(
+            array.bytes_at_offset(offset),
+            self.mark_class_count() as usize,
+        )
+    }
+}
+
+impl<'a> LigatureAttach<'a> {
+    /// The anchor for the given mark class on the given ligature component.
+    pub fn ligature_anchor(&self, component: u16, mark_class: u16) -> Option<AnchorTable<'a>> {
+        let offset = anchor_offset(
+            self.ligature_anchor_offsets(),
+            self.component_count(),
+            component,
+            mark_class,
+        )?;
+        self.resolve_offset(offset)
+    }
+}
+
+impl<'a> MarkMarkPosFormat1<'a> {
+    pub fn mark1_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.mark1_coverage_offset())
+    }
+
+    pub fn mark2_coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.mark2_coverage_offset())
+    }
+
+    pub fn mark1_array(&self) -> Option<MarkArray<'a>> {
+        self.resolve_offset(self.mark1_array_offset())
+    }
+
+    pub fn mark2_array(&self) -> Option<Mark2Array<'a>> {
+        let data = self.bytes_at_offset(self.mark2_array_offset());
+        Mark2Array::read(data, self.mark_class_count() as usize)
+    }
+}
+
+impl<'a> Mark2Array<'a> {
+    /// The anchor for the given mark class on the mark2 glyph with the given
+    /// coverage index.
+    pub fn mark2_anchor(&self, coverage_index: u16, mark_class: u16) -> Option<AnchorTable<'a>> {
+        let offset = anchor_offset(
+            self.mark2_anchor_offsets(),
+            self.mark2_count(),
+            coverage_index,
+            mark_class,
+        )?;
+        self.resolve_offset(offset)
+    }
+}
+
+/// Find an offset in a flattened array of records, each of which has one
+/// anchor offset per mark class.
+fn anchor_offset(
+    offsets: &[BigEndian<Offset16>],
+    record_count: u16,
+    record: u16,
+    mark_class: u16,
+) -> Option<Offset16> {
+    if record >= record_count {
+        return None;
+    }
+    let mark_class_count = offsets.len() / record_count as usize;
+    if mark_class as usize >= mark_class_count {
+        return None;
+    }
+    let index = record as usize * mark_class_count + mark_class as usize;
+    offsets.get(index).map(|offset| offset.get())
+}
+
+impl<'a> ExtensionPosFormat1<'a> {
+    /// Resolve the subtable this extension points to.
+    ///
+    /// Returns `None` if the subtable is itself an extension.
+    pub fn subtable(&self) -> Option<PositioningSubtable<'a>> {
+        let lookup_type = self.extension_lookup_type();
+        if lookup_type == EXTENSION_LOOKUP_TYPE {
+            return None;
+        }
+        let data = self.bytes_at_offset(self.extension_offset());
+        PositioningSubtable::read(lookup_type, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::layout::test_helpers::{coverage, layout_table, lookup, table, u16_array};
+
+    /// Read the first subtable of each lookup in a GPOS table.
+    fn first_subtables(data: &[u8]) -> Vec<PositioningSubtable<'_>> {
+        let gpos = Gpos::read(data).unwrap();
+        let count = gpos.lookup_list().unwrap().lookup_count();
+        (0..count)
+            .map(|idx| gpos.lookup(idx).unwrap().subtables().next().unwrap())
+            .collect()
+    }
+
+    /// A format 1 anchor table.
+    fn anchor(x: i16, y: i16) -> Vec<u8> {
+        let mut buf = u16_array(&[1]);
+        buf.extend([x, y]);
+        buf.to_vec()
+    }
+
+    /// A device table adjusting by 1 at 10ppem and 2 at 11ppem.
+    fn device() -> Vec<u8> {
+        u16_array(&[10, 11, 2, 0x1200]).to_vec()
+    }
+
+    fn coords(anchor: &AnchorTable) -> (i16, i16) {
+        (anchor.x_coordinate(), anchor.y_coordinate())
+    }
+
+    #[test]
+    fn pair_pos_value_records() {
+        let mut buf = BeBuffer::new();
+        // format 1, X_ADVANCE for the first glyph, X_PLACEMENT | X_PLACEMENT_DEVICE
+        // for the second, one pair set
+        buf.extend([1u16, 12, 0x0004, 0x0011, 1, 18]);
+        // coverage
+        buf.extend([1u16, 1, 5]);
+        // pair set, with two records
+        buf.push(2u16);
+        buf.extend([7u16, (-50i16) as u16, 10, 18]);
+        buf.extend([9u16, (-30i16) as u16, 0, 26]);
+        // device table
+        buf.extend([12u16, 13, 1, 0x1234]);
+        // variation index table
+        buf.extend([0u16, 3, 0x8000]);
+
+        let pair_pos = PairPos::read(&buf).unwrap();
+        assert!(pair_pos.adjustment(5, 8).is_none());
+        assert!(pair_pos.adjustment(6, 7).is_none());
+        let (first, second) = pair_pos.adjustment(5, 7).unwrap();
+        assert_eq!(
+            first,
+            ValueRecord {
+                x_advance: Some(-50),
+                ..Default::default()
+            }
+        );
+        assert_eq!(second.x_placement, Some(10));
+        assert!(second.x_advance.is_none());
+        let (first, _) = pair_pos.adjustment(5, 9).unwrap();
+        assert_eq!(first.x_advance, Some(-30));
+
+        let table = match &pair_pos {
+            PairPos::Format1(table) => table,
+            _ => panic!("expected format 1"),
+        };
+        let pair_set = table.pair_set(0).unwrap();
+        let records = table.pair_value_records(&pair_set).collect::<Vec<_>>();
+        assert_eq!(records.len(), 2);
+        match records[0].value_record2.x_placement_device(&pair_set) {
+            Some(DeviceOrVariationIndex::Device(device)) => {
+                assert_eq!(device.start_size(), 12);
+                assert_eq!(device.delta_format(), 1);
+            }
+            _ => panic!("expected device table"),
+        }
+        match records[1].value_record2.x_placement_device(&pair_set) {
+            Some(DeviceOrVariationIndex::VariationIndex(index)) => {
+                assert_eq!(index.delta_set_inner_index(), 3);
+            }
+            _ => panic!("expected variation index table"),
+        }
+    }
+
+    #[test]
+    fn pair_pos_search() {
+        let mut buf = BeBuffer::new();
+        // format 1, X_ADVANCE for the first glyph only, one pair set
+        buf.extend([1u16, 12, 0x0004, 0, 1, 18]);
+        buf.extend([1u16, 1, 1]);
+        // pair set, with records for the even glyphs 2..=10
+        buf.push(5u16);
+        for glyph in [2u16, 4, 6, 8, 10] {
+            buf.extend([glyph, glyph * 10]);
+        }
+
+        let pair_pos = PairPos::read(&buf).unwrap();
+        for second in 0..12u16 {
+            let advance = pair_pos
+                .adjustment(1, second)
+                .map(|(first, _)| first.x_advance.unwrap());
+            let expected = (second >= 2 && second % 2 == 0).then_some(second as i16 * 10);
+            assert_eq!(advance, expected, "second glyph {}", second);
+        }
+    }
+
+    #[test]
+    fn mark_to_base() {
+        let mut buf = BeBuffer::new();
+        // two mark classes
+        buf.extend([1u16, 12, 18, 2, 24, 36]);
+        // mark coverage & base coverage
+        buf.extend([1u16, 1, 20]);
+        buf.extend([1u16, 1, 10]);
+        // mark array: one mark in class 1, with a format 1 anchor
+        buf.extend([1u16, 1, 6]);
+        buf.extend([1u16, 100, 200]);
+        // base array: one base, null anchor for class 0
+        buf.extend([1u16, 0, 6]);
+        // format 2 anchor
+        buf.extend([2u16, 300, 400, 5]);
+
+        let table = MarkBasePosFormat1::read(&buf).unwrap();
+        let mark_idx = table.mark_coverage().unwrap().get(20).unwrap();
+        let (class, mark_anchor) = table.mark_array().unwrap().mark_anchor(mark_idx).unwrap();
+        assert_eq!(class, 1);
+        assert_eq!(
+            (mark_anchor.x_coordinate(), mark_anchor.y_coordinate()),
+            (100, 200)
+        );
+        let base_idx = table.base_coverage().unwrap().get(10).unwrap();
+        let base_array = table.base_array().unwrap();
+        assert!(base_array.base_anchor(base_idx, 0).is_none());
+        assert!(base_array.base_anchor(base_idx, 2).is_none());
+        let base_anchor = base_array.base_anchor(base_idx, class).unwrap();
+        assert_eq!(base_anchor.x_coordinate(), 300);
+        assert_eq!(base_anchor.anchor_point(), Some(5));
+    }
+
+    #[test]
+    fn single() {
+        // X_ADVANCE | X_ADVANCE_DEVICE
+        let format1 = table(
+            |offsets| {
+                let mut buf = u16_array(&[1, offsets[0], 0x0044]);
+                buf.push(-5i16);
+                buf.push(offsets[1]);
+                buf
+            },
+            &[coverage(&[3, 4]), device()],
+        );
+        // X_ADVANCE, for each glyph
+        let format2 = table(
+            |offsets| u16_array(&[2, offsets[0], 0x0004, 2, 30, 40]),
+            &[coverage(&[5, 6])],
+        );
+        let data = layout_table(&[], &[lookup(1, 0, &[format1, format2])]);
+        let gpos = Gpos::read(&data).unwrap();
+        let lookup = gpos.lookup(0).unwrap();
+        assert_eq!(lookup.lookup_type(), 1);
+        let subtables = lookup.subtables().collect::<Vec<_>>();
+        match &subtables[..] {
+            [PositioningSubtable::Single(first), PositioningSubtable::Single(second)] => {
+                assert_eq!(subtables[0].coverage().unwrap().get(4), Some(1));
+                // format 1 uses the same record for every glyph
+                let record = first.value_record(1).unwrap();
+                assert_eq!(record, first.value_record(0).unwrap());
+                assert_eq!(record.x_advance, Some(-5));
+                assert!(record.x_placement.is_none());
+                match record.x_advance_device(first) {
+                    Some(DeviceOrVariationIndex::Device(device)) => {
+                        assert_eq!(device.delta(11), 2)
+                    }
+                    _ => panic!("expected device table"),
+                }
+                assert!(record.x_placement_device(first).is_none());
+
+                assert_eq!(subtables[1].coverage().unwrap().get(6), Some(1));
+                assert_eq!(second.value_record(0).unwrap().x_advance, Some(30));
+                assert_eq!(second.value_record(1).unwrap().x_advance, Some(40));
+                assert!(second.value_record(2).is_none());
+            }
+            _ => panic!("expected two single subtables"),
+        }
+    }
+
+    #[test]
+    fn anchors() {
+        let format1 = anchor(-10, 20);
+        let format2 = u16_array(&[2, 30, 40, 7]).to_vec();
+        // a device table for x and a variation index for y
+        let format3 = table(
+            |offsets| u16_array(&[3, 50, 60, offsets[0], offsets[1]]),
+            &[device(), u16_array(&[1, 2, 0x8000]).to_vec()],
+        );
+        let no_devices = u16_array(&[3, 70, 80, 0, 0]).to_vec();
+
+        let anchor = AnchorTable::read(&format1).unwrap();
+        assert_eq!(coords(&anchor), (-10, 20));
+        assert!(anchor.anchor_point().is_none());
+        assert!(anchor.x_device().is_none());
+
+        let anchor = AnchorTable::read(&format2).unwrap();
+        assert_eq!(coords(&anchor), (30, 40));
+        assert_eq!(anchor.anchor_point(), Some(7));
+        assert!(anchor.y_device().is_none());
+
+        let anchor = AnchorTable::read(&format3).unwrap();
+        assert_eq!(coords(&anchor), (50, 60));
+        assert!(anchor.anchor_point().is_none());
+        match anchor.x_device() {
+            Some(DeviceOrVariationIndex::Device(device)) => {
+                assert_eq!(device.delta(10), 1);
+                assert_eq!(device.delta(12), 0);
+            }
+            _ => panic!("expected device table"),
+        }
+        match anchor.y_device() {
+            Some(DeviceOrVariationIndex::VariationIndex(index)) => {
+                assert_eq!(index.delta_set_outer_index(), 1);
+                assert_eq!(index.delta_set_inner_index(), 2);
+            }
+            _ => panic!("expected variation index table"),
+        }
+
+        let anchor = AnchorTable::read(&no_devices).unwrap();
+        assert_eq!(coords(&anchor), (70, 80));
+        assert!(anchor.x_device().is_none());
+        assert!(anchor.y_device().is_none());
+    }
+
+    #[test]
+    fn cursive() {
+        // glyph 7 has only an entry anchor, and glyph 8 only an exit anchor
+        let exit = table(
+            |offsets| u16_array(&[3, 500, 600, offsets[0], 0]),
+            &[device()],
+        );
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], 2, offsets[1], 0, 0, offsets[2]]),
+            &[coverage(&[7, 8]), anchor(0, 100), exit],
+        );
+        let data = layout_table(&[], &[lookup(3, 0, &[subtable])]);
+        let table = match first_subtables(&data).pop() {
+            Some(PositioningSubtable::Cursive(table)) => table,
+            _ => panic!("expected cursive subtable"),
+        };
+        assert_eq!(table.coverage().unwrap().get(8), Some(1));
+        assert_eq!(coords(&table.entry_anchor(0).unwrap()), (0, 100));
+        assert!(table.exit_anchor(0).is_none());
+        assert!(table.entry_anchor(1).is_none());
+        let exit = table.exit_anchor(1).unwrap();
+        assert_eq!(coords(&exit), (500, 600));
+        assert!(matches!(
+            exit.x_device(),
+            Some(DeviceOrVariationIndex::Device(_))
+        ));
+        assert!(table.entry_anchor(2).is_none());
+    }
+
+    #[test]
+    fn mark_to_ligature() {
+        // one mark class, and a ligature with two components
+        let mark_array = table(|offsets| u16_array(&[1, 0, offsets[0]]), &[anchor(0, 600)]);
+        let ligature_attach = table(
+            |offsets| u16_array(&[2, offsets[0], offsets[1]]),
+            &[anchor(100, 700), anchor(400, 700)],
+        );
+        let ligature_array = table(|offsets| u16_array(&[1, offsets[0]]), &[ligature_attach]);
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 1, offsets[2], offsets[3]]),
+            &[coverage(&[20]), coverage(&[30]), mark_array, ligature_array],
+        );
+        let data = layout_table(&[], &[lookup(5, 0, &[subtable])]);
+        let table = match first_subtables(&data).pop() {
+            Some(PositioningSubtable::MarkToLigature(table)) => table,
+            _ => panic!("expected mark to ligature subtable"),
+        };
+        assert_eq!(table.mark_coverage().unwrap().get(20), Some(0));
+        assert_eq!(table.ligature_coverage().unwrap().get(30), Some(0));
+        let (class, mark_anchor) = table.mark_array().unwrap().mark_anchor(0).unwrap();
+        assert_eq!(class, 0);
+        assert_eq!(coords(&mark_anchor), (0, 600));
+        let ligature = table.ligature_attach(0).unwrap();
+        assert_eq!(ligature.component_count(), 2);
+        assert_eq!(coords(&ligature.ligature_anchor(0, 0).unwrap()), (100, 700));
+        assert_eq!(coords(&ligature.ligature_anchor(1, 0).unwrap()), (400, 700));
+        assert!(ligature.ligature_anchor(2, 0).is_none());
+        assert!(ligature.ligature_anchor(0, 1).is_none());
+        assert!(table.ligature_attach(1).is_none());
+    }
+
+    #[test]
+    fn mark_to_mark() {
+        // two mark classes; the mark2 glyph only has an anchor for class 1
+        let mark1_array = table(
+            |offsets| u16_array(&[2, 0, offsets[0], 1, offsets[1]]),
+            &[anchor(0, 500), anchor(10, 510)],
+        );
+        let mark2_array = table(
+            |offsets| u16_array(&[1, 0, offsets[0]]),
+            &[anchor(-10, 800)],
+        );
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], offsets[1], 2, offsets[2], offsets[3]]),
+            &[
+                coverage(&[40, 41]),
+                coverage(&[50]),
+                mark1_array,
+                mark2_array,
+            ],
+        );
+        let data = layout_table(&[], &[lookup(6, 0, &[subtable])]);
+        let table = match first_subtables(&data).pop() {
+            Some(PositioningSubtable::MarkToMark(table)) => table,
+            _ => panic!("expected mark to mark subtable"),
+        };
+        assert_eq!(table.mark1_coverage().unwrap().get(41), Some(1));
+        assert_eq!(table.mark2_coverage().unwrap().get(50), Some(0));
+        let (class, mark_anchor) = table.mark1_array().unwrap().mark_anchor(1).unwrap();
+        assert_eq!(class, 1);
+        assert_eq!(coords(&mark_anchor), (10, 510));
+        let mark2_array = table.mark2_array().unwrap();
+        assert!(mark2_array.mark2_anchor(0, 0).is_none());
+        assert_eq!(coords(&mark2_array.mark2_anchor(0, 1).unwrap()), (-10, 800));
+        assert!(mark2_array.mark2_anchor(0, 2).is_none());
+        assert!(mark2_array.mark2_anchor(1, 1).is_none());
+    }
+
+    #[test]
+    fn contextual() {
+        let context = table(
+            |offsets| u16_array(&[3, 1, 1, offsets[0], 0, 2]),
+            &[coverage(&[5])],
+        );
+        let chained = table(
+            |offsets| u16_array(&[3, 0, 1, offsets[0], 0, 1, 0, 2]),
+            &[coverage(&[6])],
+        );
+        let data = layout_table(&[], &[lookup(7, 0, &[context]), lookup(8, 0, &[chained])]);
+        let subtables = first_subtables(&data);
+        match &subtables[..] {
+            [PositioningSubtable::Contextual(SequenceContext::Format3(_)), PositioningSubtable::ChainContextual(ChainedSequenceContext::Format3(_))] =>
+            {
+                assert_eq!(subtables[0].coverage().unwrap().get(5), Some(0));
+                assert_eq!(subtables[1].coverage().unwrap().get(6), Some(0));
+            }
+            _ => panic!("expected contextual subtables"),
+        }
+    }
+
+    #[test]
+    fn extension() {
+        let single = table(
+            |offsets| {
+                let mut buf = u16_array(&[1, offsets[0], 0x0004]);
+                buf.push(-20i16);
+                buf
+            },
+            &[coverage(&[3])],
+        );
+        let mut extension = BeBuffer::new();
+        extension.extend([1u16, 1]);
+        extension.push(8u32);
+        extension.extend(single.iter().copied());
+        // an extension can't point to another extension
+        let mut nested = BeBuffer::new();
+        nested.extend([1u16, EXTENSION_LOOKUP_TYPE]);
+        nested.push(8u32);
+        nested.extend(extension.iter().copied());
+        let data = layout_table(
+            &[],
+            &[
+                lookup(EXTENSION_LOOKUP_TYPE, 0, &[extension.to_vec()]),
+                lookup(EXTENSION_LOOKUP_TYPE, 0, &[nested.to_vec()]),
+            ],
+        );
+        let gpos = Gpos::read(&data).unwrap();
+        let lookup = gpos.lookup(0).unwrap();
+        assert_eq!(lookup.lookup().lookup_type(), EXTENSION_LOOKUP_TYPE);
+        // the extension is resolved to the single subtable
+        assert_eq!(lookup.lookup_type(), 1);
+        match lookup.subtables().next() {
+            Some(PositioningSubtable::Single(table)) => {
+                assert_eq!(table.coverage().unwrap().get(3), Some(0));
+                assert_eq!(table.value_record(0).unwrap().x_advance, Some(-20));
+            }
+            _ => panic!("expected single subtable"),
+        }
+        assert_eq!(gpos.lookup(1).unwrap().subtables().count(), 0);
+    }
+}
@@ -0,0 +1,708 @@
+//! the [GSUB] table
+//!
+//! [GSUB]: https://docs.microsoft.com/en-us/typography/opentype/spec/gsub
+
+#[path = "../../generated/generated_gsub.rs"]
+mod generated;
+
+pub use generated::*;
+
+#[cfg(feature = "std")]
+mod closure;
+
+use crate::layout::{
+    ChainedSequenceContext, CoverageTable, FeatureList, FeatureVariations, Lookup, LookupList,
+    ScriptList, SequenceContext,
+};
+use font_types::{FontRead, OffsetHost, Tag};
+
+#[cfg(feature = "std")]
+use font_types::F2Dot14;
+
+/// 'GSUB'
+pub const TAG: Tag = Tag::new(b"GSUB");
+
+/// The lookup type of [`ExtensionSubstFormat1`] subtables.
+const EXTENSION_LOOKUP_TYPE: u16 = 7;
+
+impl<'a> Gsub<'a> {
+    pub fn script_list(&self) -> Option<ScriptList<'a>> {
+        self.resolve_offset(self.script_list_offset())
+    }
+
+    pub fn feature_list(&self) -> Option<FeatureList<'a>> {
+        self.resolve_offset(self.feature_list_offset())
+    }
+
+    pub fn lookup_list(&self) -> Option<LookupList<'a>> {
+        self.resolve_offset(self.lookup_list_offset())
+    }
+
+    pub fn feature_variations(&self) -> Option<FeatureVariations<'a>> {
+        self.feature_variations_offset()
+            .and_then(|off| self.resolve_offset(off))
+    }
+
+    /// Return the lookup at the given index in the lookup list.
+    pub fn lookup(&self, index: u16) -> Option<SubstitutionLookup<'a>> {
+        self.lookup_list()?.get(index).map(SubstitutionLookup)
+    }
+
+    /// The indices of the lookups enabled by a feature, for a given script
+    /// and language, in the order they should be applied.
+    ///
+    /// Missing scripts fall back to `DFLT`, and missing languages to the
+    /// script's default language system.
+    #[cfg(feature = "std")]
+    pub fn feature_lookups(&self, script: Tag, lang: Tag, feature: Tag) -> Vec<u16> {
+        crate::layout::feature_lookups(
+            self.script_list(),
+            self.feature_list(),
+            None,
+            script,
+            lang,
+            feature,
+        )
+    }
+
+    /// The indices of the lookups of the required feature, for a given
+    /// script and language, in the order they should be applied.
+    ///
+    /// These are always applied, along with the lookups of any enabled
+    /// features.
+    #[cfg(feature = "std")]
+    pub fn required_lookups(&self, script: Tag, lang: Tag) -> Vec<u16> {
+        crate::layout::required_lookups(self.script_list(), self.feature_list(), None, script, lang)
+    }
+
+    /// The indices of the lookups enabled by a feature at a location in a
+    /// variable font's design space.
+    ///
+    /// This is like [`feature_lookups`](Self::feature_lookups), except that
+    /// feature tables are replaced with the alternates from the first
+    /// matching [`FeatureVariations`] record, if any. `coords` are
+    /// normalized coordinates, in axis order.
+    #[cfg(feature = "std")]
+    pub fn feature_lookups_at(
+        &self,
+        script: Tag,
+        lang: Tag,
+        feature: Tag,
+        coords: &[F2Dot14],
+    ) -> Vec<u16> {
+        let substitution = self
+            .feature_variations()
+            .and_then(|variations| variations.matching_record(coords))
+            .and_then(|(_, substitution)| substitution);
+        crate::layout::feature_lookups(
+            self.script_list(),
+            self.feature_list(),
+            substitution.as_ref(),
+            script,
+            lang,
+            feature,
+        )
+    }
+}
+
+/// A lookup in the GSUB table.
+///
+/// This is a thin wrapper around [`Lookup`] that knows how to interpret
+/// its subtables.
+pub struct SubstitutionLookup<'a>(Lookup<'a>);
+
+/// A GSUB lookup subtable, of any lookup type.
+///
+/// Extension subtables (lookup type 7) are resolved to the subtable they
+/// point to, and never appear here.
+pub enum SubstitutionSubtable<'a> {
+    /// Lookup type 1
+    Single(SingleSubst<'a>),
+    /// Lookup type 2
+    Multiple(MultipleSubstFormat1<'a>),
+    /// Lookup type 3
+    Alternate(AlternateSubstFormat1<'a>),
+    /// Lookup type 4
+    Ligature(LigatureSubstFormat1<'a>),
+    /// Lookup type 5
+    Contextual(SequenceContext<'a>),
+    /// Lookup type 6
+    ChainContextual(ChainedSequenceContext<'a>),
+    /// Lookup type 8
+    ReverseChainContextual(ReverseChainSingleSubstFormat1<'a>),
+}
+
+impl<'a> SubstitutionLookup<'a> {
+    /// The underlying lookup table.
+    pub fn lookup(&self) -> &Lookup<'a> {
+        &self.0
+    }
+
+    /// The type of this lookup.
+    ///
+    /// For extension lookups, this is the type of the extension subtables.
+    pub fn lookup_type(&self) -> u16 {
+        let lookup_type = self.0.lookup_type();
+        if lookup_type != EXTENSION_LOOKUP_TYPE {
+            return lookup_type;
+        }
+        // all subtables of an extension lookup must have the same type
+        self.0
+            .subtable_data()
+            .next()
+            .and_then(ExtensionSubstFormat1::read)
+            .map(|ext| ext.extension_lookup_type())
+            .unwrap_or(lookup_type)
+    }
+
+    /// Iterate over this lookup's subtables.
+    ///
+    /// Subtables that cannot be read are skipped.
+    pub fn subtables(&self) -> impl Iterator<Item = SubstitutionSubtable<'a>> + '_ {
+        let lookup_type = self.0.lookup_type();
+        self.0
+            .subtable_data()
+            .filter_map(move |data| SubstitutionSubtable::read(lookup_type, data))
+    }
+}
+
+impl<'a> SubstitutionSubtable<'a> {
+    /// Read a subtable of the given lookup type.
+    ///
+    /// If `lookup_type` is 7 (extension) the extension subtable is resolved.
+    pub fn read(lookup_type: u16, data: &'a [u8]) -> Option<Self> {
+        match lookup_type {
+            1 => SingleSubst::read(data).map(Self::Single),
+            2 => MultipleSubstFormat1::read(data).map(Self::Multiple),
+            3 => AlternateSubstFormat1::read(data).map(Self::Alternate),
+            4 => LigatureSubstFormat1::read(data).map(Self::Ligature),
+            5 => SequenceContext::read(data).map(Self::Contextual),
+            6 => ChainedSequenceContext::read(data).map(Self::ChainContextual),
+            EXTENSION_LOOKUP_TYPE => ExtensionSubstFormat1::read(data)?.subtable(),
+            8 => ReverseChainSingleSubstFormat1::read(data).map(Self::ReverseChainContextual),
+            _ => None,
+        }
+    }
+
+    /// The coverage table for the first glyph matched by this subtable.
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            Self::Single(table) => table.coverage(),
+            Self::Multiple(table) => table.coverage(),
+            Self::Alternate(table) => table.coverage(),
+            Self::Ligature(table) => table.coverage(),
+            Self::Contextual(table) => table.coverage(),
+            Self::ChainContextual(table) => table.coverage(),
+            Self::ReverseChainContextual(table) => table.coverage(),
+        }
+    }
+}
+
+impl<'a> SingleSubst<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        match self {
+            SingleSubst::Format1(table) => table.resolve_offset(table.coverage_offset()),
+            SingleSubst::Format2(table) => table.resolve_offset(table.coverage_offset()),
+        }
+    }
+}
+
+impl<'a> MultipleSubstFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The replacement sequence for the glyph with the given coverage index.
+    pub fn sequence(&self, coverage_index: u16) -> Option<Sequence<'a>> {
+        self.sequence_offsets()
+            .get(coverage_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> AlternateSubstFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The alternates for the glyph with the given coverage index.
+    pub fn alternate_set(&self, coverage_index: u16) -> Option<AlternateSet<'a>> {
+        self.alternate_set_offsets()
+            .get(coverage_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> LigatureSubstFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The ligatures beginning with the glyph with the given coverage index.
+    pub fn ligature_set(&self, coverage_index: u16) -> Option<LigatureSet<'a>> {
+        self.ligature_set_offsets()
+            .get(coverage_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> LigatureSet<'a> {
+    /// Iterate over the ligatures in this set, in order of preference.
+    pub fn ligatures(&self) -> impl Iterator<Item = Ligature<'a>> + '_ {
+        self.ligature_offsets()
+            .iter()
+            .filter_map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+impl<'a> ExtensionSubstFormat1<'a> {
+    /// Resolve the subtable this extension points to.
+    ///
+    /// Returns `None` if the subtable is itself an extension.
+    pub fn subtable(&self) -> Option<SubstitutionSubtable<'a>> {
+        let lookup_type = self.extension_lookup_type();
+        if lookup_type == EXTENSION_LOOKUP_TYPE {
+            return None;
+        }
+        let data = self.bytes_at_offset(self.extension_offset());
+        SubstitutionSubtable::read(lookup_type, data)
+    }
+}
+
+impl<'a> ReverseChainSingleSubstFormat1<'a> {
+    pub fn coverage(&self) -> Option<CoverageTable<'a>> {
+        self.resolve_offset(self.coverage_offset())
+    }
+
+    /// The coverage tables for the backtrack sequence, in glyph order.
+    pub fn backtrack_coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.backtrack_coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+
+    /// The coverage tables for the lookahead sequence, in glyph order.
+    pub fn lookahead_coverages(&self) -> impl Iterator<Item = Option<CoverageTable<'a>>> + '_ {
+        self.lookahead_coverage_offsets()
+            .iter()
+            .map(|off| self.resolve_offset(off.get()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, BigEndian};
+
+    use super::*;
+    use crate::layout::test_helpers::{
+        class_def, coverage, layout_table, lookup, table, u16_array,
+    };
+    use crate::layout::SequenceLookupRecord;
+
+    /// Read the first subtable of each lookup in a GSUB table.
+    fn first_subtables(data: &[u8]) -> Vec<SubstitutionSubtable<'_>> {
+        let gsub = Gsub::read(data).unwrap();
+        let count = gsub.lookup_list().unwrap().lookup_count();
+        (0..count)
+            .map(|idx| gsub.lookup(idx).unwrap().subtables().next().unwrap())
+            .collect()
+    }
+
+    fn glyphs(ids: &[BigEndian<u16>]) -> Vec<u16> {
+        ids.iter().map(|id| id.get()).collect()
+    }
+
+    fn records(records: &[SequenceLookupRecord]) -> Vec<(u16, u16)> {
+        records
+            .iter()
+            .map(|record| (record.sequence_index(), record.lookup_list_index()))
+            .collect()
+    }
+
+    #[test]
+    fn single() {
+        let format1 = table(
+            |offsets| {
+                let mut buf = u16_array(&[1, offsets[0]]);
+                buf.push(-2i16);
+                buf
+            },
+            &[coverage(&[3, 4])],
+        );
+        let format2 = table(
+            |offsets| u16_array(&[2, offsets[0], 2, 20, 21]),
+            &[coverage(&[5, 6])],
+        );
+        let data = layout_table(&[], &[lookup(1, 0, &[format1, format2])]);
+        let gsub = Gsub::read(&data).unwrap();
+        let lookup = gsub.lookup(0).unwrap();
+        assert_eq!(lookup.lookup_type(), 1);
+        let subtables = lookup.subtables().collect::<Vec<_>>();
+        match &subtables[..] {
+            [SubstitutionSubtable::Single(SingleSubst::Format1(first)), SubstitutionSubtable::Single(SingleSubst::Format2(second))] =>
+            {
+                assert_eq!(first.delta_glyph_id(), -2);
+                assert_eq!(subtables[0].coverage().unwrap().get(4), Some(1));
+                assert_eq!(glyphs(second.substitute_glyph_ids()), [20, 21]);
+                assert_eq!(subtables[1].coverage().unwrap().get(6), Some(1));
+            }
+            _ => panic!("expected two single subtables"),
+        }
+    }
+
+    #[test]
+    fn multiple_and_alternate() {
+        let multiple = table(
+            |offsets| u16_array(&[1, offsets[0], 2, offsets[1], offsets[2]]),
+            &[
+                coverage(&[5, 6]),
+                u16_array(&[2, 8, 9]).to_vec(),
+                u16_array(&[0]).to_vec(),
+            ],
+        );
+        let alternate = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[5]), u16_array(&[3, 10, 11, 12]).to_vec()],
+        );
+        let data = layout_table(
+            &[],
+            &[lookup(2, 0, &[multiple]), lookup(3, 0, &[alternate])],
+        );
+        match &first_subtables(&data)[..] {
+            [SubstitutionSubtable::Multiple(multiple), SubstitutionSubtable::Alternate(alternate)] =>
+            {
+                let sequence = multiple.sequence(0).unwrap();
+                assert_eq!(glyphs(sequence.substitute_glyph_ids()), [8, 9]);
+                // deleting a glyph
+                let sequence = multiple.sequence(1).unwrap();
+                assert!(sequence.substitute_glyph_ids().is_empty());
+                assert!(multiple.sequence(2).is_none());
+                let alternates = alternate.alternate_set(0).unwrap();
+                assert_eq!(glyphs(alternates.alternate_glyph_ids()), [10, 11, 12]);
+            }
+            _ => panic!("expected multiple and alternate subtables"),
+        }
+    }
+
+    #[test]
+    fn ligature() {
+        let ligature_set = table(
+            |offsets| u16_array(&[2, offsets[0], offsets[1]]),
+            &[
+                u16_array(&[20, 3, 6, 7]).to_vec(),
+                u16_array(&[21, 2, 6]).to_vec(),
+            ],
+        );
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[5]), ligature_set],
+        );
+        let data = layout_table(&[], &[lookup(4, 0, &[subtable])]);
+        let table = match first_subtables(&data).pop() {
+            Some(SubstitutionSubtable::Ligature(table)) => table,
+            _ => panic!("expected ligature subtable"),
+        };
+        let ligatures = table
+            .ligature_set(0)
+            .unwrap()
+            .ligatures()
+            .map(|lig| (lig.ligature_glyph(), glyphs(lig.component_glyph_ids())))
+            .collect::<Vec<_>>();
+        assert_eq!(ligatures, [(20, vec![6, 7]), (21, vec![6])]);
+    }
+
+    #[test]
+    fn contextual() {
+        // glyph 5 followed by 6
+        let rule = u16_array(&[2, 1, 6, 0, 3]).to_vec();
+        let rule_set = table(|offsets| u16_array(&[1, offsets[0]]), &[rule]);
+        let format1 = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[5]), rule_set],
+        );
+        // class 1 followed by class 2
+        let rule = u16_array(&[2, 1, 2, 1, 4]).to_vec();
+        let rule_set = table(|offsets| u16_array(&[1, offsets[0]]), &[rule]);
+        let format2 = table(
+            |offsets| u16_array(&[2, offsets[0], offsets[1], 2, 0, offsets[2]]),
+            &[coverage(&[5]), class_def(&[(5, 1), (6, 2)]), rule_set],
+        );
+        let format3 = table(
+            |offsets| u16_array(&[3, 2, 1, offsets[0], offsets[1], 1, 5]),
+            &[coverage(&[5]), coverage(&[6, 7])],
+        );
+        let data = layout_table(
+            &[],
+            &[
+                lookup(5, 0, &[format1]),
+                lookup(5, 0, &[format2]),
+                lookup(5, 0, &[format3]),
+            ],
+        );
+        let subtables = first_subtables(&data);
+        for subtable in &subtables {
+            assert_eq!(subtable.coverage().unwrap().get(5), Some(0));
+        }
+        match &subtables[..] {
+            [SubstitutionSubtable::Contextual(SequenceContext::Format1(format1)), SubstitutionSubtable::Contextual(SequenceContext::Format2(format2)), SubstitutionSubtable::Contextual(SequenceContext::Format3(format3))] =>
+            {
+                let rule = format1.seq_rule_set(0).unwrap().seq_rules().next().unwrap();
+                assert_eq!(glyphs(rule.input_sequence()), [6]);
+                assert_eq!(records(rule.seq_lookup_records()), [(0, 3)]);
+
+                let class_def = format2.class_def().unwrap();
+                assert_eq!(class_def.get(6), 2);
+                assert!(format2.class_seq_rule_set(0).is_none());
+                let rule = format2
+                    .class_seq_rule_set(1)
+                    .unwrap()
+                    .class_seq_rules()
+                    .next()
+                    .unwrap();
+                assert_eq!(glyphs(rule.input_sequence()), [2]);
+                assert_eq!(records(rule.seq_lookup_records()), [(1, 4)]);
+
+                let coverages = format3.coverages().collect::<Vec<_>>();
+                assert_eq!(coverages.len(), 2);
+                assert_eq!(coverages[1].as_ref().unwrap().get(7), Some(1));
+                assert_eq!(records(format3.seq_lookup_records()), [(1, 5)]);
+            }
+            _ => panic!("expected contextual subtables"),
+        }
+    }
+
+    #[test]
+    fn chained_contextual() {
+        // glyph 5 followed by 6, after 4 and before 7
+        let rule = u16_array(&[1, 4, 2, 6, 1, 7, 1, 0, 3]).to_vec();
+        let rule_set = table(|offsets| u16_array(&[1, offsets[0]]), &[rule]);
+        let format1 = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[5]), rule_set],
+        );
+        let rule = u16_array(&[1, 1, 2, 2, 1, 3, 1, 0, 2]).to_vec();
+        let rule_set = table(|offsets| u16_array(&[1, offsets[0]]), &[rule]);
+        let format2 = table(
+            |offsets| {
+                let mut buf = u16_array(&[2]);
+                buf.extend(offsets[..4].iter().copied());
+                buf.extend([2, 0, offsets[4]]);
+                buf
+            },
+            &[
+                coverage(&[5]),
+                class_def(&[(4, 1)]),
+                class_def(&[(5, 1), (6, 2)]),
+                class_def(&[(7, 3)]),
+                rule_set,
+            ],
+        );
+        let format3 = table(
+            |offsets| u16_array(&[3, 1, offsets[0], 1, offsets[1], 1, offsets[2], 1, 0, 1]),
+            &[coverage(&[4]), coverage(&[5]), coverage(&[7, 8])],
+        );
+        let data = layout_table(
+            &[],
+            &[
+                lookup(6, 0, &[format1]),
+                lookup(6, 0, &[format2]),
+                lookup(6, 0, &[format3]),
+            ],
+        );
+        let subtables = first_subtables(&data);
+        for subtable in &subtables {
+            assert_eq!(subtable.coverage().unwrap().get(5), Some(0));
+        }
+        match &subtables[..] {
+            [SubstitutionSubtable::ChainContextual(ChainedSequenceContext::Format1(format1)), SubstitutionSubtable::ChainContextual(ChainedSequenceContext::Format2(format2)), SubstitutionSubtable::ChainContextual(ChainedSequenceContext::Format3(format3))] =>
+            {
+                let rule = format1
+                    .chained_seq_rule_set(0)
+                    .unwrap()
+                    .chained_seq_rules()
+                    .next()
+                    .unwrap();
+                assert_eq!(glyphs(rule.backtrack_sequence()), [4]);
+                assert_eq!(glyphs(rule.input_sequence()), [6]);
+                assert_eq!(glyphs(rule.lookahead_sequence()), [7]);
+                assert_eq!(records(rule.seq_lookup_records()), [(0, 3)]);
+
+                assert_eq!(format2.backtrack_class_def().unwrap().get(4), 1);
+                assert_eq!(format2.input_class_def().unwrap().get(6), 2);
+                assert_eq!(format2.lookahead_class_def().unwrap().get(7), 3);
+                let rule = format2
+                    .chained_class_seq_rule_set(1)
+                    .unwrap()
+                    .chained_class_seq_rules()
+                    .next()
+                    .unwrap();
+                assert_eq!(glyphs(rule.backtrack_sequence()), [1]);
+                assert_eq!(glyphs(rule.input_sequence()), [2]);
+                assert_eq!(glyphs(rule.lookahead_sequence()), [3]);
+                assert_eq!(records(rule.seq_lookup_records()), [(0, 2)]);
+
+                let backtrack = format3.backtrack_coverages().collect::<Vec<_>>();
+                let lookahead = format3.lookahead_coverages().collect::<Vec<_>>();
+                assert_eq!(backtrack[0].as_ref().unwrap().get(4), Some(0));
+                assert_eq!(format3.input_coverages().count(), 1);
+                assert_eq!(lookahead[0].as_ref().unwrap().get(8), Some(1));
+                assert_eq!(records(format3.seq_lookup_records()), [(0, 1)]);
+            }
+            _ => panic!("expected chained contextual subtables"),
+        }
+    }
+
+    #[test]
+    fn extension() {
+        let extension = |lookup_type: u16, subtable: Vec<u8>| {
+            table(
+                |offsets| {
+                    let mut buf = u16_array(&[1, lookup_type]);
+                    buf.push(offsets[0] as u32);
+                    buf
+                },
+                &[subtable],
+            )
+        };
+        let single = table(|offsets| u16_array(&[1, offsets[0], 3]), &[coverage(&[3])]);
+        // extensions can't point to other extensions
+        let nested = extension(1, single.clone());
+        let data = layout_table(
+            &[],
+            &[lookup(
+                EXTENSION_LOOKUP_TYPE,
+                0,
+                &[extension(1, single), extension(7, nested)],
+            )],
+        );
+        let gsub = Gsub::read(&data).unwrap();
+        let lookup = gsub.lookup(0).unwrap();
+        assert_eq!(lookup.lookup().lookup_type(), EXTENSION_LOOKUP_TYPE);
+        assert_eq!(lookup.lookup_type(), 1);
+        let subtables = lookup.subtables().collect::<Vec<_>>();
+        match &subtables[..] {
+            [SubstitutionSubtable::Single(SingleSubst::Format1(table))] => {
+                assert_eq!(table.delta_glyph_id(), 3);
+                assert_eq!(subtables[0].coverage().unwrap().get(3), Some(0));
+            }
+            _ => panic!("expected a single extension subtable"),
+        }
+    }
+
+    #[test]
+    fn reverse_chain_single() {
+        let subtable = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1], 1, offsets[2], 2, 30, 31]),
+            &[coverage(&[5, 6]), coverage(&[2]), coverage(&[3, 4])],
+        );
+        let data = layout_table(&[], &[lookup(8, 0, &[subtable])]);
+        let table = match first_subtables(&data).pop() {
+            Some(SubstitutionSubtable::ReverseChainContextual(table)) => table,
+            _ => panic!("expected reverse chain subtable"),
+        };
+        assert_eq!(table.coverage().unwrap().get(6), Some(1));
+        let backtrack = table.backtrack_coverages().collect::<Vec<_>>();
+        assert_eq!(backtrack.len(), 1);
+        assert_eq!(backtrack[0].as_ref().unwrap().get(2), Some(0));
+        let lookahead = table.lookahead_coverages().collect::<Vec<_>>();
+        assert_eq!(lookahead[0].as_ref().unwrap().get(4), Some(1));
+        assert_eq!(glyphs(table.substitute_glyph_ids()), [30, 31]);
+    }
+
+    #[test]
+    fn extension_ligature() {
+        let mut buf = BeBuffer::new();
+        // header: version 1.0, null script & feature lists
+        buf.extend([1u16, 0, 0, 0, 10]);
+        // lookup list, with one lookup
+        buf.extend([1u16, 4]);
+        // lookup type 7, flags, one subtable
+        buf.extend([7u16, 0, 1, 8]);
+        // extension subtable, pointing to a ligature subtable
+        buf.extend([1u16, 4]);
+        buf.push(8u32);
+        // ligature subtable, with coverage & one ligature set
+        buf.extend([1u16, 8, 1, 14]);
+        buf.extend([1u16, 1, 5]);
+        buf.extend([1u16, 4]);
+        buf.extend([20u16, 3, 6, 7]);
+
+        let gsub = Gsub::read(&buf).unwrap();
+        assert!(gsub.feature_variations().is_none());
+        let lookup = gsub.lookup(0).unwrap();
+        assert_eq!(lookup.lookup().lookup_type(), 7);
+        assert_eq!(lookup.lookup_type(), 4);
+        let subtable = match lookup.subtables().next() {
+            Some(SubstitutionSubtable::Ligature(table)) => table,
+            _ => panic!("expected ligature subtable"),
+        };
+        let coverage_idx = subtable.coverage().unwrap().get(5).unwrap();
+        let ligature = subtable
+            .ligature_set(coverage_idx)
+            .unwrap()
+            .ligatures()
+            .next()
+            .unwrap();
+        assert_eq!(ligature.ligature_glyph(), 20);
+        assert_eq!(ligature.component_glyph_ids().len(), 2);
+        assert_eq!(ligature.component_glyph_ids()[1].get(), 7);
+    }
+
+    #[test]
+    fn feature_lookups() {
+        let mut buf = BeBuffer::new();
+        // header: version 1.0, script list, feature list, null lookup list
+        buf.extend([1u16, 0, 10, 62, 0]);
+        // script list: DFLT & latn
+        buf.push(2u16);
+        buf.push(Tag::new(b"DFLT"));
+        buf.push(14u16);
+        buf.push(Tag::new(b"latn"));
+        buf.push(26u16);
+        // DFLT script: default lang sys only, enabling feature 0
+        buf.extend([4u16, 0]);
+        buf.extend([0u16, 0xFFFF, 1, 0]);
+        // latn script: default lang sys enables feature 1; TRK additionally
+        // requires feature 2
+        buf.extend([10u16, 1]);
+        buf.push(Tag::new(b"TRK"));
+        buf.push(18u16);
+        buf.extend([0u16, 0xFFFF, 1, 1]);
+        buf.extend([0u16, 2, 1, 1]);
+        // feature list: two 'liga' features and a 'locl'
+        buf.push(3u16);
+        buf.push(Tag::new(b"liga"));
+        buf.push(20u16);
+        buf.push(Tag::new(b"liga"));
+        buf.push(26u16);
+        buf.push(Tag::new(b"locl"));
+        buf.push(34u16);
+        buf.extend([0u16, 1, 4]);
+        buf.extend([0u16, 2, 3, 1]);
+        buf.extend([0u16, 1, 0]);
+
+        let gsub = Gsub::read(&buf).unwrap();
+        let lookups = |script: &[u8], lang: &[u8], feature: &[u8]| {
+            gsub.feature_lookups(Tag::new(script), Tag::new(lang), Tag::new(feature))
+        };
+        assert_eq!(lookups(b"latn", b"TRK", b"liga"), [1, 3]);
+        assert_eq!(lookups(b"latn", b"TRK", b"locl"), [0]);
+        assert_eq!(lookups(b"latn", b"ENG", b"liga"), [1, 3]);
+        assert!(lookups(b"latn", b"ENG", b"locl").is_empty());
+        assert_eq!(lookups(b"cyrl", b"dflt", b"liga"), [4]);
+
+        // the required feature applies, whatever the queried feature
+        let required =
+            |script: &[u8], lang: &[u8]| gsub.required_lookups(Tag::new(script), Tag::new(lang));
+        assert_eq!(required(b"latn", b"TRK"), [0]);
+        assert!(required(b"latn", b"ENG").is_empty());
+
+        let lang_sys = gsub
+            .script_list()
+            .unwrap()
+            .lang_sys(Tag::new(b"latn"), Tag::new(b"TRK"))
+            .unwrap();
+        assert_eq!(lang_sys.required_feature(), Some(2));
+        assert_eq!(lang_sys.all_feature_indices().collect::<Vec<_>>(), [2, 1]);
+    }
+}
@@ -0,0 +1,493 @@
+//! Computing the closure of a set of glyphs under GSUB substitutions.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use font_types::{BigEndian, Tag};
+
+use super::{Gsub, SingleSubst, SubstitutionLookup, SubstitutionSubtable};
+use crate::layout::{
+    ChainedSequenceContext, ClassDef, CoverageTable, LookupList, SequenceContext,
+    SequenceLookupRecord, MAX_NESTING_DEPTH,
+};
+
+impl<'a> Gsub<'a> {
+    /// Compute the set of glyphs reachable from `glyphs` through the
+    /// substitutions of the given features, and of the required feature,
+    /// for a script and language.
+    ///
+    /// The result always includes the input glyphs. Lookups are applied
+    /// repeatedly until no new glyphs are found.
+    ///
+    /// Contextual lookups are resolved conservatively: if every glyph in a
+    /// rule's context may be present, the rule's nested lookups are applied
+    /// to the whole set, regardless of sequence position.
+    pub fn closure_glyphs(
+        &self,
+        glyphs: &BTreeSet<u16>,
+        script: Tag,
+        lang: Tag,
+        features: &[Tag],
+    ) -> BTreeSet<u16> {
+        let lookups = features
+            .iter()
+            .flat_map(|feature| self.feature_lookups(script, lang, *feature))
+            .chain(self.required_lookups(script, lang))
+            .collect::<BTreeSet<_>>();
+        match self.lookup_list() {
+            Some(lookup_list) => closure_lookups(&lookup_list, &lookups, glyphs),
+            None => glyphs.clone(),
+        }
+    }
+}
+
+/// Apply the lookups at `indices` to `glyphs`, until a fixpoint is reached.
+fn closure_lookups(
+    lookup_list: &LookupList,
+    indices: &BTreeSet<u16>,
+    glyphs: &BTreeSet<u16>,
+) -> BTreeSet<u16> {
+    let mut result = glyphs.clone();
+    let mut applied = BTreeMap::new();
+    loop {
+        let len = result.len();
+        for idx in indices {
+            closure_lookup(lookup_list, *idx, &mut result, &mut applied, 0);
+        }
+        // glyphs are only ever added, so this terminates
+        if result.len() == len {
+            return result;
+        }
+    }
+}
+
+/// Apply the lookup at `index`, and the lookups nested in it, to `glyphs`.
+///
+/// `applied` records the size of the glyph set when each lookup was last
+/// applied; a lookup can only add glyphs if the set has grown since.
+fn closure_lookup(
+    lookup_list: &LookupList,
+    index: u16,
+    glyphs: &mut BTreeSet<u16>,
+    applied: &mut BTreeMap<u16, usize>,
+    depth: usize,
+) {
+    if applied.insert(index, glyphs.len()) == Some(glyphs.len()) {
+        return;
+    }
+    let lookup = match lookup_list.get(index) {
+        Some(lookup) => SubstitutionLookup(lookup),
+        None => return,
+    };
+    for subtable in lookup.subtables() {
+        let mut nested = BTreeSet::new();
+        let added = closure_subtable(&subtable, glyphs, &mut nested).unwrap_or_default();
+        glyphs.extend(added);
+        if depth >= MAX_NESTING_DEPTH {
+            continue;
+        }
+        for idx in nested {
+            closure_lookup(lookup_list, idx, glyphs, applied, depth + 1);
+        }
+    }
+}
+
+/// Return the glyphs this subtable can produce from `glyphs`.
+///
+/// The nested lookups of any contextual rules that may match are added to
+/// `nested`.
+fn closure_subtable(
+    subtable: &SubstitutionSubtable,
+    glyphs: &BTreeSet<u16>,
+    nested: &mut BTreeSet<u16>,
+) -> Option<Vec<u16>> {
+    let coverage = subtable.coverage()?;
+    let mut added = Vec::new();
+    match subtable {
+        SubstitutionSubtable::Single(table) => {
+            for (coverage_idx, glyph_id) in covered(&coverage, glyphs) {
+                match table {
+                    SingleSubst::Format1(table) => {
+                        added.push((glyph_id as i32 + table.delta_glyph_id() as i32) as u16)
+                    }
+                    SingleSubst::Format2(table) => added.extend(
+                        table
+                            .substitute_glyph_ids()
+                            .get(coverage_idx as usize)
+                            .map(|gid| gid.get()),
+                    ),
+                }
+            }
+        }
+        SubstitutionSubtable::Multiple(table) => {
+            for (coverage_idx, _) in covered(&coverage, glyphs) {
+                if let Some(sequence) = table.sequence(coverage_idx) {
+                    added.extend(sequence.substitute_glyph_ids().iter().map(|gid| gid.get()));
+                }
+            }
+        }
+        SubstitutionSubtable::Alternate(table) => {
+            for (coverage_idx, _) in covered(&coverage, glyphs) {
+                if let Some(alternates) = table.alternate_set(coverage_idx) {
+                    added.extend(alternates.alternate_glyph_ids().iter().map(|gid| gid.get()));
+                }
+            }
+        }
+        SubstitutionSubtable::Ligature(table) => {
+            for (coverage_idx, _) in covered(&coverage, glyphs) {
+                let ligature_set = match table.ligature_set(coverage_idx) {
+                    Some(set) => set,
+                    None => continue,
+                };
+                for ligature in ligature_set.ligatures() {
+                    let components = ligature.component_glyph_ids();
+                    if components.iter().all(|gid| glyphs.contains(&gid.get())) {
+                        added.push(ligature.ligature_glyph());
+                    }
+                }
+            }
+        }
+        SubstitutionSubtable::Contextual(table) => {
+            sequence_context_records(table, &coverage, glyphs, nested)
+        }
+        SubstitutionSubtable::ChainContextual(table) => {
+            chained_context_records(table, &coverage, glyphs, nested)
+        }
+        SubstitutionSubtable::ReverseChainContextual(table) => {
+            let context_matches = table
+                .backtrack_coverages()
+                .chain(table.lookahead_coverages())
+                .all(|coverage| intersects(&coverage, glyphs));
+            if context_matches {
+                let substitutes = table.substitute_glyph_ids();
+                for (coverage_idx, _) in covered(&coverage, glyphs) {
+                    added.extend(substitutes.get(coverage_idx as usize).map(|gid| gid.get()));
+                }
+            }
+        }
+    }
+    Some(added)
+}
+
+fn sequence_context_records(
+    table: &SequenceContext,
+    coverage: &CoverageTable,
+    glyphs: &BTreeSet<u16>,
+    nested: &mut BTreeSet<u16>,
+) {
+    match table {
+        SequenceContext::Format1(table) => {
+            for (coverage_idx, _) in covered(coverage, glyphs) {
+                let rule_set = match table.seq_rule_set(coverage_idx) {
+                    Some(set) => set,
+                    None => continue,
+                };
+                for rule in rule_set.seq_rules() {
+                    if rule
+                        .input_sequence()
+                        .iter()
+                        .all(|gid| glyphs.contains(&gid.get()))
+                    {
+                        nested.extend(lookup_indices(rule.seq_lookup_records()));
+                    }
+                }
+            }
+        }
+        SequenceContext::Format2(table) => {
+            let class_def = table.class_def();
+            let classes = classes_of(&class_def, glyphs);
+            for class in first_classes(&class_def, coverage, glyphs) {
+                let rule_set = match table.class_seq_rule_set(class) {
+                    Some(set) => set,
+                    None => continue,
+                };
+                for rule in rule_set.class_seq_rules() {
+                    if rule
+                        .input_sequence()
+                        .iter()
+                        .all(|class| classes.contains(&class.get()))
+                    {
+                        nested.extend(lookup_indices(rule.seq_lookup_records()));
+                    }
+                }
+            }
+        }
+        SequenceContext::Format3(table) => {
+            if table
+                .coverages()
+                .all(|coverage| intersects(&coverage, glyphs))
+            {
+                nested.extend(lookup_indices(table.seq_lookup_records()));
+            }
+        }
+    }
+}
+
+fn chained_context_records(
+    table: &ChainedSequenceContext,
+    coverage: &CoverageTable,
+    glyphs: &BTreeSet<u16>,
+    nested: &mut BTreeSet<u16>,
+) {
+    match table {
+        ChainedSequenceContext::Format1(table) => {
+            for (coverage_idx, _) in covered(coverage, glyphs) {
+                let rule_set = match table.chained_seq_rule_set(coverage_idx) {
+                    Some(set) => set,
+                    None => continue,
+                };
+                for rule in rule_set.chained_seq_rules() {
+                    if rule
+                        .backtrack_sequence()
+                        .iter()
+                        .chain(rule.input_sequence())
+                        .chain(rule.lookahead_sequence())
+                        .all(|gid| glyphs.contains(&gid.get()))
+                    {
+                        nested.extend(lookup_indices(rule.seq_lookup_records()));
+                    }
+                }
+            }
+        }
+        ChainedSequenceContext::Format2(table) => {
+            let backtrack_classes = classes_of(&table.backtrack_class_def(), glyphs);
+            let input_class_def = table.input_class_def();
+            let input_classes = classes_of(&input_class_def, glyphs);
+            let lookahead_classes = classes_of(&table.lookahead_class_def(), glyphs);
+            for class in first_classes(&input_class_def, coverage, glyphs) {
+                let rule_set = match table.chained_class_seq_rule_set(class) {
+                    Some(set) => set,
+                    None => continue,
+                };
+                for rule in rule_set.chained_class_seq_rules() {
+                    let has_all = |sequence: &[BigEndian<u16>], classes: &BTreeSet<u16>| {
+                        sequence.iter().all(|class| classes.contains(&class.get()))
+                    };
+                    if has_all(rule.backtrack_sequence(), &backtrack_classes)
+                        && has_all(rule.input_sequence(), &input_classes)
+                        && has_all(rule.lookahead_sequence(), &lookahead_classes)
+                    {
+                        nested.extend(lookup_indices(rule.seq_lookup_records()));
+                    }
+                }
+            }
+        }
+        ChainedSequenceContext::Format3(table) => {
+            if table
+                .backtrack_coverages()
+                .chain(table.input_coverages())
+                .chain(table.lookahead_coverages())
+                .all(|coverage| intersects(&coverage, glyphs))
+            {
+                nested.extend(lookup_indices(table.seq_lookup_records()));
+            }
+        }
+    }
+}
+
+/// The lookups applied by these records.
+fn lookup_indices(records: &[SequenceLookupRecord]) -> impl Iterator<Item = u16> + '_ {
+    records.iter().map(|record| record.lookup_list_index())
+}
+
+/// The (coverage index, glyph id) of each covered glyph that is in `glyphs`.
+fn covered<'b>(
+    coverage: &'b CoverageTable,
+    glyphs: &'b BTreeSet<u16>,
+) -> impl Iterator<Item = (u16, u16)> + 'b {
+    coverage
+        .iter()
+        .enumerate()
+        .filter(move |(_, glyph_id)| glyphs.contains(glyph_id))
+        .map(|(idx, glyph_id)| (idx as u16, glyph_id))
+}
+
+fn intersects(coverage: &Option<CoverageTable>, glyphs: &BTreeSet<u16>) -> bool {
+    coverage
+        .as_ref()
+        .map(|coverage| coverage.iter().any(|glyph_id| glyphs.contains(&glyph_id)))
+        .unwrap_or(false)
+}
+
+/// The classes of all glyphs in the set; a missing class def puts all
+/// glyphs in class 0.
+fn classes_of(class_def: &Option<ClassDef>, glyphs: &BTreeSet<u16>) -> BTreeSet<u16> {
+    glyphs
+        .iter()
+        .map(|glyph_id| {
+            class_def
+                .as_ref()
+                .map(|class_def| class_def.get(*glyph_id))
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// The classes of the covered glyphs in the set, which select rule sets.
+fn first_classes(
+    class_def: &Option<ClassDef>,
+    coverage: &CoverageTable,
+    glyphs: &BTreeSet<u16>,
+) -> BTreeSet<u16> {
+    let first = covered(coverage, glyphs)
+        .map(|(_, glyph_id)| glyph_id)
+        .collect();
+    classes_of(class_def, &first)
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+    use crate::layout::test_helpers::{
+        coverage, layout_table, layout_table_with_required, lookup, table, u16_array,
+    };
+
+    /// The closure of `glyphs` under the lookups at `indices`.
+    fn closure_of(data: &[u8], indices: &[u16], glyphs: &[u16]) -> Vec<u16> {
+        let lookup_list = Gsub::read(data).unwrap().lookup_list().unwrap();
+        let indices = indices.iter().copied().collect();
+        let glyphs = glyphs.iter().copied().collect();
+        closure_lookups(&lookup_list, &indices, &glyphs)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn closure() {
+        let mut buf = BeBuffer::new();
+        // header: version 1.0, null script & feature lists
+        buf.extend([1u16, 0, 0, 0, 10]);
+        // lookup list, with three lookups
+        buf.extend([3u16, 8, 34, 66]);
+        // lookup 0: single subst 1 -> 2, 3 -> 4
+        buf.extend([1u16, 0, 1, 8]);
+        buf.extend([2u16, 10, 2, 2, 4]);
+        buf.extend([1u16, 2, 1, 3]);
+        // lookup 1: ligature 2 + 5 -> 9
+        buf.extend([4u16, 0, 1, 8]);
+        buf.extend([1u16, 8, 1, 14]);
+        buf.extend([1u16, 1, 2]);
+        buf.extend([1u16, 4]);
+        buf.extend([9u16, 2, 5]);
+        // lookup 2: chained context, applying lookup 0 to glyph 3 after 9
+        buf.extend([6u16, 0, 1, 8]);
+        buf.extend([3u16, 1, 18, 1, 24, 0, 1, 0, 0]);
+        // coverages: [9] and [3]
+        buf.extend([1u16, 1, 9]);
+        buf.extend([1u16, 1, 3]);
+
+        let gsub = Gsub::read(&buf).unwrap();
+        let lookup_list = gsub.lookup_list().unwrap();
+        let closure = |lookups: &[u16], glyphs: &[u16]| {
+            let lookups = lookups.iter().copied().collect();
+            let glyphs = glyphs.iter().copied().collect();
+            closure_lookups(&lookup_list, &lookups, &glyphs)
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(closure(&[0], &[1, 3]), [1, 2, 3, 4]);
+        assert_eq!(closure(&[1], &[2, 5]), [2, 5, 9]);
+        assert_eq!(closure(&[0, 1], &[1, 5]), [1, 2, 5, 9]);
+        // the context glyph 9 is only reachable through lookups 0 & 1
+        assert_eq!(closure(&[2], &[1, 3, 5]), [1, 3, 5]);
+        assert_eq!(closure(&[0, 1, 2], &[1, 5]), [1, 2, 5, 9]);
+        assert_eq!(closure(&[1, 2], &[2, 3, 5]), [2, 3, 4, 5, 9]);
+    }
+
+    #[test]
+    fn nested_lookups() {
+        // too short for its subtable count, so it can't be read
+        let unreadable = u16_array(&[1, 0, 0xFFFF]).to_vec();
+        let single = |from: u16, to: u16| {
+            table(
+                |offsets| u16_array(&[2, offsets[0], 1, to]),
+                &[coverage(&[from])],
+            )
+        };
+        // 3 followed by 4 applies lookup 1
+        let rule = u16_array(&[2, 1, 4, 0, 1]).to_vec();
+        let rule_set = table(|offsets| u16_array(&[1, offsets[0]]), &[rule]);
+        let contextual = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[3]), rule_set],
+        );
+        // 5 after 6 applies lookups 1 and 4, each twice
+        let chained = table(
+            |offsets| {
+                u16_array(&[
+                    3, 1, offsets[0], 1, offsets[1], 0, 4, 0, 1, 0, 4, 0, 1, 0, 4,
+                ])
+            },
+            &[coverage(&[6]), coverage(&[5])],
+        );
+        // 2 applies this lookup again, and lookup 5
+        let recursive = table(
+            |offsets| u16_array(&[3, 1, 2, offsets[0], 0, 4, 0, 5]),
+            &[coverage(&[2])],
+        );
+        let data = layout_table(
+            &[],
+            &[
+                unreadable,
+                lookup(1, 0, &[single(1, 2)]),
+                lookup(5, 0, &[contextual]),
+                lookup(6, 0, &[chained]),
+                lookup(5, 0, &[recursive]),
+                lookup(1, 0, &[single(2, 7)]),
+            ],
+        );
+        assert!(Gsub::read(&data).unwrap().lookup(0).is_none());
+        assert_eq!(closure_of(&data, &[1], &[1]), [1, 2]);
+        assert_eq!(closure_of(&data, &[2], &[1, 3, 4]), [1, 2, 3, 4]);
+        assert_eq!(closure_of(&data, &[2], &[1, 3]), [1, 3]);
+        assert_eq!(closure_of(&data, &[3], &[1, 5, 6]), [1, 2, 5, 6, 7]);
+        assert_eq!(closure_of(&data, &[3], &[1, 5]), [1, 5]);
+    }
+
+    #[test]
+    fn ligature_closure() {
+        let ligature_set = table(
+            |offsets| u16_array(&[2, offsets[0], offsets[1]]),
+            &[
+                u16_array(&[10, 3, 2, 3]).to_vec(),
+                u16_array(&[11, 2, 4]).to_vec(),
+            ],
+        );
+        let ligature = table(
+            |offsets| u16_array(&[1, offsets[0], 1, offsets[1]]),
+            &[coverage(&[1]), ligature_set],
+        );
+        let data = layout_table(&[], &[lookup(4, 0, &[ligature])]);
+        assert_eq!(closure_of(&data, &[0], &[1, 2, 3]), [1, 2, 3, 10]);
+        assert_eq!(closure_of(&data, &[0], &[1, 2, 3, 4]), [1, 2, 3, 4, 10, 11]);
+        // every component is needed
+        assert_eq!(closure_of(&data, &[0], &[1, 2, 4]), [1, 2, 4, 11]);
+        assert_eq!(closure_of(&data, &[0], &[2, 3, 4]), [2, 3, 4]);
+    }
+
+    #[test]
+    fn required_feature_closure() {
+        let single = |from: u16, to: u16| {
+            table(
+                |offsets| u16_array(&[2, offsets[0], 1, to]),
+                &[coverage(&[from])],
+            )
+        };
+        let data = layout_table_with_required(
+            Some(0),
+            &[(Tag::new(b"ccmp"), &[0]), (Tag::new(b"liga"), &[1])],
+            &[lookup(1, 0, &[single(1, 2)]), lookup(1, 0, &[single(3, 4)])],
+        );
+        let gsub = Gsub::read(&data).unwrap();
+        let closure = |features: &[Tag]| {
+            let glyphs = [1, 3].into_iter().collect();
+            gsub.closure_glyphs(&glyphs, Tag::new(b"latn"), Tag::new(b"dflt"), features)
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        // the required feature applies even when it isn't requested
+        assert_eq!(closure(&[]), [1, 2, 3]);
+        assert_eq!(closure(&[Tag::new(b"liga")]), [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,774 @@
+//! The [gvar (Glyph Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/gvar) table
+
+#[path = "../../generated/generated_gvar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{BigEndian, F2Dot14, FontRead, OffsetHost, Tag};
+use zerocopy::LayoutVerified;
+
+use super::glyf::Cursor;
+#[cfg(feature = "std")]
+use super::glyf::{Anchor, Component, Glyph, GlyphPoint, Point};
+use crate::variations::axis_scalar;
+
+/// 'gvar'
+pub const TAG: Tag = Tag::new(b"gvar");
+
+/// Flag indicating that point numbers in a run are 16 bits.
+const POINTS_ARE_WORDS: u8 = 0x80;
+/// Mask for the number of point numbers in a run, minus one.
+const POINT_RUN_COUNT_MASK: u8 = 0x7F;
+/// Flag indicating that all the deltas in a run are zero, and not stored.
+const DELTAS_ARE_ZERO: u8 = 0x80;
+/// Flag indicating that the deltas in a run are 16 bits.
+const DELTAS_ARE_WORDS: u8 = 0x40;
+/// Mask for the number of deltas in a run, minus one.
+const DELTA_RUN_COUNT_MASK: u8 = 0x3F;
+
+impl<'a> Gvar<'a> {
+    /// The shared tuple at this index, with one peak coordinate for each axis.
+    pub fn shared_tuple(&self, index: u16) -> Option<Tuple<'a>> {
+        let axis_count = self.axis_count() as usize;
+        let start = index as usize * tuple_len(axis_count);
+        read_tuple(self.shared_tuples().get(start..)?, axis_count).map(|(tuple, _)| tuple)
+    }
+
+    fn shared_tuples(&self) -> &'a [u8] {
+        let len = self.shared_tuple_count() as usize * tuple_len(self.axis_count() as usize);
+        let bytes = self.bytes_at_offset(self.shared_tuples_offset());
+        bytes.get(..len).unwrap_or_default()
+    }
+
+    /// The variation data for this glyph, if it has any.
+    pub fn glyph_variation_data(&self, glyph_id: u16) -> Option<GlyphVariationData<'a>> {
+        let start = self.glyph_variation_data_offset(glyph_id as usize)?;
+        let end = self.glyph_variation_data_offset(glyph_id as usize + 1)?;
+        // glyphs without variations have no data
+        if start >= end {
+            return None;
+        }
+        self.bytes_at_offset(self.glyph_variation_data_array_offset())
+            .get(start..end)
+            .and_then(GlyphVariationData::read)
+    }
+
+    fn glyph_variation_data_offset(&self, index: usize) -> Option<usize> {
+        let offsets = self.glyph_variation_data_offsets();
+        if self.flags().contains(GvarFlags::LONG_OFFSETS) {
+            BigEndian::<u32>::read(offsets.get(index * 4..)?).map(|off| off.get() as usize)
+        } else {
+            // short offsets are stored divided by 2
+            BigEndian::<u16>::read(offsets.get(index * 2..)?).map(|off| off.get() as usize * 2)
+        }
+    }
+
+    /// Iterate over the tuple variations of a glyph.
+    ///
+    /// `point_count` is the number of points in the glyph (or components,
+    /// for a composite glyph) plus the four phantom points.
+    pub fn tuple_variations(&self, glyph_id: u16, point_count: u16) -> TupleVariationIter<'a> {
+        self.glyph_variation_data(glyph_id)
+            .and_then(|data| {
+                TupleVariationIter::new(&data, self.shared_tuples(), self.axis_count(), point_count)
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Gvar<'a> {
+    /// Compute the delta for each point of a glyph, at a location in design
+    /// space.
+    ///
+    /// `points` are the points of the glyph (or the component offsets, for
+    /// a composite glyph) followed by the four phantom points, and
+    /// `end_points` are the indices of the last point in each contour.
+    /// Deltas for points that a tuple variation does not reference are
+    /// inferred from the referenced points in the same contour.
+    pub fn glyph_deltas(
+        &self,
+        glyph_id: u16,
+        coords: &[F2Dot14],
+        points: &[Point],
+        end_points: &[u16],
+    ) -> Vec<(f32, f32)> {
+        let mut deltas = vec![(0.0, 0.0); points.len()];
+        let point_count = match u16::try_from(points.len()) {
+            Ok(count) => count,
+            Err(_) => return deltas,
+        };
+        let mut tuple_deltas = Vec::new();
+        let mut touched = Vec::new();
+        for tuple in self.tuple_variations(glyph_id, point_count) {
+            let scalar = tuple.scalar(coords);
+            if scalar == 0.0 {
+                continue;
+            }
+            let point_numbers = match tuple.point_numbers() {
+                Some(point_numbers) => point_numbers,
+                None => {
+                    for (delta, (x, y)) in deltas.iter_mut().zip(tuple.deltas()) {
+                        delta.0 += x as f32 * scalar;
+                        delta.1 += y as f32 * scalar;
+                    }
+                    continue;
+                }
+            };
+            tuple_deltas.clear();
+            tuple_deltas.resize(points.len(), (0.0, 0.0));
+            touched.clear();
+            touched.resize(points.len(), false);
+            for (idx, (x, y)) in point_numbers.iter().zip(tuple.deltas()) {
+                let idx = idx as usize;
+                if let Some(delta) = tuple_deltas.get_mut(idx) {
+                    *delta = (x as f32, y as f32);
+                    touched[idx] = true;
+                }
+            }
+            infer_deltas(&mut tuple_deltas, &touched, points, end_points);
+            for (delta, tuple_delta) in deltas.iter_mut().zip(&tuple_deltas) {
+                delta.0 += tuple_delta.0 * scalar;
+                delta.1 += tuple_delta.1 * scalar;
+            }
+        }
+        deltas
+    }
+
+    /// Apply variations to a glyph, at a location in design space.
+    ///
+    /// `glyph` is `None` for glyphs without an outline, which may still have
+    /// varied phantom points; see [`phantom_points`][super::glyf::phantom_points].
+    pub fn varied_glyph(
+        &self,
+        glyph_id: u16,
+        glyph: Option<&Glyph>,
+        phantom_points: [Point; 4],
+        coords: &[F2Dot14],
+    ) -> VariedGlyph {
+        let mut points = Vec::new();
+        let mut end_points = Vec::new();
+        let mut components = Vec::new();
+        match glyph {
+            Some(Glyph::Simple(glyph)) => {
+                points.extend(glyph.iter_points());
+                end_points.extend(glyph.end_pts_of_contours().iter().map(|end| end.get()));
+            }
+            Some(Glyph::Composite(glyph)) => components.extend(glyph.components()),
+            None => (),
+        }
+        let outline = points
+            .iter()
+            .map(GlyphPoint::point)
+            .chain(components.iter().map(|component| match component.anchor {
+                Anchor::Offset { x, y } => Point { x, y },
+                Anchor::Point { .. } => Point::default(),
+            }))
+            .chain(phantom_points)
+            .collect::<Vec<_>>();
+        let deltas = self.glyph_deltas(glyph_id, coords, &outline, &end_points);
+        let varied = outline
+            .iter()
+            .zip(deltas)
+            .map(|(point, (dx, dy))| Point {
+                x: (point.x as f32 + dx).round() as i16,
+                y: (point.y as f32 + dy).round() as i16,
+            })
+            .collect::<Vec<_>>();
+
+        let (varied_points, varied) = varied.split_at(points.len());
+        let (varied_offsets, varied_phantom_points) = varied.split_at(components.len());
+        for (point, varied) in points.iter_mut().zip(varied_points) {
+            *point = point.with_point(*varied);
+        }
+        for (component, varied) in components.iter_mut().zip(varied_offsets) {
+            if let Anchor::Offset { x, y } = &mut component.anchor {
+                *x = varied.x;
+                *y = varied.y;
+            }
+        }
+        VariedGlyph {
+            points,
+            components,
+            phantom_points: varied_phantom_points.try_into().unwrap_or(phantom_points),
+        }
+    }
+}
+
+/// The size in bytes of a tuple record.
+fn tuple_len(axis_count: usize) -> usize {
+    axis_count * std::mem::size_of::<BigEndian<F2Dot14>>()
+}
+
+/// Read a tuple record, returning it and the bytes that follow.
+fn read_tuple(bytes: &[u8], axis_count: usize) -> Option<(Tuple<'_>, &[u8])> {
+    LayoutVerified::new_slice_unaligned_from_prefix(bytes, axis_count)
+        .map(|(tuple, rest)| (tuple.into_slice(), rest))
+}
+
+/// An iterator over the [`TupleVariation`]s of a glyph.
+#[derive(Clone, Default)]
+pub struct TupleVariationIter<'a> {
+    headers: &'a [u8],
+    data: &'a [u8],
+    shared_points: Option<PackedPointNumbers<'a>>,
+    shared_tuples: &'a [u8],
+    axis_count: u16,
+    point_count: u16,
+    remaining: u16,
+}
+
+impl<'a> TupleVariationIter<'a> {
+    fn new(
+        glyph_data: &GlyphVariationData<'a>,
+        shared_tuples: &'a [u8],
+        axis_count: u16,
+        point_count: u16,
+    ) -> Option<Self> {
+        let count = glyph_data.tuple_variation_count();
+        let data = glyph_data.bytes_at_offset(glyph_data.data_offset());
+        let (shared_points, data) = if count.contains(TupleVariationCount::SHARED_POINT_NUMBERS) {
+            PackedPointNumbers::read(data)?
+        } else {
+            (None, data)
+        };
+        Some(TupleVariationIter {
+            // the headers follow the count and data offset
+            headers: glyph_data.bytes().get(4..)?,
+            data,
+            shared_points,
+            shared_tuples,
+            axis_count,
+            point_count,
+            remaining: (count & TupleVariationCount::COUNT_MASK).bits(),
+        })
+    }
+}
+
+impl<'a> Iterator for TupleVariationIter<'a> {
+    type Item = TupleVariation<'a>;
+
+    fn next(&mut self) -> Option<TupleVariation<'a>> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let axis_count = self.axis_count as usize;
+        let mut cursor = Cursor::new(self.headers);
+        let data_size = cursor.bump::<u16>()? as usize;
+        let tuple_index = cursor.bump::<TupleIndex>()?;
+        let mut headers = cursor.remaining();
+
+        let peak_tuple = if tuple_index.contains(TupleIndex::EMBEDDED_PEAK_TUPLE) {
+            let (tuple, rest) = read_tuple(headers, axis_count)?;
+            headers = rest;
+            tuple
+        } else {
+            let index = (tuple_index & TupleIndex::TUPLE_INDEX_MASK).bits() as usize;
+            let start = index * tuple_len(axis_count);
+            read_tuple(self.shared_tuples.get(start..)?, axis_count)?.0
+        };
+        let intermediate_region = if tuple_index.contains(TupleIndex::INTERMEDIATE_REGION) {
+            let (start, rest) = read_tuple(headers, axis_count)?;
+            let (end, rest) = read_tuple(rest, axis_count)?;
+            headers = rest;
+            Some((start, end))
+        } else {
+            None
+        };
+        self.headers = headers;
+
+        let data = self.data.get(..data_size)?;
+        self.data = self.data.get(data_size..)?;
+        let (point_numbers, data) = if tuple_index.contains(TupleIndex::PRIVATE_POINT_NUMBERS) {
+            PackedPointNumbers::read(data)?
+        } else {
+            (self.shared_points.clone(), data)
+        };
+        let delta_count = point_numbers
+            .as_ref()
+            .map(PackedPointNumbers::count)
+            .unwrap_or(self.point_count);
+        let x_deltas = PackedDeltas::new(data, delta_count);
+        let y_deltas = PackedDeltas::new(x_deltas.remaining_data()?, delta_count);
+        Some(TupleVariation {
+            peak_tuple,
+            intermediate_region,
+            point_numbers,
+            x_deltas,
+            y_deltas,
+        })
+    }
+}
+
+/// A tuple record: a location in design space, with one normalized
+/// coordinate for each axis.
+pub type Tuple<'a> = &'a [BigEndian<F2Dot14>];
+
+/// A set of deltas for the points of a glyph, which apply to a region of
+/// design space.
+#[derive(Clone)]
+pub struct TupleVariation<'a> {
+    peak_tuple: Tuple<'a>,
+    intermediate_region: Option<(Tuple<'a>, Tuple<'a>)>,
+    point_numbers: Option<PackedPointNumbers<'a>>,
+    x_deltas: PackedDeltas<'a>,
+    y_deltas: PackedDeltas<'a>,
+}
+
+impl<'a> TupleVariation<'a> {
+    /// The location where these deltas apply in full, with one coordinate
+    /// for each axis.
+    pub fn peak_tuple(&self) -> Tuple<'a> {
+        self.peak_tuple
+    }
+
+    /// The start and end of the region where these deltas apply, if it is
+    /// not implied by the peak.
+    pub fn intermediate_region(&self) -> Option<(Tuple<'a>, Tuple<'a>)> {
+        self.intermediate_region
+    }
+
+    /// The points that have deltas, or `None` if every point has a delta.
+    pub fn point_numbers(&self) -> Option<PackedPointNumbers<'a>> {
+        self.point_numbers.clone()
+    }
+
+    /// The x and y deltas, for each point in [`point_numbers`][Self::point_numbers].
+    pub fn deltas(&self) -> impl Iterator<Item = (i32, i32)> + 'a {
+        self.x_deltas.clone().zip(self.y_deltas.clone())
+    }
+
+    /// The scalar for these deltas, at a location in design space.
+    ///
+    /// This is in the range 0.0..=1.0, and is the product of the scalars for
+    /// each axis.
+    pub fn scalar(&self, coords: &[F2Dot14]) -> f32 {
+        let zero = F2Dot14::default();
+        let mut scalar = 1.0;
+        for (i, peak) in self.peak_tuple.iter().enumerate() {
+            let peak = peak.get();
+            let (start, end) = match self.intermediate_region {
+                Some((start, end)) => (start[i].get(), end[i].get()),
+                None => (peak.min(zero), peak.max(zero)),
+            };
+            let coord = coords.get(i).copied().unwrap_or_default();
+            scalar *= axis_scalar(start, peak, end, coord);
+            if scalar == 0.0 {
+                break;
+            }
+        }
+        scalar
+    }
+}
+
+/// A run-length encoded list of point numbers.
+#[derive(Clone)]
+pub struct PackedPointNumbers<'a> {
+    data: &'a [u8],
+    count: u16,
+}
+
+impl<'a> PackedPointNumbers<'a> {
+    /// Read packed point numbers, returning them and the bytes that follow.
+    ///
+    /// The point numbers are `None` if they refer to all points.
+    fn read(data: &'a [u8]) -> Option<(Option<Self>, &'a [u8])> {
+        let mut cursor = Cursor::new(data);
+        let first = cursor.bump::<u8>()?;
+        if first == 0 {
+            return Some((None, cursor.remaining()));
+        }
+        let count = if first & POINTS_ARE_WORDS != 0 {
+            ((first & POINT_RUN_COUNT_MASK) as u16) << 8 | cursor.bump::<u8>()? as u16
+        } else {
+            first as u16
+        };
+        let points = PackedPointNumbers {
+            data: cursor.remaining(),
+            count,
+        };
+        let mut iter = points.iter();
+        iter.by_ref().for_each(drop);
+        if iter.remaining != 0 {
+            return None;
+        }
+        let rest = iter.cursor.remaining();
+        Some((Some(points), rest))
+    }
+
+    /// The number of point numbers.
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    /// Iterate over the point numbers.
+    pub fn iter(&self) -> PackedPointIter<'a> {
+        PackedPointIter {
+            cursor: Cursor::new(self.data),
+            remaining: self.count,
+            run_remaining: 0,
+            run_words: false,
+            last: 0,
+        }
+    }
+}
+
+/// An iterator over [`PackedPointNumbers`].
+#[derive(Clone)]
+pub struct PackedPointIter<'a> {
+    cursor: Cursor<'a>,
+    remaining: u16,
+    run_remaining: u8,
+    run_words: bool,
+    last: u16,
+}
+
+impl Iterator for PackedPointIter<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.run_remaining == 0 {
+            let control = self.cursor.bump::<u8>()?;
+            self.run_words = control & POINTS_ARE_WORDS != 0;
+            self.run_remaining = (control & POINT_RUN_COUNT_MASK) + 1;
+        }
+        // each point number is stored as the difference from the previous
+        let delta = if self.run_words {
+            self.cursor.bump::<u16>()?
+        } else {
+            self.cursor.bump::<u8>()? as u16
+        };
+        self.last = self.last.wrapping_add(delta);
+        self.run_remaining -= 1;
+        self.remaining -= 1;
+        Some(self.last)
+    }
+}
+
+/// A run-length encoded list of deltas.
+#[derive(Clone, Default)]
+pub struct PackedDeltas<'a> {
+    cursor: Cursor<'a>,
+    remaining: u16,
+    run_remaining: u8,
+    run_control: u8,
+}
+
+impl<'a> PackedDeltas<'a> {
+    fn new(data: &'a [u8], count: u16) -> Self {
+        PackedDeltas {
+            cursor: Cursor::new(data),
+            remaining: count,
+            ..Default::default()
+        }
+    }
+
+    /// The bytes following these deltas.
+    fn remaining_data(&self) -> Option<&'a [u8]> {
+        let mut iter = self.clone();
+        iter.by_ref().for_each(drop);
+        if iter.remaining != 0 {
+            return None;
+        }
+        Some(iter.cursor.remaining())
+    }
+}
+
+impl Iterator for PackedDeltas<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.run_remaining == 0 {
+            let control = self.cursor.bump::<u8>()?;
+            self.run_control = control;
+            self.run_remaining = (control & DELTA_RUN_COUNT_MASK) + 1;
+        }
+        let delta = if self.run_control & DELTAS_ARE_ZERO != 0 {
+            0
+        } else if self.run_control & DELTAS_ARE_WORDS != 0 {
+            self.cursor.bump::<i16>()? as i32
+        } else {
+            self.cursor.bump::<i8>()? as i32
+        };
+        self.run_remaining -= 1;
+        self.remaining -= 1;
+        Some(delta)
+    }
+}
+
+/// Infer deltas for the points in each contour that were not referenced
+/// by a tuple variation.
+///
+/// This is the "interpolate untouched points" (IUP) step.
+#[cfg(feature = "std")]
+fn infer_deltas(deltas: &mut [(f32, f32)], touched: &[bool], points: &[Point], end_points: &[u16]) {
+    let mut start = 0;
+    for end in end_points {
+        let end = *end as usize;
+        if end < start || end >= deltas.len() {
+            break;
+        }
+        let contour = start..end + 1;
+        infer_contour_deltas(
+            &mut deltas[contour.clone()],
+            &touched[contour.clone()],
+            &points[contour],
+        );
+        start = end + 1;
+    }
+}
+
+#[cfg(feature = "std")]
+fn infer_contour_deltas(deltas: &mut [(f32, f32)], touched: &[bool], points: &[Point]) {
+    let touched_idxs = (0..touched.len())
+        .filter(|idx| touched[*idx])
+        .collect::<Vec<_>>();
+    let (first, last) = match (touched_idxs.first(), touched_idxs.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return,
+    };
+    // a single touched point moves the whole contour
+    if first == last {
+        let delta = deltas[first];
+        deltas.iter_mut().for_each(|d| *d = delta);
+        return;
+    }
+    let len = deltas.len();
+    // untouched points are between two touched points, wrapping around the
+    // end of the contour
+    let pairs = touched_idxs
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .chain(Some((last, first)));
+    for (prev, next) in pairs {
+        let (prev_point, next_point) = (points[prev], points[next]);
+        let (prev_delta, next_delta) = (deltas[prev], deltas[next]);
+        let mut idx = (prev + 1) % len;
+        while idx != next {
+            let point = points[idx];
+            deltas[idx] = (
+                infer_delta(
+                    point.x,
+                    (prev_point.x, prev_delta.0),
+                    (next_point.x, next_delta.0),
+                ),
+                infer_delta(
+                    point.y,
+                    (prev_point.y, prev_delta.1),
+                    (next_point.y, next_delta.1),
+                ),
+            );
+            idx = (idx + 1) % len;
+        }
+    }
+}
+
+/// Infer the delta for one coordinate of an untouched point, from the
+/// (coordinate, delta) of the touched points on either side.
+#[cfg(feature = "std")]
+fn infer_delta(coord: i16, prev: (i16, f32), next: (i16, f32)) -> f32 {
+    let ((c1, d1), (c2, d2)) = if prev.0 <= next.0 {
+        (prev, next)
+    } else {
+        (next, prev)
+    };
+    if c1 == c2 {
+        return if d1 == d2 { d1 } else { 0.0 };
+    }
+    if coord <= c1 {
+        d1
+    } else if coord >= c2 {
+        d2
+    } else {
+        let t = (coord - c1) as f32 / (c2 - c1) as f32;
+        d1 + t * (d2 - d1)
+    }
+}
+
+/// A glyph with variations applied.
+///
+/// This has the same points and components as the original [`Glyph`], at
+/// their varied positions.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct VariedGlyph {
+    points: Vec<GlyphPoint>,
+    components: Vec<Component>,
+    phantom_points: [Point; 4],
+}
+
+#[cfg(feature = "std")]
+impl VariedGlyph {
+    /// The points of a simple glyph.
+    pub fn iter_points(&self) -> impl Iterator<Item = GlyphPoint> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// The components of a composite glyph, with varied offsets.
+    pub fn components(&self) -> impl Iterator<Item = Component> + '_ {
+        self.components.iter().copied()
+    }
+
+    /// The varied phantom points.
+    pub fn phantom_points(&self) -> [Point; 4] {
+        self.phantom_points
+    }
+
+    /// The varied advance width, the distance between the first two phantom
+    /// points.
+    pub fn advance_width(&self) -> i32 {
+        let [left, right, ..] = self.phantom_points;
+        right.x as i32 - left.x as i32
+    }
+
+    /// The varied advance height, the distance between the last two phantom
+    /// points.
+    pub fn advance_height(&self) -> i32 {
+        let [.., top, bottom] = self.phantom_points;
+        top.y as i32 - bottom.y as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::tables::{
+        glyf::phantom_points,
+        test_helpers::{maxp, metrics, metrics_header, TableList},
+        TableProvider,
+    };
+    use crate::variations::test_helpers::f2dot14;
+
+    fn square_glyph() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1i16, 0, 0, 100, 100]);
+        // one contour, no instructions
+        buf.extend([3u16, 0]);
+        buf.extend([1u8, 1, 1, 1]);
+        buf.extend([0i16, 0, 100, 0]);
+        buf.extend([0i16, 100, 0, -100]);
+        buf
+    }
+
+    fn gvar() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        // one axis & one shared tuple; two glyphs, the first without variations
+        buf.extend([1u16, 0, 1, 1]);
+        buf.push(26u32);
+        buf.extend([2u16, 0]);
+        buf.push(28u32);
+        buf.extend([0u16, 0, 17]);
+        buf.push(f2dot14(1.0));
+
+        // shared point numbers, two tuples, data at 18
+        buf.extend([0x8002u16, 18]);
+        // the shared tuple, with shared points
+        buf.extend([6u16, 0]);
+        // an embedded intermediate tuple, with private points
+        buf.extend([6u16, 0xE000]);
+        buf.extend([f2dot14(0.5), f2dot14(0.0), f2dot14(1.0)]);
+        // shared points: 1 & 2
+        buf.extend([2u8, 0x01, 1, 1]);
+        // x & y deltas for points 1 & 2
+        buf.extend([0x01u8, 10, 20, 0x01, 0, 30]);
+        // all points: only the advance (point 5) moves
+        buf.extend([0u8, 0x84, 0x00, 100, 0x81, 0x87]);
+        buf
+    }
+
+    #[test]
+    fn tuple_variations() {
+        let buf = gvar();
+        let gvar = Gvar::read(&buf).unwrap();
+        assert_eq!(gvar.shared_tuple(0).unwrap()[0].get(), f2dot14(1.0));
+        assert!(gvar.shared_tuple(1).is_none());
+        assert!(gvar.glyph_variation_data(0).is_none());
+        assert_eq!(gvar.tuple_variations(0, 8).count(), 0);
+
+        let tuples = gvar.tuple_variations(1, 8).collect::<Vec<_>>();
+        assert_eq!(tuples.len(), 2);
+        let points = tuples[0].point_numbers().unwrap();
+        assert_eq!(points.iter().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(tuples[0].deltas().collect::<Vec<_>>(), [(10, 0), (20, 30)]);
+        assert_eq!(tuples[0].scalar(&[f2dot14(0.5)]), 0.5);
+        assert!(tuples[1].point_numbers().is_none());
+        assert_eq!(tuples[1].deltas().count(), 8);
+        assert_eq!(tuples[1].deltas().nth(5), Some((100, 0)));
+        assert_eq!(tuples[1].scalar(&[f2dot14(0.25)]), 0.5);
+        assert_eq!(tuples[1].scalar(&[f2dot14(1.0)]), 0.0);
+    }
+
+    #[test]
+    fn varied_glyph() {
+        let buf = gvar();
+        let gvar = Gvar::read(&buf).unwrap();
+        let glyph_buf = square_glyph();
+        let glyph = Glyph::read(&glyph_buf).unwrap();
+        let phantom = phantom_points(Some(&glyph), 120, 0, 0, 0);
+        let varied = |glyph_id, coord| {
+            let glyph = gvar.varied_glyph(glyph_id, Some(&glyph), phantom, &[f2dot14(coord)]);
+            let points = glyph
+                .iter_points()
+                .map(|point| {
+                    let point = point.point();
+                    (point.x, point.y)
+                })
+                .collect::<Vec<_>>();
+            (points, glyph.phantom_points())
+        };
+
+        let (points, phantom_points) = varied(1, 0.0);
+        assert_eq!(points, [(0, 0), (0, 100), (100, 100), (100, 0)]);
+        assert_eq!(phantom_points, phantom);
+        // points 0 & 3 are inferred from their neighbours
+        let (points, phantom_points) = varied(1, 1.0);
+        assert_eq!(points, [(10, 0), (10, 100), (120, 130), (120, 0)]);
+        assert_eq!(phantom_points, phantom);
+        let (points, phantom_points) = varied(1, 0.5);
+        assert_eq!(points, [(5, 0), (5, 100), (110, 115), (110, 0)]);
+        assert_eq!(phantom_points[1], Point { x: 220, y: 0 });
+        let advance = gvar
+            .varied_glyph(1, Some(&glyph), phantom, &[f2dot14(0.5)])
+            .advance_width();
+        assert_eq!(advance, 220);
+        // no variation data
+        let (points, _) = varied(0, 1.0);
+        assert_eq!(points, [(0, 0), (0, 100), (100, 100), (100, 0)]);
+    }
+
+    #[test]
+    fn advance_from_phantom_points() {
+        let glyph = square_glyph();
+        // glyph 0 is empty, and glyph 1 is the square
+        let mut loca = BeBuffer::new();
+        loca.extend([0u16, 0, glyph.len() as u16 / 2]);
+        // one long metric, so glyph 1 only has a side bearing; no HVAR or VVAR
+        let font = TableList(vec![
+            // short loca offsets
+            (Tag::new(b"head"), vec![0; 54]),
+            (Tag::new(b"maxp"), maxp(2)),
+            (Tag::new(b"hhea"), metrics_header(1)),
+            (Tag::new(b"hmtx"), metrics(&[(120, 0)], &[0])),
+            (Tag::new(b"vhea"), metrics_header(1)),
+            (Tag::new(b"vmtx"), metrics(&[(1000, 0)], &[0])),
+            (Tag::new(b"loca"), loca.to_vec()),
+            (Tag::new(b"glyf"), glyph.to_vec()),
+            (TAG, gvar().to_vec()),
+        ]);
+
+        assert_eq!(font.advance(1, &[f2dot14(0.0)]), Some(120.0));
+        assert_eq!(font.advance(1, &[f2dot14(0.5)]), Some(220.0));
+        assert_eq!(font.advance(1, &[f2dot14(1.0)]), Some(120.0));
+        // no outline and no variation data
+        assert_eq!(font.advance(0, &[f2dot14(0.5)]), Some(120.0));
+        // only the advance width varies
+        assert_eq!(font.vertical_advance(1, &[f2dot14(0.5)]), Some(1000.0));
+    }
+}
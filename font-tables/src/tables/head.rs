@@ -7,23 +7,40 @@ use font_types::Tag;
 
 pub use generated::*;
 
+use super::loca::LocaFormat;
+
 /// 'name'
 pub const TAG: Tag = Tag::new(b"head");
 
+impl Head {
+    /// The format of the offsets in the `loca` table.
+    ///
+    /// Returns `None` if `index_to_loc_format` is not 0 or 1.
+    pub fn loca_format(&self) -> Option<LocaFormat> {
+        match self.index_to_loc_format() {
+            0 => Some(LocaFormat::Short),
+            1 => Some(LocaFormat::Long),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use font_types::{test_helpers::BeBuffer, Fixed, FontRead, LongDateTime};
 
+    use super::{HeadFlags, MacStyle};
+
     #[test]
     fn smoke_text() {
         let mut buf = BeBuffer::new();
         buf.extend([1u16, 0u16]);
         buf.push(Fixed::from_f64(2.8));
         buf.extend([42u32, 0x5f0f3cf5]);
-        buf.extend([16u16, 4096]); // flags, upm
+        buf.extend([0x0809u16, 4096]); // flags, upm
         buf.extend([LongDateTime::new(-500), LongDateTime::new(101)]);
         buf.extend([-100i16, -50, 400, 711]);
-        buf.extend([0u16, 12]); // mac_style / ppem
+        buf.extend([3u16, 12]); // mac_style / ppem
         buf.extend([2i16, 1, 0]);
 
         let head = super::Head::read(&buf).unwrap();
@@ -33,5 +50,12 @@ mod tests {
         assert_eq!(head.units_per_em(), 4096);
         assert_eq!(head.created().as_secs(), -500);
         assert_eq!(head.y_min(), -50);
+        assert_eq!(
+            head.flags(),
+            HeadFlags::BASELINE_AT_Y_0 | HeadFlags::FORCE_INTEGER_PPEM | HeadFlags::LOSSLESS
+        );
+        assert_eq!(head.mac_style(), MacStyle::BOLD | MacStyle::ITALIC);
+        assert_eq!(head.modified().to_string(), "1904-01-01T00:01:41Z");
+        assert_eq!(head.loca_format(), Some(super::LocaFormat::Long));
     }
 }
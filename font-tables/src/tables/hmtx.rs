@@ -8,3 +8,29 @@ pub use generated::*;
 use font_types::Tag;
 
 pub const TAG: Tag = Tag::new(b"hmtx");
+
+impl<'a> Hmtx<'a> {
+    /// The advance width of a glyph.
+    ///
+    /// Glyphs without an entry in `h_metrics` share the advance of the last
+    /// entry.
+    pub fn advance(&self, glyph_id: u16) -> Option<u16> {
+        let metrics = self.h_metrics();
+        metrics
+            .get(glyph_id as usize)
+            .or_else(|| metrics.last())
+            .map(|metric| metric.advance_width())
+    }
+
+    /// The left side bearing of a glyph.
+    pub fn lsb(&self, glyph_id: u16) -> Option<i16> {
+        let metrics = self.h_metrics();
+        match metrics.get(glyph_id as usize) {
+            Some(metric) => Some(metric.lsb()),
+            None => self
+                .left_side_bearings()
+                .get(glyph_id as usize - metrics.len())
+                .map(|lsb| lsb.get()),
+        }
+    }
+}
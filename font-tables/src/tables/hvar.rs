@@ -0,0 +1,108 @@
+//! The [HVAR (Horizontal Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
+
+#[path = "../../generated/generated_hvar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{F2Dot14, OffsetHost, Tag};
+
+use crate::variations::{DeltaSetIndexMap, ItemVariationStore};
+
+/// 'HVAR'
+pub const TAG: Tag = Tag::new(b"HVAR");
+
+impl<'a> Hvar<'a> {
+    pub fn item_variation_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.resolve_offset(self.item_variation_store_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for advance widths.
+    ///
+    /// If this is missing, glyph ids are used as inner indices.
+    pub fn advance_width_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.advance_width_mapping_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for left side bearings.
+    pub fn lsb_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.lsb_mapping_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for right side bearings.
+    pub fn rsb_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.rsb_mapping_offset())
+    }
+
+    /// The delta for the advance width of a glyph, at a location in design
+    /// space.
+    pub fn advance_width_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        self.item_variation_store()?.compute_mapped_delta(
+            self.advance_width_mapping().as_ref(),
+            glyph_id as u32,
+            coords,
+        )
+    }
+
+    /// The delta for the left side bearing of a glyph, if the font has
+    /// side bearing variations.
+    pub fn lsb_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let map = self.lsb_mapping()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map), glyph_id as u32, coords)
+    }
+
+    /// The delta for the right side bearing of a glyph, if the font has
+    /// side bearing variations.
+    pub fn rsb_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let map = self.rsb_mapping()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map), glyph_id as u32, coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+    use crate::variations::test_helpers::{f2dot14, var_store};
+
+    #[test]
+    fn implicit_advance_mapping() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.extend([20u32, 0, 0, 0]);
+        buf.extend(var_store(&[(0.0, 1.0, 1.0)], &[10, 20, -30]));
+
+        let hvar = Hvar::read(&buf).unwrap();
+        let coords = [f2dot14(0.5)];
+        assert_eq!(hvar.advance_width_delta(0, &coords), Some(5.0));
+        assert_eq!(hvar.advance_width_delta(2, &coords), Some(-15.0));
+        assert_eq!(hvar.advance_width_delta(3, &coords), None);
+        assert_eq!(hvar.lsb_delta(0, &coords), None);
+    }
+
+    #[test]
+    fn mapped_deltas() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.extend([20u32, 56, 63, 0]);
+        buf.extend(var_store(&[(0.0, 1.0, 1.0)], &[10, 20, -30]));
+        // advances: glyphs 0, 1 & 2 use rows 2, 0 & 0
+        buf.extend([0u8, 0x07]);
+        buf.push(3u16);
+        buf.extend([2u8, 0, 0]);
+        // lsbs: every glyph uses row 1
+        buf.extend([0u8, 0x07]);
+        buf.push(1u16);
+        buf.push(1u8);
+
+        let hvar = Hvar::read(&buf).unwrap();
+        let coords = [f2dot14(1.0)];
+        assert_eq!(hvar.advance_width_delta(0, &coords), Some(-30.0));
+        assert_eq!(hvar.advance_width_delta(1, &coords), Some(10.0));
+        assert_eq!(hvar.lsb_delta(5, &coords), Some(20.0));
+        assert_eq!(hvar.rsb_delta(5, &coords), None);
+    }
+}
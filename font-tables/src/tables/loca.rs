@@ -2,12 +2,27 @@
 //!
 //! [loca]: https://docs.microsoft.com/en-us/typography/opentype/spec/loca
 
-use font_types::{BigEndian, Offset32, Tag};
+use font_types::{BigEndian, Offset, Offset32, Tag};
 use zerocopy::LayoutVerified;
 
 /// 'loca'
 pub const TAG: Tag = Tag::new(b"loca");
 
+/// The format of the offsets in a [`Loca`] table.
+///
+/// This is determined by the `index_to_loc_format` field of the [head]
+/// table; see [`Head::loca_format`].
+///
+/// [head]: super::head::Head
+/// [`Head::loca_format`]: super::head::Head::loca_format
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaFormat {
+    /// Offsets are stored as `u16`, and are half the actual offset.
+    Short,
+    /// Offsets are stored as [`Offset32`].
+    Long,
+}
+
 /// The [loca] table.
 ///
 /// [loca]: https://docs.microsoft.com/en-us/typography/opentype/spec/loca
@@ -16,21 +31,81 @@ pub enum Loca<'a> {
     Long(&'a [BigEndian<Offset32>]),
 }
 
+/// A problem found in a `loca` table by [`Loca::validate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaError {
+    /// The table's length does not match the number of glyphs in `maxp`.
+    ///
+    /// Lengths are measured in entries; there should be `num_glyphs + 1`.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The offset is less than the preceding offset.
+    NotMonotonic { entry: LocaEntry, offset: u32 },
+    /// The offset is odd.
+    ///
+    /// Glyph data should be at least two-byte aligned; odd offsets cannot
+    /// be represented in the short format.
+    OddOffset { entry: LocaEntry, offset: u32 },
+    /// The offset is past the end of the `glyf` table.
+    OutOfBounds { entry: LocaEntry, offset: u32 },
+}
+
+/// An offset in a `loca` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaEntry {
+    /// The offset to the start of a glyph.
+    Glyph(u16),
+    /// The final offset, which marks the end of the last glyph.
+    End,
+}
+
+impl LocaFormat {
+    /// The size in bytes of a single offset in this format.
+    pub fn offset_size(self) -> usize {
+        match self {
+            LocaFormat::Short => 2,
+            LocaFormat::Long => 4,
+        }
+    }
+}
+
 impl<'a> Loca<'a> {
     /// Create a new loca table.
     ///
-    /// num_glyphs should be read from [maxp], and is_short from [head].
+    /// num_glyphs should be read from [maxp], and format from [head].
     ///
-    /// [maxp]: super::Maxp
-    /// [head]: super::head::Head
-    pub fn read(data: &'a [u8], num_glyphs: u16, is_long: bool) -> Option<Self> {
+    /// [maxp]: super::maxp::Maxp
+    /// [head]: super::head::Head::loca_format
+    pub fn read(data: &'a [u8], num_glyphs: u16, format: LocaFormat) -> Option<Self> {
         let num_glyphs = num_glyphs as usize;
-        if is_long {
-            let (data, _) = LayoutVerified::new_slice_unaligned_from_prefix(data, num_glyphs + 1)?;
-            Some(Loca::Long(data.into_slice()))
-        } else {
-            let (data, _) = LayoutVerified::new_slice_unaligned_from_prefix(data, num_glyphs + 1)?;
-            Some(Loca::Short(data.into_slice()))
+        match format {
+            LocaFormat::Long => {
+                let (data, _) =
+                    LayoutVerified::new_slice_unaligned_from_prefix(data, num_glyphs + 1)?;
+                Some(Loca::Long(data.into_slice()))
+            }
+            LocaFormat::Short => {
+                let (data, _) =
+                    LayoutVerified::new_slice_unaligned_from_prefix(data, num_glyphs + 1)?;
+                Some(Loca::Short(data.into_slice()))
+            }
+        }
+    }
+
+    /// The format of this table.
+    pub fn format(&self) -> LocaFormat {
+        match self {
+            Loca::Short(_) => LocaFormat::Short,
+            Loca::Long(_) => LocaFormat::Long,
+        }
+    }
+
+    /// The number of offsets in the table.
+    ///
+    /// This is one more than the number of glyphs.
+    pub fn len(&self) -> usize {
+        match self {
+            Loca::Short(data) => data.len(),
+            Loca::Long(data) => data.len(),
         }
     }
 
@@ -46,13 +121,158 @@ impl<'a> Loca<'a> {
         }
     }
 
+    /// Return the range of the `glyf` table containing the given glyph.
+    ///
+    /// Returns `None` if the glyph id is out of bounds, or if the range is
+    /// inverted. An empty glyph (such as a space) has an empty range.
+    pub fn glyph_range(&self, glyph_id: u16) -> Option<core::ops::Range<usize>> {
+        let start = self.get_raw(glyph_id as usize)? as usize;
+        let end = self.get_raw(glyph_id as usize + 1)? as usize;
+        (start <= end).then_some(start..end)
+    }
+
     /// Iterate all offsets
     pub fn iter(&self) -> impl Iterator<Item = Offset32> + '_ {
-        let mut idx = 0;
-        std::iter::from_fn(move || {
-            let result = self.get(idx);
-            idx += 1;
-            result
-        })
+        (0..self.len()).filter_map(|idx| self.get(idx))
+    }
+
+    /// Check the raw data of a `loca` table for problems.
+    ///
+    /// This works on tables that are too short to be read. `num_glyphs`
+    /// should be read from `maxp`, and `glyf_len` is the length of the
+    /// `glyf` table. Only the offsets for those glyphs, and the end offset,
+    /// are checked.
+    #[cfg(feature = "std")]
+    pub fn validate(
+        data: &[u8],
+        format: LocaFormat,
+        num_glyphs: u16,
+        glyf_len: usize,
+    ) -> Vec<LocaError> {
+        let mut errors = Vec::new();
+        let expected = num_glyphs as usize + 1;
+        let actual = data.len() / format.offset_size();
+        if actual != expected || actual * format.offset_size() != data.len() {
+            errors.push(LocaError::LengthMismatch { expected, actual });
+        }
+
+        let n_entries = actual.min(expected);
+        let loca = match n_entries
+            .checked_sub(1)
+            .and_then(|n_glyphs| Loca::read(data, n_glyphs as u16, format))
+        {
+            Some(loca) => loca,
+            None => return errors,
+        };
+        let mut prev = 0;
+        for idx in 0..loca.len() {
+            let offset = match loca.get_raw(idx) {
+                Some(offset) => offset,
+                None => break,
+            };
+            let entry = if idx == num_glyphs as usize {
+                LocaEntry::End
+            } else {
+                LocaEntry::Glyph(idx as u16)
+            };
+            if offset < prev {
+                errors.push(LocaError::NotMonotonic { entry, offset });
+            }
+            if offset % 2 != 0 {
+                errors.push(LocaError::OddOffset { entry, offset });
+            }
+            if offset as usize > glyf_len {
+                errors.push(LocaError::OutOfBounds { entry, offset });
+            }
+            prev = offset;
+        }
+        errors
+    }
+
+    fn get_raw(&self, idx: usize) -> Option<u32> {
+        match self {
+            Loca::Short(data) => data.get(idx).map(|x| x.get() as u32 * 2),
+            Loca::Long(data) => data
+                .get(idx)
+                .map(|x| x.get().non_null().unwrap_or_default() as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+
+    #[test]
+    fn short_ranges() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 10, 10, 20]);
+        let loca = Loca::read(&buf, 3, LocaFormat::Short).unwrap();
+        assert_eq!(loca.glyph_range(0), Some(0..20));
+        assert_eq!(loca.glyph_range(1), Some(20..20));
+        assert_eq!(loca.glyph_range(2), Some(20..40));
+        assert_eq!(loca.glyph_range(3), None);
+        assert!(Loca::validate(&buf, LocaFormat::Short, 3, 40).is_empty());
+    }
+
+    #[test]
+    fn validate() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u32, 12, 7, 40, 44]);
+        // maxp says 3 glyphs; the table has 4
+        let loca = Loca::read(&buf, 3, LocaFormat::Long).unwrap();
+        assert_eq!(loca.glyph_range(1), None);
+        assert_eq!(
+            Loca::validate(&buf, LocaFormat::Long, 3, 32),
+            [
+                LocaError::LengthMismatch {
+                    expected: 4,
+                    actual: 5
+                },
+                LocaError::NotMonotonic {
+                    entry: LocaEntry::Glyph(2),
+                    offset: 7
+                },
+                LocaError::OddOffset {
+                    entry: LocaEntry::Glyph(2),
+                    offset: 7
+                },
+                LocaError::OutOfBounds {
+                    entry: LocaEntry::End,
+                    offset: 40
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_short_table() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 10, 5]);
+        // maxp says 3 glyphs; the table only has offsets for 2
+        assert!(Loca::read(&buf, 3, LocaFormat::Short).is_none());
+        assert_eq!(
+            Loca::validate(&buf, LocaFormat::Short, 3, 40),
+            [
+                LocaError::LengthMismatch {
+                    expected: 4,
+                    actual: 3
+                },
+                LocaError::NotMonotonic {
+                    entry: LocaEntry::Glyph(2),
+                    offset: 10
+                },
+            ]
+        );
+        // not even a single offset
+        assert_eq!(
+            Loca::validate(&[0], LocaFormat::Short, 0, 40),
+            [LocaError::LengthMismatch {
+                expected: 1,
+                actual: 0
+            }]
+        );
     }
 }
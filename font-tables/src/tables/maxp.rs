@@ -7,4 +7,427 @@ pub use generated::*;
 
 use font_types::Tag;
 
+#[cfg(feature = "std")]
+use font_types::Offset32;
+
+#[cfg(feature = "std")]
+use super::{
+    glyf::{Glyf, Glyph},
+    loca::Loca,
+};
+
 pub const TAG: Tag = Tag::new(b"maxp");
+
+/// Composites nested deeper than this are assumed to be cyclic.
+#[cfg(feature = "std")]
+const MAX_COMPONENT_DEPTH: u16 = 64;
+
+/// The fields of a version 1.0 `maxp` table that are derived from the glyphs.
+///
+/// This can be computed for a single glyph, or for a whole font with
+/// [`GlyphLimits::compute`], in which case it contains the values that
+/// should be written to the `maxp` table. The remaining fields (`max_zones`,
+/// `max_twilight_points`, `max_storage`, and so on) depend on the font's
+/// hinting, and cannot be recomputed from the glyphs.
+///
+/// Note that `max_size_of_instructions` in `maxp` should also account for
+/// the `fpgm` and `prep` tables; the computed value only considers glyphs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GlyphLimits {
+    pub max_points: u16,
+    pub max_contours: u16,
+    pub max_composite_points: u16,
+    pub max_composite_contours: u16,
+    pub max_size_of_instructions: u16,
+    pub max_component_elements: u16,
+    pub max_component_depth: u16,
+}
+
+/// A field in [`GlyphLimits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphLimit {
+    Points,
+    Contours,
+    CompositePoints,
+    CompositeContours,
+    SizeOfInstructions,
+    ComponentElements,
+    ComponentDepth,
+}
+
+/// A problem found in a glyph by [`Maxp::validate_glyphs`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphError {
+    /// The glyph exceeds one of the maxima declared in the `maxp` table.
+    LimitExceeded(LimitExceeded),
+    /// The glyph is a composite that contains itself, directly or through
+    /// its components.
+    ///
+    /// Composites nested too deeply to resolve are also reported as cyclic.
+    /// The glyph's flattened points and contours cannot be computed.
+    CyclicComposite { glyph_id: u16 },
+}
+
+/// A glyph that exceeds one of the maxima declared in the `maxp` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LimitExceeded {
+    pub glyph_id: u16,
+    pub limit: GlyphLimit,
+    /// The value declared in `maxp`.
+    pub declared: u16,
+    /// The actual value for this glyph.
+    pub actual: u16,
+}
+
+impl Maxp {
+    /// The glyph-derived fields of this table, if it is version 1.0.
+    pub fn glyph_limits(&self) -> Option<GlyphLimits> {
+        match self {
+            Maxp::Version0_5(_) => None,
+            Maxp::Version1_0(table) => Some(GlyphLimits {
+                max_points: table.max_points(),
+                max_contours: table.max_contours(),
+                max_composite_points: table.max_composite_points(),
+                max_composite_contours: table.max_composite_contours(),
+                max_size_of_instructions: table.max_size_of_instructions(),
+                max_component_elements: table.max_component_elements(),
+                max_component_depth: table.max_component_depth(),
+            }),
+        }
+    }
+
+    /// Check every glyph against the maxima declared in this table.
+    ///
+    /// Returns a list of each glyph and value that exceeds the declared
+    /// maximum, as well as any cyclic composite glyphs. Version 0.5 tables
+    /// declare no maxima, and always pass.
+    #[cfg(feature = "std")]
+    pub fn validate_glyphs(&self, glyf: &Glyf, loca: &Loca) -> Vec<GlyphError> {
+        let declared = match self.glyph_limits() {
+            Some(limits) => limits,
+            None => return Vec::new(),
+        };
+        let mut errors = Vec::new();
+        let mut computer = LimitComputer::new(glyf, loca, self.num_glyphs());
+        for gid in 0..self.num_glyphs() {
+            let actual = computer.glyph_limits(gid);
+            if computer.is_cyclic(gid) {
+                errors.push(GlyphError::CyclicComposite { glyph_id: gid });
+            }
+            for (limit, declared, actual) in declared.compare(&actual) {
+                if actual > declared {
+                    errors.push(GlyphError::LimitExceeded(LimitExceeded {
+                        glyph_id: gid,
+                        limit,
+                        declared,
+                        actual,
+                    }));
+                }
+            }
+        }
+        errors
+    }
+}
+
+impl GlyphLimits {
+    /// Compute the correct values for a font's `maxp` table.
+    ///
+    /// `num_glyphs` should be read from the `maxp` table. Cyclic composites
+    /// contribute no flattened points or contours; use
+    /// [`Maxp::validate_glyphs`] to find them.
+    #[cfg(feature = "std")]
+    pub fn compute(glyf: &Glyf, loca: &Loca, num_glyphs: u16) -> Self {
+        let mut computer = LimitComputer::new(glyf, loca, num_glyphs);
+        (0..num_glyphs)
+            .map(|gid| computer.glyph_limits(gid))
+            .fold(GlyphLimits::default(), |acc, glyph| acc.max(&glyph))
+    }
+
+    /// Combine two sets of limits, taking the maximum of each field.
+    pub fn max(&self, other: &GlyphLimits) -> GlyphLimits {
+        GlyphLimits {
+            max_points: self.max_points.max(other.max_points),
+            max_contours: self.max_contours.max(other.max_contours),
+            max_composite_points: self.max_composite_points.max(other.max_composite_points),
+            max_composite_contours: self
+                .max_composite_contours
+                .max(other.max_composite_contours),
+            max_size_of_instructions: self
+                .max_size_of_instructions
+                .max(other.max_size_of_instructions),
+            max_component_elements: self
+                .max_component_elements
+                .max(other.max_component_elements),
+            max_component_depth: self.max_component_depth.max(other.max_component_depth),
+        }
+    }
+
+    /// Iterate over `(limit, self_value, other_value)` for each field.
+    #[cfg(feature = "std")]
+    fn compare(&self, other: &GlyphLimits) -> impl Iterator<Item = (GlyphLimit, u16, u16)> {
+        [
+            (GlyphLimit::Points, self.max_points, other.max_points),
+            (GlyphLimit::Contours, self.max_contours, other.max_contours),
+            (
+                GlyphLimit::CompositePoints,
+                self.max_composite_points,
+                other.max_composite_points,
+            ),
+            (
+                GlyphLimit::CompositeContours,
+                self.max_composite_contours,
+                other.max_composite_contours,
+            ),
+            (
+                GlyphLimit::SizeOfInstructions,
+                self.max_size_of_instructions,
+                other.max_size_of_instructions,
+            ),
+            (
+                GlyphLimit::ComponentElements,
+                self.max_component_elements,
+                other.max_component_elements,
+            ),
+            (
+                GlyphLimit::ComponentDepth,
+                self.max_component_depth,
+                other.max_component_depth,
+            ),
+        ]
+        .into_iter()
+    }
+}
+
+/// The flattened size of a glyph: (points, contours, component depth)
+#[cfg(feature = "std")]
+type Flattened = (u16, u16, u16);
+
+/// The progress of flattening a glyph.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+enum FlattenState {
+    Pending,
+    InProgress,
+    Done(Flattened),
+    Cyclic,
+}
+
+/// Computes limits for glyphs, caching the flattened sizes of composites.
+#[cfg(feature = "std")]
+struct LimitComputer<'a, 'b> {
+    glyf: &'b Glyf<'a>,
+    loca: &'b Loca<'a>,
+    flattened: Vec<FlattenState>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, 'b> LimitComputer<'a, 'b> {
+    fn new(glyf: &'b Glyf<'a>, loca: &'b Loca<'a>, num_glyphs: u16) -> Self {
+        LimitComputer {
+            glyf,
+            loca,
+            flattened: vec![FlattenState::Pending; num_glyphs as usize],
+        }
+    }
+
+    fn get_glyph(&self, gid: u16) -> Option<Glyph<'a>> {
+        let range = self.loca.glyph_range(gid)?;
+        if range.is_empty() {
+            return None;
+        }
+        self.glyf.resolve_glyph(Offset32::new(range.start as u32))
+    }
+
+    fn glyph_limits(&mut self, gid: u16) -> GlyphLimits {
+        let mut result = GlyphLimits::default();
+        match self.get_glyph(gid) {
+            Some(Glyph::Simple(glyph)) => {
+                result.max_points = simple_glyph_points(&glyph);
+                result.max_contours = glyph.end_pts_of_contours().len() as u16;
+                result.max_size_of_instructions = glyph.instruction_length();
+            }
+            Some(Glyph::Composite(glyph)) => {
+                let (points, contours, depth) = self.flattened(gid, 0).unwrap_or_default();
+                result.max_composite_points = points;
+                result.max_composite_contours = contours;
+                result.max_component_depth = depth;
+                result.max_component_elements = glyph.components().count() as u16;
+                result.max_size_of_instructions =
+                    glyph.instructions().map(|x| x.len() as u16).unwrap_or(0);
+            }
+            None => (),
+        }
+        result
+    }
+
+    /// Whether this glyph was found to be a cyclic composite.
+    ///
+    /// This is only known once the glyph's limits have been computed.
+    fn is_cyclic(&self, gid: u16) -> bool {
+        matches!(self.flattened.get(gid as usize), Some(FlattenState::Cyclic))
+    }
+
+    /// Compute the number of points & contours in the glyph, after resolving
+    /// all components, as well as the depth of the component tree.
+    ///
+    /// Returns `None` if the glyph is cyclic.
+    fn flattened(&mut self, gid: u16, depth: u16) -> Option<Flattened> {
+        if depth > MAX_COMPONENT_DEPTH {
+            return None;
+        }
+        match self.flattened.get(gid as usize).copied() {
+            Some(FlattenState::Done(cached)) => return Some(cached),
+            Some(FlattenState::InProgress | FlattenState::Cyclic) => return None,
+            Some(FlattenState::Pending) => self.flattened[gid as usize] = FlattenState::InProgress,
+            None => (),
+        }
+        let result = self.flatten_glyph(gid, depth);
+        if let Some(slot) = self.flattened.get_mut(gid as usize) {
+            *slot = match result {
+                Some(result) => FlattenState::Done(result),
+                None => FlattenState::Cyclic,
+            };
+        }
+        result
+    }
+
+    fn flatten_glyph(&mut self, gid: u16, depth: u16) -> Option<Flattened> {
+        let result = match self.get_glyph(gid) {
+            Some(Glyph::Simple(glyph)) => (
+                simple_glyph_points(&glyph),
+                glyph.end_pts_of_contours().len() as u16,
+                0,
+            ),
+            Some(Glyph::Composite(glyph)) => {
+                let mut result = (0u16, 0u16, 1u16);
+                for component in glyph.components() {
+                    let (points, contours, child_depth) =
+                        self.flattened(component.glyph, depth + 1)?;
+                    result.0 = result.0.saturating_add(points);
+                    result.1 = result.1.saturating_add(contours);
+                    result.2 = result.2.max(child_depth + 1);
+                }
+                result
+            }
+            None => (0, 0, 0),
+        };
+        Some(result)
+    }
+}
+
+#[cfg(feature = "std")]
+fn simple_glyph_points(glyph: &super::glyf::SimpleGlyph) -> u16 {
+    glyph
+        .end_pts_of_contours()
+        .last()
+        .map(|last| last.get().saturating_add(1))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead, Version16Dot16};
+
+    use super::*;
+    use crate::tables::loca::LocaFormat;
+
+    fn make_glyf() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        // glyph 0: two contours, 4 points, 2 bytes of instructions
+        buf.extend([2i16, 0, 0, 10, 10]);
+        buf.extend([1u16, 3]);
+        buf.push(2u16);
+        buf.extend([0u8, 0]);
+        buf.extend([0x31u8; 4]); // on curve, x & y same
+                                 // padding
+        buf.extend([0u8, 0]);
+        // glyph 1: composite of glyph 0 twice
+        buf.extend([-1i16, 0, 0, 10, 10]);
+        buf.extend([0x0022u16, 0]); // ARGS_ARE_XY_VALUES | MORE_COMPONENTS
+        buf.extend([0u8, 0]);
+        buf.extend([0x0002u16, 0]);
+        buf.extend([5u8, 5]);
+        // glyph 2: composite of glyph 1
+        buf.extend([-1i16, 0, 0, 10, 10]);
+        buf.extend([0x0002u16, 1]);
+        buf.extend([0u8, 0]);
+        buf
+    }
+
+    fn make_maxp(limits: [u16; 13]) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.push(Version16Dot16::VERSION_1_0);
+        buf.push(3u16);
+        buf.extend(limits);
+        buf
+    }
+
+    #[test]
+    fn compute_limits() {
+        let glyf_data = make_glyf();
+        let glyf = Glyf::read(&glyf_data).unwrap();
+        let mut loca_data = BeBuffer::new();
+        loca_data.extend([0u32, 24, 46, 62]);
+        let loca = Loca::read(&loca_data, 3, LocaFormat::Long).unwrap();
+
+        let limits = GlyphLimits::compute(&glyf, &loca, 3);
+        assert_eq!(
+            limits,
+            GlyphLimits {
+                max_points: 4,
+                max_contours: 2,
+                max_composite_points: 8,
+                max_composite_contours: 4,
+                max_size_of_instructions: 2,
+                max_component_elements: 2,
+                max_component_depth: 2,
+            }
+        );
+
+        let maxp_data = make_maxp([4, 2, 8, 4, 2, 0, 0, 0, 0, 0, 2, 2, 1]);
+        let maxp = Maxp::read(&maxp_data).unwrap();
+        let errors = maxp.validate_glyphs(&glyf, &loca);
+        assert_eq!(
+            errors,
+            [GlyphError::LimitExceeded(LimitExceeded {
+                glyph_id: 2,
+                limit: GlyphLimit::ComponentDepth,
+                declared: 1,
+                actual: 2,
+            })]
+        );
+    }
+
+    #[test]
+    fn cyclic_composites() {
+        let mut glyf_data = BeBuffer::new();
+        // glyph 0: composite of glyph 1
+        glyf_data.extend([-1i16, 0, 0, 10, 10]);
+        glyf_data.extend([0x0002u16, 1]);
+        glyf_data.extend([0u8, 0]);
+        // glyph 1: composite of glyph 0
+        glyf_data.extend([-1i16, 0, 0, 10, 10]);
+        glyf_data.extend([0x0002u16, 0]);
+        glyf_data.extend([0u8, 0]);
+        // glyph 2: composite of glyph 1
+        glyf_data.extend([-1i16, 0, 0, 10, 10]);
+        glyf_data.extend([0x0002u16, 1]);
+        glyf_data.extend([0u8, 0]);
+        let glyf = Glyf::read(&glyf_data).unwrap();
+        let mut loca_data = BeBuffer::new();
+        loca_data.extend([0u32, 16, 32, 48]);
+        let loca = Loca::read(&loca_data, 3, LocaFormat::Long).unwrap();
+
+        let maxp_data = make_maxp([0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1, 1]);
+        let maxp = Maxp::read(&maxp_data).unwrap();
+        let errors = maxp.validate_glyphs(&glyf, &loca);
+        assert_eq!(
+            errors,
+            [
+                GlyphError::CyclicComposite { glyph_id: 0 },
+                GlyphError::CyclicComposite { glyph_id: 1 },
+                GlyphError::CyclicComposite { glyph_id: 2 },
+            ]
+        );
+    }
+}
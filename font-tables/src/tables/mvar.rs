@@ -0,0 +1,146 @@
+//! The [MVAR (Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar) table
+
+#[path = "../../generated/generated_mvar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{F2Dot14, FontRead, OffsetHost, Tag};
+
+use crate::variations::ItemVariationStore;
+
+/// 'MVAR'
+pub const TAG: Tag = Tag::new(b"MVAR");
+
+/// The length of the header, which is followed by the value records.
+const HEADER_LEN: usize = 12;
+
+/// Horizontal ascender; `OS/2.sTypoAscender`, or `hhea.ascender`.
+pub const HORIZONTAL_ASCENDER: Tag = Tag::new(b"hasc");
+/// Horizontal descender; `OS/2.sTypoDescender`, or `hhea.descender`.
+pub const HORIZONTAL_DESCENDER: Tag = Tag::new(b"hdsc");
+/// Horizontal line gap; `OS/2.sTypoLineGap`, or `hhea.lineGap`.
+pub const HORIZONTAL_LINE_GAP: Tag = Tag::new(b"hlgp");
+/// Horizontal clipping ascent; `OS/2.usWinAscent`.
+pub const HORIZONTAL_CLIPPING_ASCENT: Tag = Tag::new(b"hcla");
+/// Horizontal clipping descent; `OS/2.usWinDescent`.
+pub const HORIZONTAL_CLIPPING_DESCENT: Tag = Tag::new(b"hcld");
+/// Vertical ascender; `vhea.ascent`.
+pub const VERTICAL_ASCENDER: Tag = Tag::new(b"vasc");
+/// Vertical descender; `vhea.descent`.
+pub const VERTICAL_DESCENDER: Tag = Tag::new(b"vdsc");
+/// Vertical line gap; `vhea.lineGap`.
+pub const VERTICAL_LINE_GAP: Tag = Tag::new(b"vlgp");
+/// Horizontal caret rise; `hhea.caretSlopeRise`.
+pub const HORIZONTAL_CARET_RISE: Tag = Tag::new(b"hcrs");
+/// Horizontal caret run; `hhea.caretSlopeRun`.
+pub const HORIZONTAL_CARET_RUN: Tag = Tag::new(b"hcrn");
+/// Horizontal caret offset; `hhea.caretOffset`.
+pub const HORIZONTAL_CARET_OFFSET: Tag = Tag::new(b"hcof");
+/// Vertical caret rise; `vhea.caretSlopeRise`.
+pub const VERTICAL_CARET_RISE: Tag = Tag::new(b"vcrs");
+/// Vertical caret run; `vhea.caretSlopeRun`.
+pub const VERTICAL_CARET_RUN: Tag = Tag::new(b"vcrn");
+/// Vertical caret offset; `vhea.caretOffset`.
+pub const VERTICAL_CARET_OFFSET: Tag = Tag::new(b"vcof");
+/// X height; `OS/2.sxHeight`.
+pub const X_HEIGHT: Tag = Tag::new(b"xhgt");
+/// Cap height; `OS/2.sCapHeight`.
+pub const CAP_HEIGHT: Tag = Tag::new(b"cpht");
+/// Subscript em x size; `OS/2.ySubscriptXSize`.
+pub const SUBSCRIPT_EM_X_SIZE: Tag = Tag::new(b"sbxs");
+/// Subscript em y size; `OS/2.ySubscriptYSize`.
+pub const SUBSCRIPT_EM_Y_SIZE: Tag = Tag::new(b"sbys");
+/// Subscript em x offset; `OS/2.ySubscriptXOffset`.
+pub const SUBSCRIPT_EM_X_OFFSET: Tag = Tag::new(b"sbxo");
+/// Subscript em y offset; `OS/2.ySubscriptYOffset`.
+pub const SUBSCRIPT_EM_Y_OFFSET: Tag = Tag::new(b"sbyo");
+/// Superscript em x size; `OS/2.ySuperscriptXSize`.
+pub const SUPERSCRIPT_EM_X_SIZE: Tag = Tag::new(b"spxs");
+/// Superscript em y size; `OS/2.ySuperscriptYSize`.
+pub const SUPERSCRIPT_EM_Y_SIZE: Tag = Tag::new(b"spys");
+/// Superscript em x offset; `OS/2.ySuperscriptXOffset`.
+pub const SUPERSCRIPT_EM_X_OFFSET: Tag = Tag::new(b"spxo");
+/// Superscript em y offset; `OS/2.ySuperscriptYOffset`.
+pub const SUPERSCRIPT_EM_Y_OFFSET: Tag = Tag::new(b"spyo");
+/// Strikeout size; `OS/2.yStrikeoutSize`.
+pub const STRIKEOUT_SIZE: Tag = Tag::new(b"strs");
+/// Strikeout offset; `OS/2.yStrikeoutPosition`.
+pub const STRIKEOUT_OFFSET: Tag = Tag::new(b"stro");
+/// Underline size; `post.underlineThickness`.
+pub const UNDERLINE_SIZE: Tag = Tag::new(b"unds");
+/// Underline offset; `post.underlinePosition`.
+pub const UNDERLINE_OFFSET: Tag = Tag::new(b"undo");
+
+impl<'a> Mvar<'a> {
+    pub fn item_variation_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.resolve_offset(self.item_variation_store_offset())
+    }
+
+    /// The value records, sorted by tag.
+    pub fn value_records(&self) -> impl Iterator<Item = ValueRecord> + '_ {
+        (0..self.value_record_count()).filter_map(|idx| self.value_record(idx))
+    }
+
+    /// Return the value record at this index.
+    pub fn value_record(&self, index: u16) -> Option<ValueRecord> {
+        let record_len = self.value_record_size() as usize;
+        let start = HEADER_LEN + index as usize * record_len;
+        self.bytes()
+            .get(start..start + record_len)
+            .and_then(ValueRecord::read)
+    }
+
+    /// The delta for the metric with this tag, at a location in design
+    /// space.
+    ///
+    /// Returns `None` if the metric does not vary.
+    pub fn delta(&self, tag: Tag, coords: &[F2Dot14]) -> Option<f32> {
+        let (mut lo, mut hi) = (0, self.value_record_count());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let record = self.value_record(mid)?;
+            match record.value_tag().cmp(&tag) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => {
+                    return self.item_variation_store()?.compute_delta(
+                        record.delta_set_outer_index(),
+                        record.delta_set_inner_index(),
+                        coords,
+                    )
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::*;
+    use crate::variations::test_helpers::{f2dot14, var_store};
+
+    #[test]
+    fn deltas() {
+        let mut buf = BeBuffer::new();
+        // three records of 10 bytes, padded, and a var store at 42
+        buf.extend([1u16, 0, 0, 10, 3, 42]);
+        for (tag, inner) in [(b"hasc", 0u16), (b"undo", 1), (b"xhgt", 2)] {
+            buf.push(Tag::new(tag));
+            buf.extend([0u16, inner, 0]);
+        }
+        // var store: one region, peaking at wght = 1.0
+        buf.extend(var_store(&[(0.0, 1.0, 1.0)], &[40, -20, 30]));
+
+        let mvar = Mvar::read(&buf).unwrap();
+        assert_eq!(mvar.value_records().count(), 3);
+        let coords = [f2dot14(0.5)];
+        assert_eq!(mvar.delta(HORIZONTAL_ASCENDER, &coords), Some(20.0));
+        assert_eq!(mvar.delta(UNDERLINE_OFFSET, &coords), Some(-10.0));
+        assert_eq!(mvar.delta(X_HEIGHT, &[f2dot14(1.0)]), Some(30.0));
+        assert_eq!(mvar.delta(CAP_HEIGHT, &coords), None);
+    }
+}
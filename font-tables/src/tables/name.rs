@@ -23,8 +23,34 @@ impl<'a> Name<'a> {
         let encoding = encoding(name.platform_id(), name.encoding_id());
         Some(Entry { data, encoding })
     }
+
+    /// Return the preferred entry for this name id, if one exists.
+    ///
+    /// English (US) Windows entries are preferred, followed by any other
+    /// Windows or Unicode entries, and then Macintosh entries.
+    pub fn name_for_id(&self, name_id: u16) -> Option<Entry<'a>> {
+        let record = self
+            .name_record()
+            .iter()
+            .filter(|rec| rec.name_id() == name_id)
+            .filter(|rec| {
+                !matches!(
+                    encoding(rec.platform_id(), rec.encoding_id()),
+                    Encoding::Unknown
+                )
+            })
+            .min_by_key(|rec| match (rec.platform_id(), rec.language_id()) {
+                (3, ENGLISH_US) => 0,
+                (0, _) | (3, _) => 1,
+                _ => 2,
+            })?;
+        self.resolve(record)
+    }
 }
 
+/// The Windows language id for English (United States).
+const ENGLISH_US: u16 = 0x0409;
+
 //-- all this is from pinot https://github.com/dfrg/pinot/blob/eff5239018ca50290fb890a84da3dd51505da364/src/name.rs
 /// Entry for a name in the naming table.
 ///
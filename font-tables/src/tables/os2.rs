@@ -0,0 +1,73 @@
+//! The [OS/2 (OS/2 and Windows Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/os2) table
+
+#[path = "../../generated/generated_os2.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{BigEndian, FontRead, OffsetHost, Scalar, Tag};
+
+/// 'OS/2'
+pub const TAG: Tag = Tag::new(b"OS/2");
+
+impl<'a> Os2<'a> {
+    /// Read a field that was added in a later version of the table.
+    fn versioned_field<T: Scalar>(&self, min_version: u16, offset: usize) -> Option<T> {
+        if self.version() < min_version {
+            return None;
+        }
+        BigEndian::<T>::read(self.bytes().get(offset..)?).map(|val| val.get())
+    }
+
+    /// Code Page Character Range (bits 0–31), in version 1 and later.
+    pub fn ul_code_page_range_1(&self) -> Option<u32> {
+        self.versioned_field(1, 78)
+    }
+
+    /// Code Page Character Range (bits 32–63), in version 1 and later.
+    pub fn ul_code_page_range_2(&self) -> Option<u32> {
+        self.versioned_field(1, 82)
+    }
+
+    /// The distance between the baseline and the approximate height of
+    /// non-ascending lowercase letters, in version 2 and later.
+    pub fn sx_height(&self) -> Option<i16> {
+        self.versioned_field(2, 86)
+    }
+
+    /// The distance between the baseline and the approximate height of
+    /// uppercase letters, in version 2 and later.
+    pub fn s_cap_height(&self) -> Option<i16> {
+        self.versioned_field(2, 88)
+    }
+
+    /// The Unicode code point of the default character, in version 2 and
+    /// later.
+    pub fn us_default_char(&self) -> Option<u16> {
+        self.versioned_field(2, 90)
+    }
+
+    /// The Unicode code point of the break character, in version 2 and
+    /// later.
+    pub fn us_break_char(&self) -> Option<u16> {
+        self.versioned_field(2, 92)
+    }
+
+    /// The maximum length of a target glyph context for any feature in
+    /// this font, in version 2 and later.
+    pub fn us_max_context(&self) -> Option<u16> {
+        self.versioned_field(2, 94)
+    }
+
+    /// The lower end of the size range for which this font has been
+    /// designed, in twentieths of a point, in version 5.
+    pub fn us_lower_optical_point_size(&self) -> Option<u16> {
+        self.versioned_field(5, 96)
+    }
+
+    /// The upper end of the size range for which this font has been
+    /// designed, in twentieths of a point, in version 5.
+    pub fn us_upper_optical_point_size(&self) -> Option<u16> {
+        self.versioned_field(5, 98)
+    }
+}
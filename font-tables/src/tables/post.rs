@@ -16,10 +16,15 @@ impl<'a> Post<'a> {
         match self {
             Post::Post1_0(_) => DEFAULT_GLYPH_NAMES.len(),
             Post::Post2_0(table) => table.glyph_name_index().len() as usize,
+            Post::Post2_5(table) => table.offset().len(),
             _ => 0,
         }
     }
 
+    /// The name of the glyph with the given id, if one exists.
+    ///
+    /// For version 2.0 tables this has to scan the string data on each call;
+    /// if you are looking up many names, use [`Post::names`] instead.
     pub fn glyph_name(&self, glyph_id: u16) -> Option<&str> {
         match self {
             Post::Post1_0(_table) => DEFAULT_GLYPH_NAMES.get(glyph_id as usize).copied(),
@@ -29,17 +34,126 @@ impl<'a> Post<'a> {
                     return Some(name);
                 }
                 let idx = idx - DEFAULT_GLYPH_NAMES.len();
-                let mut offset = 0;
-                for _ in 0..idx {
-                    offset += table.string_data().get(offset).copied().unwrap_or(0) as usize + 1;
-                }
-                let len = table.string_data().get(offset).copied().unwrap_or(0) as usize;
-                let bytes = table.string_data().get(offset + 1..offset + 1 + len)?;
-                std::str::from_utf8(bytes).ok()
+                iter_pascal_strings(table.string_data()).nth(idx)?
             }
+            Post::Post2_5(table) => table.standard_name(glyph_id),
             _ => None,
         }
     }
+
+    /// Build an index of all the glyph names in this table.
+    ///
+    /// This does the work of decoding the names once, up front, so that
+    /// subsequent lookups (in either direction) are cheap.
+    #[cfg(feature = "std")]
+    pub fn names(&self) -> PostNames<'_> {
+        PostNames::new(self)
+    }
+}
+
+impl<'a> Post2_5<'a> {
+    fn standard_name(&self, glyph_id: u16) -> Option<&'static str> {
+        let offset = self.offset().get(glyph_id as usize)?.get();
+        let idx = (glyph_id as i32).checked_add(offset as i32)?;
+        DEFAULT_GLYPH_NAMES.get(usize::try_from(idx).ok()?).copied()
+    }
+}
+
+impl<'a> Post4_0<'a> {
+    /// The character code for a given glyph, if it has one.
+    pub fn char_code(&self, glyph_id: u16) -> Option<u16> {
+        self.char_codes()
+            .get(glyph_id as usize)
+            .map(|code| code.get())
+            .filter(|code| *code != 0xFFFF)
+    }
+}
+
+/// Iterate the pascal strings in the `stringData` of a version 2.0 table.
+///
+/// Strings that are not valid utf-8 are returned as `None`.
+fn iter_pascal_strings(mut data: &[u8]) -> impl Iterator<Item = Option<&str>> {
+    std::iter::from_fn(move || {
+        let (len, rest) = data.split_first()?;
+        let (bytes, rest) = rest.split_at((*len as usize).min(rest.len()));
+        data = rest;
+        Some(std::str::from_utf8(bytes).ok())
+    })
+}
+
+/// A precomputed index of the glyph names in a [`Post`] table.
+///
+/// This supports constant-time lookup of a glyph's name, as well as of the
+/// glyph id for a given name.
+#[cfg(feature = "std")]
+pub struct PostNames<'a> {
+    names: Vec<Option<&'a str>>,
+    ids: std::collections::HashMap<&'a str, u16>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> PostNames<'a> {
+    /// Build the index for a given table.
+    pub fn new(post: &'a Post) -> Self {
+        let names: Vec<_> = match post {
+            Post::Post1_0(_) => DEFAULT_GLYPH_NAMES.iter().copied().map(Some).collect(),
+            Post::Post2_0(table) => {
+                let custom_names = iter_pascal_strings(table.string_data()).collect::<Vec<_>>();
+                table
+                    .glyph_name_index()
+                    .iter()
+                    .map(|idx| {
+                        let idx = idx.get() as usize;
+                        match idx.checked_sub(DEFAULT_GLYPH_NAMES.len()) {
+                            None => Some(DEFAULT_GLYPH_NAMES[idx]),
+                            Some(idx) => custom_names.get(idx).copied().flatten(),
+                        }
+                    })
+                    .collect()
+            }
+            Post::Post2_5(table) => (0..table.offset().len())
+                .map(|gid| table.standard_name(gid as u16))
+                .collect(),
+            Post::Post3_0(_) | Post::Post4_0(_) => Vec::new(),
+        };
+
+        let mut ids = std::collections::HashMap::with_capacity(names.len());
+        for (gid, name) in names.iter().enumerate() {
+            if let Some(name) = name {
+                // if a name is duplicated, the first glyph wins
+                ids.entry(*name).or_insert(gid as u16);
+            }
+        }
+        PostNames { names, ids }
+    }
+
+    /// The number of glyphs in the index.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if the table contains no glyph names.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The name of the glyph with the given id.
+    pub fn glyph_name(&self, glyph_id: u16) -> Option<&'a str> {
+        self.names.get(glyph_id as usize).copied().flatten()
+    }
+
+    /// The id of the glyph with the given name.
+    pub fn glyph_id(&self, name: &str) -> Option<u16> {
+        self.ids.get(name).copied()
+    }
+
+    /// Iterate over all `(glyph_id, name)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &'a str)> + '_ {
+        self.names
+            .iter()
+            .enumerate()
+            .filter_map(|(gid, name)| name.map(|name| (gid as u16, name)))
+    }
 }
 
 #[rustfmt::skip]
@@ -74,3 +188,75 @@ const DEFAULT_GLYPH_NAMES: [&str; 258] = [
     "onequarter", "threequarters", "franc", "Gbreve", "gbreve", "Idotaccent", "Scedilla",
     "scedilla", "Cacute", "cacute", "Ccaron", "ccaron", "dcroat",
 ];
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, Fixed, FontRead, Version16Dot16};
+
+    use super::*;
+
+    fn push_header(buf: &mut BeBuffer, version: Version16Dot16) {
+        buf.push(version);
+        buf.push(Fixed::from_f64(-12.5));
+        buf.extend([-75i16, 50]);
+        buf.extend([0u32, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn version_2_names() {
+        let mut buf = BeBuffer::new();
+        push_header(&mut buf, Version16Dot16::VERSION_2_0);
+        buf.push(5u16);
+        buf.extend([0u16, 259, 68, 258, 259]);
+        for name in ["a.alt", "b.swsh"] {
+            buf.push(name.len() as u8);
+            buf.extend(name.bytes());
+        }
+
+        let post = Post::read(&buf).unwrap();
+        assert_eq!(post.num_names(), 5);
+        assert_eq!(post.glyph_name(1), Some("b.swsh"));
+        assert_eq!(post.glyph_name(3), Some("a.alt"));
+
+        let names = post.names();
+        assert_eq!(names.len(), 5);
+        assert_eq!(names.glyph_name(0), Some(".notdef"));
+        assert_eq!(names.glyph_name(2), Some("a"));
+        assert_eq!(names.glyph_name(3), Some("a.alt"));
+        assert_eq!(names.glyph_name(5), None);
+        assert_eq!(names.glyph_id("b.swsh"), Some(1));
+        assert_eq!(names.glyph_id("a"), Some(2));
+        assert_eq!(names.glyph_id("b"), None);
+    }
+
+    #[test]
+    fn version_2_5_names() {
+        let mut buf = BeBuffer::new();
+        push_header(&mut buf, Version16Dot16::VERSION_2_5);
+        buf.push(4u16);
+        // .notdef, space, A, B
+        buf.extend([0i8, 2, 34, 34]);
+
+        let post = Post::read(&buf).unwrap();
+        assert_eq!(post.num_names(), 4);
+        assert_eq!(post.glyph_name(1), Some("space"));
+        let names = post.names();
+        assert_eq!(names.glyph_name(3), Some("B"));
+        assert_eq!(names.glyph_id("A"), Some(2));
+    }
+
+    #[test]
+    fn version_4_char_codes() {
+        let mut buf = BeBuffer::new();
+        push_header(&mut buf, Version16Dot16::VERSION_4_0);
+        buf.extend([0xFFFFu16, 0x20, 0x41]);
+
+        let post = Post::read(&buf).unwrap();
+        let Post::Post4_0(table) = &post else {
+            panic!("wrong version");
+        };
+        assert_eq!(table.char_code(0), None);
+        assert_eq!(table.char_code(2), Some(0x41));
+        assert_eq!(post.glyph_name(2), None);
+    }
+}
@@ -7,9 +7,13 @@ mod generated;
 
 pub use generated::*;
 
+#[cfg(feature = "std")]
+use font_types::Fixed;
 use font_types::{BigEndian, FontRead, Offset, Offset16, OffsetHost, Tag};
 use zerocopy::LayoutVerified;
 
+use super::name::{Entry, Name};
+
 /// 'STAT'
 pub const TAG: Tag = Tag::new(b"STAT");
 
@@ -53,11 +57,212 @@ impl<'a> Stat<'a> {
                 .and_then(|off| bytes.get(off..).and_then(AxisValue::read))
         })
     }
+
+    /// The name to use when every axis value in a style name is elided,
+    /// such as "Regular".
+    ///
+    /// Tables before version 1.1 don't specify this, and use the font's
+    /// subfamily name (name id 2).
+    pub fn elided_fallback_name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.elided_fallback_name_id().unwrap_or(SUBFAMILY_NAME_ID))
+    }
+}
+
+/// The name id of the font subfamily name.
+const SUBFAMILY_NAME_ID: u16 = 2;
+
+#[cfg(feature = "std")]
+impl<'a> Stat<'a> {
+    /// The axis values that name a position in the design space.
+    ///
+    /// `position` gives a user coordinate for each axis, by tag; axes without
+    /// a coordinate are not matched. Format 4 values take precedence over
+    /// single-axis values for the axes they cover, and a format 2 value
+    /// is only used if no value matches the coordinate exactly.
+    ///
+    /// Values flagged as elidable are omitted, as are values that describe
+    /// older sibling fonts. The result is sorted by axis ordering.
+    pub fn axis_values_at(&self, position: &[(Tag, Fixed)]) -> Vec<AxisValue<'a>> {
+        let axes = self.design_axes().collect::<Vec<_>>();
+        let coord = |axis_index: u16| {
+            let tag = axes.get(axis_index as usize)?.axis_tag();
+            position
+                .iter()
+                .rev()
+                .find(|(axis_tag, _)| *axis_tag == tag)
+                .map(|(_, value)| *value)
+        };
+        let values = self
+            .iter_axis_value_tables()
+            .filter(|value| {
+                !value
+                    .flags()
+                    .contains(AxisValueFlags::OLDER_SIBLING_FONT_ATTRIBUTE)
+            })
+            .collect::<Vec<_>>();
+
+        let mut covered = vec![false; axes.len()];
+        // indices into `values`
+        let mut matched = Vec::new();
+        // multi-axis values that cover more axes are preferred
+        let mut combinations = values
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, value)| match value {
+                AxisValue::Format4(table) => Some((idx, table.axis_values())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        combinations.sort_by_key(|(_, records)| std::cmp::Reverse(records.len()));
+        for (value_idx, records) in combinations {
+            let is_match = records.iter().all(|record| {
+                let idx = record.axis_index() as usize;
+                !covered.get(idx).copied().unwrap_or(true)
+                    && coord(record.axis_index()) == Some(record.value())
+            });
+            if is_match {
+                records
+                    .iter()
+                    .for_each(|record| covered[record.axis_index() as usize] = true);
+                matched.push(value_idx);
+            }
+        }
+
+        for axis_index in 0..axes.len() as u16 {
+            if covered[axis_index as usize] {
+                continue;
+            }
+            let coord = match coord(axis_index) {
+                Some(coord) => coord,
+                None => continue,
+            };
+            // the distance from an exact match, for values on this axis
+            let best = values
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, value)| {
+                    let distance = match value {
+                        AxisValue::Format1(table) if table.axis_index() == axis_index => {
+                            (table.value() == coord).then_some(0.0)?
+                        }
+                        AxisValue::Format3(table) if table.axis_index() == axis_index => {
+                            (table.value() == coord).then_some(0.0)?
+                        }
+                        AxisValue::Format2(table) if table.axis_index() == axis_index => {
+                            if coord < table.range_min_value() || coord > table.range_max_value() {
+                                return None;
+                            }
+                            // the difference can overflow Fixed, so compute it in f64;
+                            // ranged values never beat an exact match
+                            let distance = coord.to_f64() - table.nominal_value().to_f64();
+                            distance.abs().max(1.0 / 65536.0)
+                        }
+                        _ => return None,
+                    };
+                    Some((distance, idx))
+                })
+                .min_by(|(a, _), (b, _)| a.total_cmp(b));
+            if let Some((_, value_idx)) = best {
+                matched.push(value_idx);
+            }
+        }
+
+        let ordering = |value: &AxisValue| {
+            value
+                .axis_indices()
+                .filter_map(|idx| axes.get(idx as usize))
+                .map(|axis| axis.axis_ordering())
+                .min()
+                .unwrap_or(u16::MAX)
+        };
+        matched.retain(|idx| !values[*idx].is_elidable());
+        matched.sort_by_key(|idx| ordering(&values[*idx]));
+        let mut values = values.into_iter().map(Some).collect::<Vec<_>>();
+        matched
+            .into_iter()
+            .filter_map(|idx| values[idx].take())
+            .collect()
+    }
+
+    /// Compose a style name, such as "Bold Condensed Italic", for a position
+    /// in the design space.
+    ///
+    /// This joins the names of the values returned by
+    /// [`axis_values_at`][Self::axis_values_at]. If they are all elided,
+    /// the elided fallback name is used.
+    pub fn style_name(&self, position: &[(Tag, Fixed)], name: &Name) -> Option<String> {
+        let names = self
+            .axis_values_at(position)
+            .iter()
+            .map(|value| value.name(name).map(|entry| entry.to_string()))
+            .collect::<Option<Vec<_>>>()?;
+        if names.is_empty() {
+            return self
+                .elided_fallback_name(name)
+                .map(|entry| entry.to_string());
+        }
+        Some(names.join(" "))
+    }
+}
+
+impl AxisRecord {
+    /// The display name of this axis.
+    pub fn name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.axis_name_id())
+    }
+}
+
+impl<'a> AxisValue<'a> {
+    pub fn flags(&self) -> AxisValueFlags {
+        match self {
+            Self::Format1(table) => table.flags(),
+            Self::Format2(table) => table.flags(),
+            Self::Format3(table) => table.flags(),
+            Self::Format4(table) => table.flags(),
+        }
+    }
+
+    pub fn value_name_id(&self) -> u16 {
+        match self {
+            Self::Format1(table) => table.value_name_id(),
+            Self::Format2(table) => table.value_name_id(),
+            Self::Format3(table) => table.value_name_id(),
+            Self::Format4(table) => table.value_name_id(),
+        }
+    }
+
+    /// Returns `true` if this is a "normal" value, which may be omitted
+    /// from style names.
+    pub fn is_elidable(&self) -> bool {
+        self.flags()
+            .contains(AxisValueFlags::ELIDABLE_AXIS_VALUE_NAME)
+    }
+
+    /// The indices of the design axes that this value applies to.
+    pub fn axis_indices(&self) -> impl Iterator<Item = u16> + '_ {
+        let (single, multiple) = match self {
+            Self::Format1(table) => (Some(table.axis_index()), None),
+            Self::Format2(table) => (Some(table.axis_index()), None),
+            Self::Format3(table) => (Some(table.axis_index()), None),
+            Self::Format4(table) => (None, Some(table.axis_values())),
+        };
+        single.into_iter().chain(
+            multiple
+                .into_iter()
+                .flatten()
+                .map(|record| record.axis_index()),
+        )
+    }
+
+    /// The display name of this value, such as "Bold".
+    pub fn name<'n>(&self, name: &Name<'n>) -> Option<Entry<'n>> {
+        name.name_for_id(self.value_name_id())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use font_types::{Fixed, Offset32};
+    use font_types::{test_helpers::BeBuffer, Fixed, Offset32};
 
     use super::*;
 
@@ -118,4 +323,178 @@ mod tests {
         assert_eq!(value2.value_name_id(), 7);
         assert_eq!(value2.linked_value(), Fixed::from_f64(108.));
     }
+
+    const NAMES: &[(u16, &str)] = &[
+        (2, "Regular"),
+        (256, "Weight"),
+        (257, "Width"),
+        (258, "Italic"),
+        (259, "Regular"),
+        (260, "Bold"),
+        (261, "Condensed"),
+        (262, "Normal"),
+        (263, "Roman"),
+        (264, "Black"),
+        (265, "Compressed Bold"),
+    ];
+
+    /// A name table with a Windows English entry for each name.
+    fn make_name() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        let storage_offset = 6 + NAMES.len() * 12;
+        buf.extend([0u16, NAMES.len() as u16, storage_offset as u16]);
+        let mut storage = Vec::new();
+        for (name_id, string) in NAMES {
+            let start = storage.len();
+            storage.extend(string.encode_utf16());
+            buf.extend([3u16, 1, 0x409, *name_id]);
+            buf.extend([(storage.len() - start) as u16 * 2, start as u16 * 2]);
+        }
+        buf.extend(storage);
+        buf
+    }
+
+    fn fixed(val: f64) -> Fixed {
+        Fixed::from_f64(val)
+    }
+
+    fn make_stat() -> BeBuffer {
+        const ELIDABLE: u16 = 2;
+        const OLDER_SIBLING: u16 = 1;
+        let mut values = BeBuffer::new();
+        let mut offsets = Vec::new();
+        let value_count = 8u16;
+        let offsets_len = value_count as usize * 2;
+        let mut format1 = |values: &mut BeBuffer, axis: u16, flags, name_id, value| {
+            offsets.push((offsets_len + values.len()) as u16);
+            values.extend([1u16, axis, flags, name_id]);
+            values.push(fixed(value));
+        };
+        format1(&mut values, 0, ELIDABLE, 259, 400.0);
+        format1(&mut values, 0, 0, 260, 700.0);
+        format1(&mut values, 0, OLDER_SIBLING, 264, 900.0);
+        format1(&mut values, 1, ELIDABLE, 262, 100.0);
+        format1(&mut values, 2, 0, 258, 1.0);
+        // format 2: Condensed
+        offsets.push((offsets_len + values.len()) as u16);
+        values.extend([2u16, 1, 0, 261]);
+        values.extend([fixed(75.0), fixed(50.0), fixed(87.5)]);
+        // format 3: Roman, linked to Italic
+        offsets.push((offsets_len + values.len()) as u16);
+        values.extend([3u16, 2, ELIDABLE, 263]);
+        values.extend([fixed(0.0), fixed(1.0)]);
+        // format 4: Compressed Bold
+        offsets.push((offsets_len + values.len()) as u16);
+        values.extend([4u16, 2, 0, 265]);
+        values.push(0u16);
+        values.push(fixed(700.0));
+        values.push(1u16);
+        values.push(fixed(50.0));
+        assert_eq!(offsets.len(), value_count as usize);
+
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 2, 8, 3]);
+        buf.push(Offset32::new(20));
+        buf.push(value_count);
+        buf.push(Offset32::new(44));
+        buf.push(2u16);
+        // axes, ordered so that italic comes last
+        for (tag, name_id, ordering) in [
+            (b"wght", 256u16, 0u16),
+            (b"wdth", 257, 1),
+            (b"ital", 258, 2),
+        ] {
+            buf.push(Tag::new(tag));
+            buf.extend([name_id, ordering]);
+        }
+        assert_eq!(buf.len(), 44, "sanity check");
+        buf.extend(offsets);
+        buf.extend(values.iter().copied());
+        buf
+    }
+
+    #[test]
+    fn axis_names() {
+        let name_buf = make_name();
+        let name = Name::read(&name_buf).unwrap();
+        let stat_buf = make_stat();
+        let stat = Stat::read(&stat_buf).unwrap();
+        let axis_names = stat
+            .design_axes()
+            .map(|axis| axis.name(&name).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(axis_names, ["Weight", "Width", "Italic"]);
+        let value_names = stat
+            .iter_axis_value_tables()
+            .map(|value| value.name(&name).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            value_names,
+            [
+                "Regular",
+                "Bold",
+                "Black",
+                "Normal",
+                "Italic",
+                "Condensed",
+                "Roman",
+                "Compressed Bold"
+            ]
+        );
+        assert_eq!(
+            stat.elided_fallback_name(&name).unwrap().to_string(),
+            "Regular"
+        );
+    }
+
+    #[test]
+    fn style_names() {
+        let name_buf = make_name();
+        let name = Name::read(&name_buf).unwrap();
+        let stat_buf = make_stat();
+        let stat = Stat::read(&stat_buf).unwrap();
+        let style_name = |wght, wdth, ital| {
+            let position = [
+                (Tag::new(b"ital"), fixed(ital)),
+                (Tag::new(b"wght"), fixed(wght)),
+                (Tag::new(b"wdth"), fixed(wdth)),
+            ];
+            stat.style_name(&position, &name).unwrap()
+        };
+        assert_eq!(style_name(700.0, 75.0, 1.0), "Bold Condensed Italic");
+        // within the range of Condensed
+        assert_eq!(style_name(700.0, 80.0, 0.0), "Bold Condensed");
+        // the format 4 value covers both wght and wdth
+        assert_eq!(style_name(700.0, 50.0, 1.0), "Compressed Bold Italic");
+        // an exact match is preferred to a range
+        assert_eq!(style_name(400.0, 100.0, 1.0), "Italic");
+        assert_eq!(style_name(400.0, 100.0, 0.0), "Regular");
+        // values for older sibling fonts are ignored
+        assert_eq!(style_name(900.0, 100.0, 0.0), "Regular");
+
+        let values = stat.axis_values_at(&[(Tag::new(b"wght"), fixed(700.0))]);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].value_name_id(), 260);
+    }
+
+    #[test]
+    fn ranged_value_full_range() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 2, 8, 1]);
+        buf.push(Offset32::new(20));
+        buf.push(1u16);
+        buf.push(Offset32::new(28));
+        buf.push(2u16);
+        buf.push(Tag::new(b"wght"));
+        buf.extend([256u16, 0]);
+        buf.push(2u16);
+        // format 2, with the nominal value at the bottom of the range
+        buf.extend([2u16, 0, 0, 260]);
+        buf.extend([fixed(-32768.0), fixed(-32768.0), fixed(32767.0)]);
+
+        let stat = Stat::read(&buf).unwrap();
+        let values = stat.axis_values_at(&[(Tag::new(b"wght"), fixed(32767.0))]);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].value_name_id(), 260);
+    }
 }
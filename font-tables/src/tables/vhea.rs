@@ -0,0 +1,10 @@
+//! the [vhea (Vertical Header)](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea) table
+
+#[path = "../../generated/generated_vhea.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::Tag;
+
+pub const TAG: Tag = Tag::new(b"vhea");
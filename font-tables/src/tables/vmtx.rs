@@ -0,0 +1,36 @@
+//! The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+
+#[path = "../../generated/generated_vmtx.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::Tag;
+
+pub const TAG: Tag = Tag::new(b"vmtx");
+
+impl<'a> Vmtx<'a> {
+    /// The advance height of a glyph.
+    ///
+    /// Glyphs without an entry in `v_metrics` share the advance of the last
+    /// entry.
+    pub fn advance(&self, glyph_id: u16) -> Option<u16> {
+        let metrics = self.v_metrics();
+        metrics
+            .get(glyph_id as usize)
+            .or_else(|| metrics.last())
+            .map(|metric| metric.advance_height())
+    }
+
+    /// The top side bearing of a glyph.
+    pub fn tsb(&self, glyph_id: u16) -> Option<i16> {
+        let metrics = self.v_metrics();
+        match metrics.get(glyph_id as usize) {
+            Some(metric) => Some(metric.tsb()),
+            None => self
+                .top_side_bearings()
+                .get(glyph_id as usize - metrics.len())
+                .map(|tsb| tsb.get()),
+        }
+    }
+}
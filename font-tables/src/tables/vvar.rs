@@ -0,0 +1,138 @@
+//! The [VVAR (Vertical Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
+
+#[path = "../../generated/generated_vvar.rs"]
+mod generated;
+
+pub use generated::*;
+
+use font_types::{F2Dot14, OffsetHost, Tag};
+
+use crate::variations::{DeltaSetIndexMap, ItemVariationStore};
+
+/// 'VVAR'
+pub const TAG: Tag = Tag::new(b"VVAR");
+
+impl<'a> Vvar<'a> {
+    pub fn item_variation_store(&self) -> Option<ItemVariationStore<'a>> {
+        self.resolve_offset(self.item_variation_store_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for advance heights.
+    ///
+    /// If this is missing, glyph ids are used as inner indices.
+    pub fn advance_height_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.advance_height_mapping_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for top side bearings.
+    pub fn tsb_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.tsb_mapping_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for bottom side bearings.
+    pub fn bsb_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.bsb_mapping_offset())
+    }
+
+    /// The map from glyph ids to delta-set indices for vertical origins.
+    pub fn v_org_mapping(&self) -> Option<DeltaSetIndexMap<'a>> {
+        self.resolve_offset(self.v_org_mapping_offset())
+    }
+
+    /// The delta for the advance height of a glyph, at a location in design
+    /// space.
+    pub fn advance_height_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        self.item_variation_store()?.compute_mapped_delta(
+            self.advance_height_mapping().as_ref(),
+            glyph_id as u32,
+            coords,
+        )
+    }
+
+    /// The delta for the top side bearing of a glyph, if the font has
+    /// side bearing variations.
+    pub fn tsb_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let map = self.tsb_mapping()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map), glyph_id as u32, coords)
+    }
+
+    /// The delta for the bottom side bearing of a glyph, if the font has
+    /// side bearing variations.
+    pub fn bsb_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let map = self.bsb_mapping()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map), glyph_id as u32, coords)
+    }
+
+    /// The delta for the y coordinate of the vertical origin of a glyph, if
+    /// the font has vertical origin variations.
+    pub fn v_org_delta(&self, glyph_id: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let map = self.v_org_mapping()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map), glyph_id as u32, coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::{test_helpers::BeBuffer, FontRead};
+
+    use super::*;
+    use crate::tables::{
+        test_helpers::{maxp, metrics, metrics_header, TableList},
+        TableProvider,
+    };
+    use crate::variations::test_helpers::{f2dot14, var_store};
+
+    /// A VVAR table with an implicit advance mapping, and mapped side
+    /// bearings.
+    fn vvar() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.extend([24u32, 0, 60, 65, 0]);
+        buf.extend(var_store(&[(0.0, 1.0, 1.0)], &[10, 20, -30]));
+        // top side bearings: every glyph uses row 1
+        buf.extend([0u8, 0x07]);
+        buf.push(1u16);
+        buf.push(1u8);
+        // bottom side bearings: every glyph uses row 2
+        buf.extend([0u8, 0x07]);
+        buf.push(1u16);
+        buf.push(2u8);
+        buf
+    }
+
+    #[test]
+    fn deltas() {
+        let buf = vvar();
+        let vvar = Vvar::read(&buf).unwrap();
+        let coords = [f2dot14(0.5)];
+        assert_eq!(vvar.advance_height_delta(0, &coords), Some(5.0));
+        assert_eq!(vvar.advance_height_delta(2, &coords), Some(-15.0));
+        assert_eq!(vvar.advance_height_delta(3, &coords), None);
+        assert_eq!(vvar.tsb_delta(0, &coords), Some(10.0));
+        assert_eq!(vvar.bsb_delta(4, &[f2dot14(1.0)]), Some(-30.0));
+        assert_eq!(vvar.v_org_delta(0, &coords), None);
+        assert!(vvar.v_org_mapping().is_none());
+    }
+
+    #[test]
+    fn vertical_advance() {
+        // two long metrics; glyph 2 shares the last advance
+        let mut font = TableList(vec![
+            (Tag::new(b"maxp"), maxp(3)),
+            (Tag::new(b"vhea"), metrics_header(2)),
+            (Tag::new(b"vmtx"), metrics(&[(1000, 0), (900, 0)], &[0])),
+        ]);
+        let coords = [f2dot14(0.5)];
+        assert_eq!(font.vertical_advance(0, &coords), Some(1000.0));
+
+        font.0.push((TAG, vvar().to_vec()));
+        assert_eq!(font.vertical_advance(0, &coords), Some(1005.0));
+        assert_eq!(font.vertical_advance(1, &coords), Some(910.0));
+        assert_eq!(font.vertical_advance(2, &coords), Some(885.0));
+        // no delta for this glyph
+        assert_eq!(font.vertical_advance(3, &coords), Some(900.0));
+    }
+}
@@ -0,0 +1,339 @@
+//! [OpenType Font Variations Common Table Formats](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats)
+
+use font_types::{BigEndian, F2Dot14, FontRead, OffsetHost};
+
+#[path = "../generated/generated_variations.rs"]
+mod generated;
+
+pub use generated::*;
+
+impl<'a> ItemVariationStore<'a> {
+    pub fn variation_region_list(&self) -> Option<VariationRegionList<'a>> {
+        self.resolve_offset(self.variation_region_list_offset())
+    }
+
+    /// Return the item variation data subtable at this (outer) index.
+    pub fn item_variation_data(&self, outer_index: u16) -> Option<ItemVariationData<'a>> {
+        self.item_variation_data_offsets()
+            .get(outer_index as usize)
+            .and_then(|off| self.resolve_offset(off.get()))
+    }
+
+    /// Compute the delta for an item, at a location in design space.
+    ///
+    /// The item is identified by an outer index, which selects an item
+    /// variation data subtable, and an inner index, which selects a row in
+    /// that subtable. `coords` are normalized coordinates, in axis order;
+    /// missing coordinates are treated as 0.
+    pub fn compute_delta(
+        &self,
+        outer_index: u16,
+        inner_index: u16,
+        coords: &[F2Dot14],
+    ) -> Option<f32> {
+        let data = self.item_variation_data(outer_index)?;
+        let regions = self.variation_region_list()?;
+        let deltas = data.delta_set(inner_index)?;
+        let delta = data
+            .region_indexes()
+            .iter()
+            .zip(deltas)
+            .map(|(region_idx, delta)| {
+                let scalar = regions.region_scalar(region_idx.get(), coords);
+                delta as f32 * scalar.unwrap_or(0.0)
+            })
+            .sum();
+        Some(delta)
+    }
+
+    /// Compute the delta for an item whose delta-set index is given by a
+    /// [`DeltaSetIndexMap`].
+    ///
+    /// Without a map, the item index is used as the inner index in the
+    /// first item variation data subtable.
+    pub fn compute_mapped_delta(
+        &self,
+        map: Option<&DeltaSetIndexMap>,
+        index: u32,
+        coords: &[F2Dot14],
+    ) -> Option<f32> {
+        let (outer, inner) = match map {
+            Some(map) => map.get(index)?,
+            None => (0, u16::try_from(index).ok()?),
+        };
+        self.compute_delta(outer, inner, coords)
+    }
+}
+
+impl<'a> VariationRegionList<'a> {
+    /// The per-axis coordinates of the region at this index.
+    pub fn region(&self, region_index: u16) -> Option<&[RegionAxisCoordinates]> {
+        let axis_count = self.axis_count() as usize;
+        let start = region_index as usize * axis_count;
+        self.variation_regions().get(start..start + axis_count)
+    }
+
+    /// The scalar for the region at this index, at a location in design
+    /// space.
+    ///
+    /// This is in the range 0.0..=1.0, and is the product of the scalars for
+    /// each axis.
+    pub fn region_scalar(&self, region_index: u16, coords: &[F2Dot14]) -> Option<f32> {
+        let scalar = self
+            .region(region_index)?
+            .iter()
+            .enumerate()
+            .map(|(i, axis)| {
+                let coord = coords.get(i).copied().unwrap_or_default();
+                axis.scalar(coord)
+            })
+            .product();
+        Some(scalar)
+    }
+}
+
+impl RegionAxisCoordinates {
+    /// The scalar for this axis, at this normalized coordinate.
+    pub fn scalar(&self, coord: F2Dot14) -> f32 {
+        axis_scalar(
+            self.start_coord(),
+            self.peak_coord(),
+            self.end_coord(),
+            coord,
+        )
+    }
+}
+
+/// The scalar for one axis of a region, at this normalized coordinate.
+///
+/// This is shared by item variation stores and tuple variations.
+pub(crate) fn axis_scalar(start: F2Dot14, peak: F2Dot14, end: F2Dot14, coord: F2Dot14) -> f32 {
+    let zero = F2Dot14::default();
+    // invalid or zero-peak regions don't constrain this axis
+    if peak == zero || start > peak || peak > end || (start < zero && end > zero) {
+        return 1.0;
+    }
+    if coord == peak {
+        return 1.0;
+    }
+    if coord <= start || coord >= end {
+        return 0.0;
+    }
+    let (coord, start, peak, end) = (coord.to_f32(), start.to_f32(), peak.to_f32(), end.to_f32());
+    if coord < peak {
+        (coord - start) / (peak - start)
+    } else {
+        (end - coord) / (end - peak)
+    }
+}
+
+impl DeltaSetFormat {
+    /// The number of deltas in each row that are stored as "words".
+    pub fn word_count(self) -> u16 {
+        (self & DeltaSetFormat::WORD_DELTA_COUNT_MASK).bits()
+    }
+
+    /// The size in bytes of a delta-set row, with this many regions.
+    pub fn row_byte_len(self, region_index_count: u16) -> usize {
+        let (word_size, short_size) = self.delta_sizes();
+        let word_count = (self.word_count() as usize).min(region_index_count as usize);
+        word_count * word_size + (region_index_count as usize - word_count) * short_size
+    }
+
+    /// The size in bytes of "word" and "short" deltas.
+    ///
+    /// Word deltas are 16 bits and short deltas 8 bits, unless the
+    /// `LONG_WORDS` flag is set, in which case both are doubled.
+    fn delta_sizes(self) -> (usize, usize) {
+        if self.contains(DeltaSetFormat::LONG_WORDS) {
+            (4, 2)
+        } else {
+            (2, 1)
+        }
+    }
+}
+
+impl<'a> ItemVariationData<'a> {
+    /// Iterate over the deltas in the row at this (inner) index.
+    ///
+    /// There is one delta for each entry in `region_indexes`.
+    pub fn delta_set(&self, inner_index: u16) -> Option<impl Iterator<Item = i32> + '_> {
+        let format = self.word_delta_count();
+        let region_count = self.region_index_count();
+        let row_len = format.row_byte_len(region_count);
+        let start = inner_index as usize * row_len;
+        let row = self.delta_sets().get(start..start + row_len)?;
+        let (word_size, short_size) = format.delta_sizes();
+        let word_count = format.word_count().min(region_count) as usize;
+        let (words, shorts) = row.split_at(word_count * word_size);
+        let words = words
+            .chunks_exact(word_size)
+            .map(move |bytes| read_delta(bytes, word_size));
+        let shorts = shorts
+            .chunks_exact(short_size)
+            .map(move |bytes| read_delta(bytes, short_size));
+        Some(words.chain(shorts))
+    }
+}
+
+/// Mask for the bits of a DeltaSetIndexMap `entryFormat` that give the
+/// number of bits used for the inner index, minus one.
+const INNER_INDEX_BIT_COUNT_MASK: u8 = 0x0F;
+/// Mask for the bits of a DeltaSetIndexMap `entryFormat` that give the size
+/// in bytes of each entry, minus one.
+const MAP_ENTRY_SIZE_MASK: u8 = 0x30;
+
+/// The size in bytes of the entries in a [`DeltaSetIndexMap`].
+pub(crate) fn map_entry_size(entry_format: u8) -> usize {
+    ((entry_format & MAP_ENTRY_SIZE_MASK) >> 4) as usize + 1
+}
+
+impl<'a> DeltaSetIndexMap<'a> {
+    /// The (outer, inner) delta-set index for this item.
+    ///
+    /// Items past the end of the map use the last entry.
+    pub fn get(&self, index: u32) -> Option<(u16, u16)> {
+        let (entry_format, map_count, data) = match self {
+            DeltaSetIndexMap::Format0(map) => {
+                (map.entry_format(), map.map_count() as u32, map.map_data())
+            }
+            DeltaSetIndexMap::Format1(map) => (map.entry_format(), map.map_count(), map.map_data()),
+        };
+        let index = index.min(map_count.checked_sub(1)?) as usize;
+        let entry_size = map_entry_size(entry_format);
+        let entry = data
+            .get(index * entry_size..(index + 1) * entry_size)?
+            .iter()
+            .fold(0u32, |acc, byte| (acc << 8) | *byte as u32);
+        let inner_bits = (entry_format & INNER_INDEX_BIT_COUNT_MASK) as u32 + 1;
+        let outer = entry >> inner_bits;
+        let inner = entry & ((1 << inner_bits) - 1);
+        Some((outer as u16, inner as u16))
+    }
+}
+
+/// Read a signed big-endian delta of 1, 2 or 4 bytes.
+fn read_delta(bytes: &[u8], size: usize) -> i32 {
+    match size {
+        1 => bytes.first().map(|x| *x as i8 as i32),
+        2 => BigEndian::<i16>::read(bytes).map(|x| x.get() as i32),
+        _ => BigEndian::<i32>::read(bytes).map(|x| x.get()),
+    }
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    //! Utilities for building variation data in tests.
+
+    use font_types::{test_helpers::BeBuffer, F2Dot14};
+
+    pub fn f2dot14(val: f32) -> F2Dot14 {
+        F2Dot14::from_f32(val)
+    }
+
+    /// An item variation store with a single region, given as the
+    /// (start, peak, end) on each axis, and one row with a word delta for
+    /// each of `deltas`.
+    pub fn var_store(region: &[(f32, f32, f32)], deltas: &[i16]) -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(1u16);
+        buf.push(12u32);
+        buf.push(1u16);
+        buf.push(16 + 6 * region.len() as u32);
+        buf.extend([region.len() as u16, 1]);
+        for (start, peak, end) in region {
+            buf.extend([f2dot14(*start), f2dot14(*peak), f2dot14(*end)]);
+        }
+        buf.extend([deltas.len() as u16, 1, 1, 0]);
+        buf.extend(deltas.iter().copied());
+        buf.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::test_helpers::BeBuffer;
+
+    use super::test_helpers::f2dot14;
+    use super::*;
+
+    #[test]
+    fn axis_scalar() {
+        let axis = |start: f32, peak: f32, end: f32, coord: f32| {
+            let mut buf = BeBuffer::new();
+            buf.extend([f2dot14(start), f2dot14(peak), f2dot14(end)]);
+            RegionAxisCoordinates::read(&buf)
+                .unwrap()
+                .scalar(f2dot14(coord))
+        };
+        assert_eq!(axis(0.0, 1.0, 1.0, 0.5), 0.5);
+        assert_eq!(axis(0.0, 1.0, 1.0, 1.0), 1.0);
+        assert_eq!(axis(0.0, 1.0, 1.0, -0.5), 0.0);
+        assert_eq!(axis(0.0, 0.5, 1.0, 0.75), 0.5);
+        assert_eq!(axis(-1.0, -1.0, 0.0, -0.25), 0.25);
+        // a zero peak means the axis is ignored
+        assert_eq!(axis(0.0, 0.0, 1.0, -1.0), 1.0);
+        // so does a region that crosses zero
+        assert_eq!(axis(-0.5, 0.5, 1.0, -1.0), 1.0);
+    }
+
+    #[test]
+    fn delta_set_index_map() {
+        let mut buf = BeBuffer::new();
+        // format 0, 2-byte entries with 4 bits of inner index
+        buf.extend([0u8, 0x13]);
+        buf.push(3u16);
+        buf.extend([0x0012u16, 0x0105, 0x002F]);
+        let map = DeltaSetIndexMap::read(&buf).unwrap();
+        assert_eq!(map.get(0), Some((1, 2)));
+        assert_eq!(map.get(1), Some((16, 5)));
+        assert_eq!(map.get(2), Some((2, 15)));
+        // past the end uses the last entry
+        assert_eq!(map.get(100), Some((2, 15)));
+
+        let mut buf = BeBuffer::new();
+        // format 1, 1-byte entries with 8 bits of inner index
+        buf.extend([1u8, 0x07]);
+        buf.push(2u32);
+        buf.extend([7u8, 9]);
+        let map = DeltaSetIndexMap::read(&buf).unwrap();
+        assert_eq!(map.get(1), Some((0, 9)));
+    }
+
+    #[test]
+    fn compute_delta() {
+        let mut buf = BeBuffer::new();
+        buf.push(1u16);
+        buf.push(16u32);
+        buf.push(2u16);
+        buf.extend([44u32, 60]);
+        // region list: two axes & two regions, weight & width
+        buf.extend([2u16, 2]);
+        buf.extend([f2dot14(0.0), f2dot14(1.0), f2dot14(1.0)]);
+        buf.extend([f2dot14(0.0), f2dot14(0.0), f2dot14(0.0)]);
+        buf.extend([f2dot14(0.0), f2dot14(0.0), f2dot14(0.0)]);
+        buf.extend([f2dot14(0.0), f2dot14(1.0), f2dot14(1.0)]);
+        // two rows, with one word & one byte delta each: [300, 10], [-10, 20]
+        buf.extend([2u16, 1, 2, 0, 1]);
+        buf.extend([0x012Cu16, 0x0AFF, 0xF614]);
+        // one row with long words, one long & one short delta
+        buf.extend([1u16, 0x8001, 2, 1, 0]);
+        buf.push(100_000i32);
+        buf.push(-2i16);
+
+        let store = ItemVariationStore::read(&buf).unwrap();
+        let delta = |outer, inner, coords: &[f32]| {
+            let coords = coords.iter().copied().map(f2dot14).collect::<Vec<_>>();
+            store.compute_delta(outer, inner, &coords)
+        };
+        assert_eq!(delta(0, 0, &[0.0, 0.0]), Some(0.0));
+        assert_eq!(delta(0, 0, &[1.0, 0.0]), Some(300.0));
+        assert_eq!(delta(0, 0, &[0.5, 1.0]), Some(160.0));
+        assert_eq!(delta(0, 1, &[1.0, 1.0]), Some(-10.0 + 20.0));
+        // the region indices are reversed in the second subtable
+        assert_eq!(delta(1, 0, &[0.5, 1.0]), Some(100_000.0 - 1.0));
+        assert_eq!(delta(1, 1, &[1.0, 1.0]), None);
+        assert_eq!(delta(2, 0, &[1.0, 1.0]), None);
+    }
+}
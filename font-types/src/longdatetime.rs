@@ -1,11 +1,33 @@
 //! a datetime type
 
+use std::fmt::{Display, Formatter};
+
+/// The number of seconds between 1904-01-01 and the unix epoch, 1970-01-01.
+const UNIX_EPOCH_OFFSET: i64 = 2_082_844_800;
+const SECS_PER_DAY: i64 = 86_400;
+
 /// A simple datetime type.
 ///
 /// This represented as a number of seconds since 12:00 midnight, January 1, 1904, UTC.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct LongDateTime(i64);
 
+/// A [`LongDateTime`] broken down into calendar fields.
+///
+/// This uses the proleptic Gregorian calendar, and is always in UTC.
+/// The `Display` impl writes an ISO 8601 timestamp, like `2022-05-04T13:45:00Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub year: i64,
+    /// The month, in the range `1..=12`.
+    pub month: u8,
+    /// The day of the month, starting from 1.
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
 impl LongDateTime {
     /// Create with a number of seconds relative to 1904-01-01 00:00.
     pub fn new(secs: i64) -> Self {
@@ -19,7 +41,237 @@ impl LongDateTime {
     pub fn as_secs(&self) -> i64 {
         self.0
     }
+
+    /// Create from a number of seconds relative to the unix epoch, 1970-01-01.
+    ///
+    /// Returns `None` if the result would overflow.
+    pub fn from_unix_secs(secs: i64) -> Option<Self> {
+        secs.checked_add(UNIX_EPOCH_OFFSET).map(Self)
+    }
+
+    /// The number of seconds since the unix epoch, 1970-01-01 00:00 UTC.
+    ///
+    /// Returns `None` if the result would overflow.
+    pub fn to_unix_secs(&self) -> Option<i64> {
+        self.0.checked_sub(UNIX_EPOCH_OFFSET)
+    }
+
+    /// Create from a calendar date and time.
+    ///
+    /// Returns `None` if any field is out of range (including days that do
+    /// not exist, such as February 30th) or if the result would overflow.
+    pub fn from_date_time(date: DateTime) -> Option<Self> {
+        if !(1..=12).contains(&date.month)
+            || date.day == 0
+            || date.day > days_in_month(date.year, date.month)
+            || date.hour > 23
+            || date.minute > 59
+            || date.second > 59
+        {
+            return None;
+        }
+        let days = days_from_civil(date.year, date.month, date.day)?;
+        let secs_of_day = date.hour as i64 * 3600 + date.minute as i64 * 60 + date.second as i64;
+        days.checked_mul(SECS_PER_DAY)?
+            .checked_add(secs_of_day)?
+            .checked_add(UNIX_EPOCH_OFFSET)
+            .map(Self)
+    }
+
+    /// Break this time down into calendar fields.
+    pub fn to_date_time(&self) -> DateTime {
+        // 1904-01-01 is 24107 days before the unix epoch
+        let days = self.0.div_euclid(SECS_PER_DAY) - UNIX_EPOCH_OFFSET / SECS_PER_DAY;
+        let secs_of_day = self.0.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        DateTime {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: (secs_of_day / 60 % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+        }
+    }
+
+    /// Convert to a [`SystemTime`](std::time::SystemTime).
+    ///
+    /// Returns `None` if the time is not representable on this platform.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<std::time::SystemTime> {
+        use std::time::{Duration, SystemTime};
+        let unix_secs = self.to_unix_secs()?;
+        let duration = Duration::from_secs(unix_secs.unsigned_abs());
+        if unix_secs >= 0 {
+            SystemTime::UNIX_EPOCH.checked_add(duration)
+        } else {
+            SystemTime::UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+
+    /// Create from a [`SystemTime`](std::time::SystemTime), truncating to
+    /// whole seconds.
+    ///
+    /// Returns `None` if the result would overflow.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> Option<Self> {
+        let unix_secs = match time.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_secs()).ok()?,
+            Err(before) => {
+                // round towards negative infinity, so truncation is consistent
+                let duration = before.duration();
+                let secs = i64::try_from(duration.as_secs()).ok()?;
+                let secs = if duration.subsec_nanos() > 0 {
+                    secs.checked_add(1)?
+                } else {
+                    secs
+                };
+                -secs
+            }
+        };
+        Self::from_unix_secs(unix_secs)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The following two functions are adapted from Howard Hinnant's
+// 'chrono-Compatible Low-Level Date Algorithms':
+// <http://howardhinnant.github.io/date_algorithms.html>
+
+/// The number of days between 1970-01-01 and the given date.
+fn days_from_civil(year: i64, month: u8, day: u8) -> Option<i64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era.checked_mul(146_097)?
+        .checked_add(day_of_era)?
+        .checked_sub(719_468)
+}
+
+/// The date that is the given number of days after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    // this cannot overflow: days is at most i64::MAX / SECS_PER_DAY
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // ISO 8601 requires a sign for years outside 0000..=9999
+        if (0..=9999).contains(&self.year) {
+            write!(f, "{:04}", self.year)?;
+        } else {
+            write!(f, "{:+05}", self.year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl Display for LongDateTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.to_date_time().fmt(f)
+    }
 }
 
 crate::newtype_scalar!(LongDateTime, [u8; 8]);
-//TODO: maybe a 'chrono' feature for constructing these sanely?
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    #[test]
+    fn unix_epoch() {
+        let epoch = LongDateTime::from_unix_secs(0).unwrap();
+        assert_eq!(epoch.as_secs(), UNIX_EPOCH_OFFSET);
+        assert_eq!(epoch.to_date_time(), date(1970, 1, 1, 0, 0, 0));
+        assert_eq!(
+            LongDateTime::new(0).to_date_time(),
+            date(1904, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(LongDateTime::new(i64::MIN).to_unix_secs(), None);
+    }
+
+    #[test]
+    fn calendar_round_trip() {
+        for (secs, expected) in [
+            (-1, "1903-12-31T23:59:59Z"),
+            (3_735_928_559, "2022-05-20T21:55:59Z"),
+            (5_097_600, "1904-02-29T00:00:00Z"),
+            (-60_052_752_000, "0001-01-01T00:00:00Z"),
+            (-60_084_374_400, "0000-01-01T00:00:00Z"),
+            (-60_115_910_400, "-0001-01-01T00:00:00Z"),
+            (255_516_768_000, "+10001-01-01T00:00:00Z"),
+        ] {
+            let time = LongDateTime::new(secs);
+            let date = time.to_date_time();
+            assert_eq!(date.to_string(), expected);
+            assert_eq!(LongDateTime::from_date_time(date), Some(time));
+        }
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert!(LongDateTime::from_date_time(date(2021, 2, 29, 0, 0, 0)).is_none());
+        assert!(LongDateTime::from_date_time(date(2000, 2, 29, 0, 0, 0)).is_some());
+        assert!(LongDateTime::from_date_time(date(1900, 2, 29, 0, 0, 0)).is_none());
+        assert!(LongDateTime::from_date_time(date(2021, 13, 1, 0, 0, 0)).is_none());
+        assert!(LongDateTime::from_date_time(date(2021, 4, 31, 0, 0, 0)).is_none());
+        assert!(LongDateTime::from_date_time(date(2021, 4, 30, 24, 0, 0)).is_none());
+        assert!(LongDateTime::from_date_time(date(i64::MAX, 1, 1, 0, 0, 0)).is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time() {
+        use std::time::{Duration, SystemTime};
+        let time = LongDateTime::from_unix_secs(-10).unwrap();
+        let system = time.to_system_time().unwrap();
+        assert_eq!(system, SystemTime::UNIX_EPOCH - Duration::from_secs(10));
+        assert_eq!(LongDateTime::from_system_time(system), Some(time));
+        let just_before = system + Duration::from_millis(500);
+        assert_eq!(LongDateTime::from_system_time(just_before), Some(time));
+    }
+}
@@ -0,0 +1,33 @@
+/// The [avar (Axis Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/avar) table
+#[offset_host]
+Avar<'a> {
+    /// Major version number of the axis variations table — set to 1
+    /// or 2.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the axis variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// Permanently reserved; set to zero.
+    #[hidden]
+    reserved: BigEndian<u16>,
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    axis_count: BigEndian<u16>,
+}
+
+/// [SegmentMaps](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+SegmentMaps<'a> {
+    /// The number of correspondence pairs for this axis.
+    position_map_count: BigEndian<u16>,
+    /// The array of axis value map records for this axis.
+    #[count(position_map_count)]
+    axis_value_maps: [AxisValueMap],
+}
+
+/// [AxisValueMap](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+AxisValueMap {
+    /// A normalized coordinate value obtained using default
+    /// normalization.
+    from_coordinate: BigEndian<F2Dot14>,
+    /// The modified, normalized coordinate value.
+    to_coordinate: BigEndian<F2Dot14>,
+}
@@ -0,0 +1,44 @@
+/// The [CFF (Compact Font Format)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff) table header
+#[offset_host]
+Cff<'a> {
+    /// Format major version (starting at 1)
+    major: BigEndian<u8>,
+    /// Format minor version (starting at 0)
+    minor: BigEndian<u8>,
+    /// Header size (bytes)
+    hdr_size: BigEndian<u8>,
+    /// Absolute offset (0) size
+    off_size: BigEndian<u8>,
+}
+
+/// A range in a format 1 charset
+Charset1Range {
+    /// First glyph in range
+    first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first)
+    n_left: BigEndian<u8>,
+}
+
+/// A range in a format 2 charset
+Charset2Range {
+    /// First glyph in range
+    first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first)
+    n_left: BigEndian<u16>,
+}
+
+/// A range in a format 1 encoding
+EncodingRange {
+    /// First code in range
+    first: BigEndian<u8>,
+    /// Codes left in range (excluding first)
+    n_left: BigEndian<u8>,
+}
+
+/// An additional code for a glyph, in an encoding with supplements
+EncodingSupplement {
+    /// Encoding
+    code: BigEndian<u8>,
+    /// Name
+    glyph: BigEndian<u16>,
+}
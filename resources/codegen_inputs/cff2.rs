@@ -0,0 +1,12 @@
+/// The [CFF2 (Compact Font Format Version 2)](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2) table header
+#[offset_host]
+Cff2<'a> {
+    /// Format major version (set to 2)
+    major_version: BigEndian<u8>,
+    /// Format minor version (set to 0)
+    minor_version: BigEndian<u8>,
+    /// Header size (bytes)
+    header_size: BigEndian<u8>,
+    /// Length of Top DICT structure in bytes
+    top_dict_length: BigEndian<u16>,
+}
@@ -0,0 +1,51 @@
+/// The [fvar (Font Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar) table
+#[offset_host]
+Fvar<'a> {
+    /// Major version number of the font variations table — set to 1.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the font variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// Offset in bytes from the beginning of the table to the start of
+    /// the VariationAxisRecord array.
+    axes_array_offset: BigEndian<Offset16>,
+    /// This field is permanently reserved. Set to 2.
+    #[hidden]
+    reserved: BigEndian<u16>,
+    /// The number of variation axes in the font (the number of records
+    /// in the axes array).
+    axis_count: BigEndian<u16>,
+    /// The size in bytes of each VariationAxisRecord — set to 20
+    /// (0x0014) for this version.
+    axis_size: BigEndian<u16>,
+    /// The number of named instances defined in the font (the number
+    /// of records in the instances array).
+    instance_count: BigEndian<u16>,
+    /// The size in bytes of each InstanceRecord — set to either
+    /// axisCount * sizeof(Fixed) + 4, or to axisCount * sizeof(Fixed)
+    /// + 6.
+    instance_size: BigEndian<u16>,
+}
+
+/// [VariationAxisRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#variationaxisrecord)
+VariationAxisRecord {
+    /// Tag identifying the design variation for the axis.
+    axis_tag: BigEndian<Tag>,
+    /// The minimum coordinate value for the axis.
+    min_value: BigEndian<Fixed>,
+    /// The default coordinate value for the axis.
+    default_value: BigEndian<Fixed>,
+    /// The maximum coordinate value for the axis.
+    max_value: BigEndian<Fixed>,
+    /// Axis qualifiers — see details below.
+    flags: BigEndian<VariationAxisFlags>,
+    /// The name ID for entries in the 'name' table that provide a
+    /// display name for this axis.
+    axis_name_id: BigEndian<u16>,
+}
+
+/// Flags for a [VariationAxisRecord]
+#[flags(u16)]
+VariationAxisFlags {
+    /// The axis should not be exposed directly in user interfaces.
+    HIDDEN_AXIS = 0x0001,
+}
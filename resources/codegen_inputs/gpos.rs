@@ -0,0 +1,434 @@
+/// [GPOS](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#gpos-header) 1.0
+#[offset_host]
+Gpos1_0<'a> {
+    /// Major version of the GPOS table, = 1
+    major_version: BigEndian<u16>,
+    /// Minor version of the GPOS table, = 0
+    minor_version: BigEndian<u16>,
+    /// Offset to ScriptList table, from beginning of GPOS table
+    script_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureList table, from beginning of GPOS table
+    feature_list_offset: BigEndian<Offset16>,
+    /// Offset to LookupList table, from beginning of GPOS table
+    lookup_list_offset: BigEndian<Offset16>,
+}
+
+/// [GPOS](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#gpos-header) 1.1
+#[offset_host]
+Gpos1_1<'a> {
+    /// Major version of the GPOS table, = 1
+    major_version: BigEndian<u16>,
+    /// Minor version of the GPOS table, = 1
+    minor_version: BigEndian<u16>,
+    /// Offset to ScriptList table, from beginning of GPOS table
+    script_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureList table, from beginning of GPOS table
+    feature_list_offset: BigEndian<Offset16>,
+    /// Offset to LookupList table, from beginning of GPOS table
+    lookup_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureVariations table, from beginning of GPOS table
+    /// (may be NULL)
+    feature_variations_offset: BigEndian<Offset32>,
+}
+
+#[format(MajorMinor)]
+#[generate_getters]
+enum Gpos<'a> {
+    #[version(MajorMinor::VERSION_1_0)]
+    Gpos1_0(Gpos1_0<'a>),
+    #[version(MajorMinor::VERSION_1_1)]
+    Gpos1_1(Gpos1_1<'a>),
+}
+
+/// See [ValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#value-record)
+#[flags(u16)]
+ValueFormat {
+    /// Includes horizontal adjustment for placement
+    X_PLACEMENT = 0x0001,
+    /// Includes vertical adjustment for placement
+    Y_PLACEMENT = 0x0002,
+    /// Includes horizontal adjustment for advance
+    X_ADVANCE = 0x0004,
+    /// Includes vertical adjustment for advance
+    Y_ADVANCE = 0x0008,
+    /// Includes Device table (non-variable font) / VariationIndex
+    /// table (variable font) for horizontal placement
+    X_PLACEMENT_DEVICE = 0x0010,
+    /// Includes Device table (non-variable font) / VariationIndex
+    /// table (variable font) for vertical placement
+    Y_PLACEMENT_DEVICE = 0x0020,
+    /// Includes Device table (non-variable font) / VariationIndex
+    /// table (variable font) for horizontal advance
+    X_ADVANCE_DEVICE = 0x0040,
+    /// Includes Device table (non-variable font) / VariationIndex
+    /// table (variable font) for vertical advance
+    Y_ADVANCE_DEVICE = 0x0080,
+}
+
+/// [Anchor Table Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-1-design-units): Design Units
+AnchorFormat1 {
+    /// Format identifier, = 1
+    anchor_format: BigEndian<u16>,
+    /// Horizontal value, in design units
+    x_coordinate: BigEndian<i16>,
+    /// Vertical value, in design units
+    y_coordinate: BigEndian<i16>,
+}
+
+/// [Anchor Table Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-2-design-units-plus-contour-point): Design Units Plus Contour Point
+AnchorFormat2 {
+    /// Format identifier, = 2
+    anchor_format: BigEndian<u16>,
+    /// Horizontal value, in design units
+    x_coordinate: BigEndian<i16>,
+    /// Vertical value, in design units
+    y_coordinate: BigEndian<i16>,
+    /// Index to glyph contour point
+    anchor_point: BigEndian<u16>,
+}
+
+/// [Anchor Table Format 3](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-table-format-3-design-units-plus-device-or-variationindex-tables): Design Units Plus Device or VariationIndex Tables
+#[offset_host]
+AnchorFormat3<'a> {
+    /// Format identifier, = 3
+    anchor_format: BigEndian<u16>,
+    /// Horizontal value, in design units
+    x_coordinate: BigEndian<i16>,
+    /// Vertical value, in design units
+    y_coordinate: BigEndian<i16>,
+    /// Offset to Device table (non-variable font) / VariationIndex
+    /// table (variable font) for X coordinate, from beginning of
+    /// Anchor table (may be NULL)
+    x_device_offset: BigEndian<Offset16>,
+    /// Offset to Device table (non-variable font) / VariationIndex
+    /// table (variable font) for Y coordinate, from beginning of
+    /// Anchor table (may be NULL)
+    y_device_offset: BigEndian<Offset16>,
+}
+
+/// [Anchor Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-tables)
+#[format(u16)]
+enum AnchorTable<'a> {
+    #[version(1)]
+    Format1(AnchorFormat1),
+    #[version(2)]
+    Format2(AnchorFormat2),
+    #[version(3)]
+    Format3(AnchorFormat3<'a>),
+}
+
+/// [Mark Array Table](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-array-table)
+#[offset_host]
+MarkArray<'a> {
+    /// Number of MarkRecords
+    mark_count: BigEndian<u16>,
+    /// Array of MarkRecords, ordered by corresponding glyphs in the
+    /// associated mark Coverage table.
+    #[count(mark_count)]
+    mark_records: [MarkRecord],
+}
+
+/// Part of [MarkArray]
+MarkRecord {
+    /// Class defined for the associated mark.
+    mark_class: BigEndian<u16>,
+    /// Offset to Anchor table, from beginning of MarkArray table.
+    mark_anchor_offset: BigEndian<Offset16>,
+}
+
+/// [Single Adjustment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#single-adjustment-positioning-format-1-single-positioning-value): Single Positioning Value
+#[offset_host]
+SinglePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of SinglePos subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// Defines the types of data in the ValueRecord.
+    value_format: BigEndian<ValueFormat>,
+    /// Defines positioning value(s) — applied to all glyphs in the
+    /// Coverage table.
+    #[count_with(value_record_len, value_format)]
+    value_record_data: [u8],
+}
+
+/// [Single Adjustment Positioning Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#single-adjustment-positioning-format-2-array-of-positioning-values): Array of Positioning Values
+#[offset_host]
+SinglePosFormat2<'a> {
+    /// Format identifier: format = 2
+    pos_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of SinglePos subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// Defines the types of data in the ValueRecords.
+    value_format: BigEndian<ValueFormat>,
+    /// Number of ValueRecords — must equal glyphCount in the
+    /// Coverage table.
+    value_count: BigEndian<u16>,
+    /// Array of ValueRecords — positioning values applied to glyphs.
+    #[count_with(value_records_len, value_format, value_count)]
+    value_records_data: [u8],
+}
+
+/// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+#[format(u16)]
+enum SinglePos<'a> {
+    #[version(1)]
+    Format1(SinglePosFormat1<'a>),
+    #[version(2)]
+    Format2(SinglePosFormat2<'a>),
+}
+
+/// [Pair Adjustment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#pair-adjustment-positioning-format-1-adjustments-for-glyph-pairs): Adjustments for Glyph Pairs
+#[offset_host]
+PairPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of PairPos subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// Defines the types of data in valueRecord1 — for the first
+    /// glyph in the pair (may be zero).
+    value_format1: BigEndian<ValueFormat>,
+    /// Defines the types of data in valueRecord2 — for the second
+    /// glyph in the pair (may be zero).
+    value_format2: BigEndian<ValueFormat>,
+    /// Number of PairSet tables
+    pair_set_count: BigEndian<u16>,
+    /// Array of offsets to PairSet tables. Offsets are from beginning
+    /// of PairPos subtable, ordered by Coverage Index.
+    #[count(pair_set_count)]
+    pair_set_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [PairPosFormat1]
+///
+/// The size of each PairValueRecord depends on the value formats of the
+/// parent subtable, and must be passed in when reading.
+#[offset_host]
+#[init(pair_value_record_len)]
+PairSet<'a> {
+    /// Number of PairValueRecords
+    pair_value_count: BigEndian<u16>,
+    /// Array of PairValueRecords, ordered by glyph ID of the second
+    /// glyph.
+    #[count_with(records_len, pair_value_count, pair_value_record_len)]
+    pair_value_record_data: [u8],
+}
+
+/// [Pair Adjustment Positioning Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#pair-adjustment-positioning-format-2-class-pair-adjustment): Class Pair Adjustment
+#[offset_host]
+PairPosFormat2<'a> {
+    /// Format identifier: format = 2
+    pos_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of PairPos subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// ValueRecord definition — for the first glyph of the pair (may
+    /// be zero).
+    value_format1: BigEndian<ValueFormat>,
+    /// ValueRecord definition — for the second glyph of the pair
+    /// (may be zero).
+    value_format2: BigEndian<ValueFormat>,
+    /// Offset to ClassDef table, from beginning of PairPos subtable
+    /// — for the first glyph of the pair.
+    class_def1_offset: BigEndian<Offset16>,
+    /// Offset to ClassDef table, from beginning of PairPos subtable
+    /// — for the second glyph of the pair.
+    class_def2_offset: BigEndian<Offset16>,
+    /// Number of classes in classDef1 table — includes Class 0.
+    class1_count: BigEndian<u16>,
+    /// Number of classes in classDef2 table — includes Class 0.
+    class2_count: BigEndian<u16>,
+    /// Array of Class1 records, ordered by classes in classDef1.
+    #[count_with(class1_records_len, class1_count, class2_count, value_format1, value_format2)]
+    class1_record_data: [u8],
+}
+
+/// [Lookup Type 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-2-pair-adjustment-positioning-subtable): Pair Adjustment Positioning Subtable
+#[format(u16)]
+enum PairPos<'a> {
+    #[version(1)]
+    Format1(PairPosFormat1<'a>),
+    #[version(2)]
+    Format2(PairPosFormat2<'a>),
+}
+
+/// [Cursive Attachment Positioning Format1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#cursive-attachment-positioning-format1-cursive-attachment): Cursvie attachment
+#[offset_host]
+CursivePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of CursivePos subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of EntryExit records
+    entry_exit_count: BigEndian<u16>,
+    /// Array of EntryExit records, in Coverage index order.
+    #[count(entry_exit_count)]
+    entry_exit_record: [EntryExitRecord],
+}
+
+/// Part of [CursivePosFormat1]
+EntryExitRecord {
+    /// Offset to entryAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    entry_anchor_offset: BigEndian<Offset16>,
+    /// Offset to exitAnchor table, from beginning of CursivePos
+    /// subtable (may be NULL).
+    exit_anchor_offset: BigEndian<Offset16>,
+}
+
+/// [Mark-to-Base Attachment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-base-attachment-positioning-format-1-mark-to-base-attachment-point): Mark-to-base Attachment Point
+#[offset_host]
+MarkBasePosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to markCoverage table, from beginning of MarkBasePos
+    /// subtable.
+    mark_coverage_offset: BigEndian<Offset16>,
+    /// Offset to baseCoverage table, from beginning of MarkBasePos
+    /// subtable.
+    base_coverage_offset: BigEndian<Offset16>,
+    /// Number of classes defined for marks
+    mark_class_count: BigEndian<u16>,
+    /// Offset to MarkArray table, from beginning of MarkBasePos
+    /// subtable.
+    mark_array_offset: BigEndian<Offset16>,
+    /// Offset to BaseArray table, from beginning of MarkBasePos
+    /// subtable.
+    base_array_offset: BigEndian<Offset16>,
+}
+
+/// Part of [MarkBasePosFormat1]
+///
+/// Each BaseRecord contains one anchor offset per mark class; the records
+/// are stored here as a single flat array.
+#[offset_host]
+#[init(mark_class_count)]
+BaseArray<'a> {
+    /// Number of BaseRecords
+    base_count: BigEndian<u16>,
+    /// Array of BaseRecords, in order of baseCoverage Index. Offsets
+    /// are from beginning of BaseArray table, ordered by class
+    /// (offsets may be NULL).
+    #[count_with(records_len, base_count, mark_class_count)]
+    base_anchor_offsets: [BigEndian<Offset16>],
+}
+
+/// [Mark-to-Ligature Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-ligature-attachment-positioning-format-1-mark-to-ligature-attachment): Mark-to-Ligature Attachment
+#[offset_host]
+MarkLigPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to markCoverage table, from beginning of MarkLigPos
+    /// subtable.
+    mark_coverage_offset: BigEndian<Offset16>,
+    /// Offset to ligatureCoverage table, from beginning of MarkLigPos
+    /// subtable.
+    ligature_coverage_offset: BigEndian<Offset16>,
+    /// Number of defined mark classes
+    mark_class_count: BigEndian<u16>,
+    /// Offset to MarkArray table, from beginning of MarkLigPos
+    /// subtable.
+    mark_array_offset: BigEndian<Offset16>,
+    /// Offset to LigatureArray table, from beginning of MarkLigPos
+    /// subtable.
+    ligature_array_offset: BigEndian<Offset16>,
+}
+
+/// Part of [MarkLigPosFormat1]
+#[offset_host]
+LigatureArray<'a> {
+    /// Number of LigatureAttach table offsets
+    ligature_count: BigEndian<u16>,
+    /// Array of offsets to LigatureAttach tables. Offsets are from
+    /// beginning of LigatureArray table, ordered by ligatureCoverage
+    /// index.
+    #[count(ligature_count)]
+    ligature_attach_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [MarkLigPosFormat1]
+///
+/// Each ComponentRecord contains one anchor offset per mark class; the
+/// records are stored here as a single flat array.
+#[offset_host]
+#[init(mark_class_count)]
+LigatureAttach<'a> {
+    /// Number of ComponentRecords in this ligature
+    component_count: BigEndian<u16>,
+    /// Array of Component records, ordered in writing direction.
+    /// Offsets are from beginning of LigatureAttach table, ordered by
+    /// class (offsets may be NULL).
+    #[count_with(records_len, component_count, mark_class_count)]
+    ligature_anchor_offsets: [BigEndian<Offset16>],
+}
+
+/// [Mark-to-Mark Attachment Positioning Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#mark-to-mark-attachment-positioning-format-1-mark-to-mark-attachment): Mark-to-Mark Attachment
+#[offset_host]
+MarkMarkPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Offset to Combining Mark Coverage table, from beginning of
+    /// MarkMarkPos subtable.
+    mark1_coverage_offset: BigEndian<Offset16>,
+    /// Offset to Base Mark Coverage table, from beginning of
+    /// MarkMarkPos subtable.
+    mark2_coverage_offset: BigEndian<Offset16>,
+    /// Number of Combining Mark classes defined
+    mark_class_count: BigEndian<u16>,
+    /// Offset to MarkArray table for mark1, from beginning of
+    /// MarkMarkPos subtable.
+    mark1_array_offset: BigEndian<Offset16>,
+    /// Offset to Mark2Array table for mark2, from beginning of
+    /// MarkMarkPos subtable.
+    mark2_array_offset: BigEndian<Offset16>,
+}
+
+/// Part of [MarkMarkPosFormat1]
+///
+/// Each Mark2Record contains one anchor offset per mark class; the records
+/// are stored here as a single flat array.
+#[offset_host]
+#[init(mark_class_count)]
+Mark2Array<'a> {
+    /// Number of Mark2 records
+    mark2_count: BigEndian<u16>,
+    /// Array of Mark2Records, in Coverage order. Offsets are from
+    /// beginning of Mark2Array table, in class order (offsets may be
+    /// NULL).
+    #[count_with(records_len, mark2_count, mark_class_count)]
+    mark2_anchor_offsets: [BigEndian<Offset16>],
+}
+
+/// [Extension Positioning Subtable Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#extension-positioning-subtable-format-1)
+#[offset_host]
+ExtensionPosFormat1<'a> {
+    /// Format identifier: format = 1
+    pos_format: BigEndian<u16>,
+    /// Lookup type of subtable referenced by extensionOffset (i.e. the
+    /// extension subtable).
+    extension_lookup_type: BigEndian<u16>,
+    /// Offset to the extension subtable, of lookup type
+    /// extensionLookupType, relative to the start of the
+    /// ExtensionPosFormat1 subtable.
+    extension_offset: BigEndian<Offset32>,
+}
+
+fn value_record_len(format: ValueFormat) -> usize {
+    format.record_byte_len()
+}
+
+fn value_records_len(format: ValueFormat, count: u16) -> usize {
+    format.record_byte_len() * count as usize
+}
+
+fn records_len(count: u16, record_len: usize) -> usize {
+    count as usize * record_len
+}
+
+fn class1_records_len(
+    class1_count: u16,
+    class2_count: u16,
+    format1: ValueFormat,
+    format2: ValueFormat,
+) -> usize {
+    let class2_record_len = format1.record_byte_len() + format2.record_byte_len();
+    class1_count as usize * class2_count as usize * class2_record_len
+}
@@ -0,0 +1,212 @@
+/// [GSUB](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#gsub-header) 1.0
+#[offset_host]
+Gsub1_0<'a> {
+    /// Major version of the GSUB table, = 1
+    major_version: BigEndian<u16>,
+    /// Minor version of the GSUB table, = 0
+    minor_version: BigEndian<u16>,
+    /// Offset to ScriptList table, from beginning of GSUB table
+    script_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureList table, from beginning of GSUB table
+    feature_list_offset: BigEndian<Offset16>,
+    /// Offset to LookupList table, from beginning of GSUB table
+    lookup_list_offset: BigEndian<Offset16>,
+}
+
+/// [GSUB](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#gsub-header) 1.1
+#[offset_host]
+Gsub1_1<'a> {
+    /// Major version of the GSUB table, = 1
+    major_version: BigEndian<u16>,
+    /// Minor version of the GSUB table, = 1
+    minor_version: BigEndian<u16>,
+    /// Offset to ScriptList table, from beginning of GSUB table
+    script_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureList table, from beginning of GSUB table
+    feature_list_offset: BigEndian<Offset16>,
+    /// Offset to LookupList table, from beginning of GSUB table
+    lookup_list_offset: BigEndian<Offset16>,
+    /// Offset to FeatureVariations table, from beginning of the GSUB
+    /// table (may be NULL)
+    feature_variations_offset: BigEndian<Offset32>,
+}
+
+#[format(MajorMinor)]
+#[generate_getters]
+enum Gsub<'a> {
+    #[version(MajorMinor::VERSION_1_0)]
+    Gsub1_0(Gsub1_0<'a>),
+    #[version(MajorMinor::VERSION_1_1)]
+    Gsub1_1(Gsub1_1<'a>),
+}
+
+/// [Single Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#11-single-substitution-format-1)
+#[offset_host]
+SingleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    coverage_offset: BigEndian<Offset16>,
+    /// Add to original glyph ID to get substitute glyph ID
+    delta_glyph_id: BigEndian<i16>,
+}
+
+/// [Single Substitution Format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#12-single-substitution-format-2)
+#[offset_host]
+SingleSubstFormat2<'a> {
+    /// Format identifier: format = 2
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of glyph IDs in the substituteGlyphIDs array
+    glyph_count: BigEndian<u16>,
+    /// Array of substitute glyph IDs — ordered by Coverage index
+    #[count(glyph_count)]
+    substitute_glyph_ids: [BigEndian<u16>],
+}
+
+/// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#lookuptype-1-single-substitution-subtable): Single Substitution Subtable
+#[format(u16)]
+enum SingleSubst<'a> {
+    #[version(1)]
+    Format1(SingleSubstFormat1<'a>),
+    #[version(2)]
+    Format2(SingleSubstFormat2<'a>),
+}
+
+/// [Multiple Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#21-multiple-substitution-format-1)
+#[offset_host]
+MultipleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of Sequence table offsets in the sequenceOffsets array
+    sequence_count: BigEndian<u16>,
+    /// Array of offsets to Sequence tables. Offsets are from beginning
+    /// of substitution subtable, ordered by Coverage index
+    #[count(sequence_count)]
+    sequence_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [MultipleSubstFormat1]
+Sequence<'a> {
+    /// Number of glyph IDs in the substituteGlyphIDs array. This must
+    /// always be greater than 0.
+    glyph_count: BigEndian<u16>,
+    /// String of glyph IDs to substitute
+    #[count(glyph_count)]
+    substitute_glyph_ids: [BigEndian<u16>],
+}
+
+/// [Alternate Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#31-alternate-substitution-format-1)
+#[offset_host]
+AlternateSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of AlternateSet tables
+    alternate_set_count: BigEndian<u16>,
+    /// Array of offsets to AlternateSet tables. Offsets are from
+    /// beginning of substitution subtable, ordered by Coverage index
+    #[count(alternate_set_count)]
+    alternate_set_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [AlternateSubstFormat1]
+AlternateSet<'a> {
+    /// Number of glyph IDs in the alternateGlyphIDs array
+    glyph_count: BigEndian<u16>,
+    /// Array of alternate glyph IDs, in arbitrary order
+    #[count(glyph_count)]
+    alternate_glyph_ids: [BigEndian<u16>],
+}
+
+/// [Ligature Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#41-ligature-substitution-format-1)
+#[offset_host]
+LigatureSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of LigatureSet tables
+    ligature_set_count: BigEndian<u16>,
+    /// Array of offsets to LigatureSet tables. Offsets are from
+    /// beginning of substitution subtable, ordered by Coverage index
+    #[count(ligature_set_count)]
+    ligature_set_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [LigatureSubstFormat1]
+#[offset_host]
+LigatureSet<'a> {
+    /// Number of Ligature tables
+    ligature_count: BigEndian<u16>,
+    /// Array of offsets to Ligature tables. Offsets are from beginning
+    /// of LigatureSet table, ordered by preference.
+    #[count(ligature_count)]
+    ligature_offsets: [BigEndian<Offset16>],
+}
+
+/// Part of [LigatureSubstFormat1]
+Ligature<'a> {
+    /// glyph ID of ligature to substitute
+    ligature_glyph: BigEndian<u16>,
+    /// Number of components in the ligature
+    component_count: BigEndian<u16>,
+    /// Array of component glyph IDs — start with the second
+    /// component, ordered in writing direction
+    #[count_with(minus_one, component_count)]
+    component_glyph_ids: [BigEndian<u16>],
+}
+
+fn minus_one(inp: u16) -> usize {
+inp.saturating_sub(1) as usize
+}
+
+/// [Extension Substitution Subtable Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#71-extension-substitution-subtable-format-1)
+#[offset_host]
+ExtensionSubstFormat1<'a> {
+    /// Format identifier. Set to 1.
+    subst_format: BigEndian<u16>,
+    /// Lookup type of subtable referenced by extensionOffset (that is,
+    /// the extension subtable).
+    extension_lookup_type: BigEndian<u16>,
+    /// Offset to the extension subtable, of lookup type
+    /// extensionLookupType, relative to the start of the
+    /// ExtensionSubstFormat1 subtable.
+    extension_offset: BigEndian<Offset32>,
+}
+
+/// [Reverse Chaining Contextual Single Substitution Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gsub#81-reverse-chaining-contextual-single-substitution-format-1-coverage-based-glyph-contexts)
+#[offset_host]
+ReverseChainSingleSubstFormat1<'a> {
+    /// Format identifier: format = 1
+    subst_format: BigEndian<u16>,
+    /// Offset to Coverage table, from beginning of substitution
+    /// subtable.
+    coverage_offset: BigEndian<Offset16>,
+    /// Number of glyphs in the backtrack sequence.
+    backtrack_glyph_count: BigEndian<u16>,
+    /// Array of offsets to coverage tables in backtrack sequence, in
+    /// glyph sequence order.
+    #[count(backtrack_glyph_count)]
+    backtrack_coverage_offsets: [BigEndian<Offset16>],
+    /// Number of glyphs in lookahead sequence.
+    lookahead_glyph_count: BigEndian<u16>,
+    /// Array of offsets to coverage tables in lookahead sequence, in
+    /// glyph sequence order.
+    #[count(lookahead_glyph_count)]
+    lookahead_coverage_offsets: [BigEndian<Offset16>],
+    /// Number of glyph IDs in the substituteGlyphIDs array.
+    glyph_count: BigEndian<u16>,
+    /// Array of substitute glyph IDs — ordered by Coverage index.
+    #[count(glyph_count)]
+    substitute_glyph_ids: [BigEndian<u16>],
+}
@@ -0,0 +1,94 @@
+/// The [gvar (Glyph Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/gvar) table
+#[offset_host]
+Gvar<'a> {
+    /// Major version number of the glyph variations table — set to 1.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the glyph variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    axis_count: BigEndian<u16>,
+    /// The number of shared tuple records. Shared tuple records can be
+    /// referenced within glyph variation data tables for multiple
+    /// glyphs, as opposed to other tuple records stored directly
+    /// within a glyph variation data table.
+    shared_tuple_count: BigEndian<u16>,
+    /// Offset from the start of this table to the shared tuple records.
+    shared_tuples_offset: BigEndian<Offset32>,
+    /// The number of glyphs in this font. This must match the number
+    /// of glyphs stored elsewhere in the font.
+    glyph_count: BigEndian<u16>,
+    /// Bit-field that gives the format of the offset array that
+    /// follows. If bit 0 is clear, the offsets are uint16; if bit 0 is
+    /// set, the offsets are uint32.
+    flags: BigEndian<GvarFlags>,
+    /// Offset from the start of this table to the array of
+    /// GlyphVariationData tables.
+    glyph_variation_data_array_offset: BigEndian<Offset32>,
+    /// Offsets from the start of the GlyphVariationData array to each
+    /// GlyphVariationData table.
+    ///
+    /// These are either `Offset16` values divided by 2, or `Offset32`
+    /// values, depending on `flags`.
+    #[count_with(glyph_variation_data_offsets_len, glyph_count, flags)]
+    glyph_variation_data_offsets: [u8],
+}
+
+/// Flags for the [Gvar] table
+#[flags(u16)]
+GvarFlags {
+    /// If set, offsets to glyph variation data are 32 bits.
+    LONG_OFFSETS = 0x0001,
+}
+
+/// [GlyphVariationData](https://docs.microsoft.com/en-us/typography/opentype/spec/gvar#the-glyphvariationdata-table-array)
+#[offset_host]
+GlyphVariationData<'a> {
+    /// A packed field. The high 4 bits are flags, and the low 12 bits
+    /// are the number of tuple variation tables for this glyph.
+    tuple_variation_count: BigEndian<TupleVariationCount>,
+    /// Offset from the start of the GlyphVariationData table to the
+    /// serialized data
+    data_offset: BigEndian<Offset16>,
+    /// The tuple variation headers, followed by the serialized data.
+    #[count_all]
+    tuple_variation_headers: [u8],
+}
+
+/// The `tupleVariationCount` field of a [GlyphVariationData] table.
+#[flags(u16)]
+TupleVariationCount {
+    /// Flag indicating that some or all tuple variation tables
+    /// reference a shared set of “point” numbers. These shared numbers
+    /// are represented as packed point number data at the start of the
+    /// serialized data.
+    SHARED_POINT_NUMBERS = 0x8000,
+    /// Mask for the low 12 bits to give the count value.
+    COUNT_MASK = 0x0FFF,
+}
+
+/// The `tupleIndex` field of a tuple variation header.
+#[flags(u16)]
+TupleIndex {
+    /// Flag indicating that this tuple variation header includes an
+    /// embedded peak tuple record, immediately after the tupleIndex
+    /// field.
+    EMBEDDED_PEAK_TUPLE = 0x8000,
+    /// Flag indicating that this tuple variation table applies to an
+    /// intermediate region within the variation space.
+    INTERMEDIATE_REGION = 0x4000,
+    /// Flag indicating that the serialized data for this tuple
+    /// variation table includes packed “point” number data.
+    PRIVATE_POINT_NUMBERS = 0x2000,
+    /// Mask for the low 12 bits to give the shared tuple records index.
+    TUPLE_INDEX_MASK = 0x0FFF,
+}
+
+fn glyph_variation_data_offsets_len(glyph_count: u16, flags: GvarFlags) -> usize {
+    let offset_size = if flags.contains(GvarFlags::LONG_OFFSETS) {
+        4
+    } else {
+        2
+    };
+    (glyph_count as usize + 1) * offset_size
+}
@@ -15,7 +15,7 @@ Head {
     /// Set to 0x5F0F3CF5.
     magic_number: BigEndian<u32>,
     /// See the flags enum
-    flags: BigEndian<u16>,
+    flags: BigEndian<HeadFlags>,
     /// Set to a value from 16 to 16384. Any value in this range is
     /// valid. In fonts that have TrueType outlines, a power of 2 is
     /// recommended as this allows performance optimizations in some
@@ -35,8 +35,9 @@ Head {
     x_max: BigEndian<i16>,
     /// Maximum y coordinate across all glyph bounding boxes.
     y_max: BigEndian<i16>,
-    /// see somewhere else
-    mac_style: BigEndian<u16>,
+    /// Style information, which should agree with the `fsSelection`
+    /// field in the OS/2 table.
+    mac_style: BigEndian<MacStyle>,
     /// Smallest readable size in pixels.
     lowest_rec_ppem: BigEndian<u16>,
     /// Deprecated (Set to 2).
@@ -46,3 +47,64 @@ Head {
     /// 0 for current format.
     glyph_data_format: BigEndian<i16>,
 }
+
+/// Flags used in the [Head] table
+#[flags(u16)]
+HeadFlags {
+    /// Bit 0: Baseline for font at y=0.
+    BASELINE_AT_Y_0 = 0x0001,
+    /// Bit 1: Left sidebearing point at x=0 (relevant only for
+    /// TrueType rasterizers).
+    LSB_AT_X_0 = 0x0002,
+    /// Bit 2: Instructions may depend on point size.
+    INSTRUCTIONS_DEPEND_ON_POINT_SIZE = 0x0004,
+    /// Bit 3: Force ppem to integer values for all internal scaler
+    /// math; may use fractional ppem sizes if this bit is clear. It is
+    /// strongly recommended that this be set in hinted fonts.
+    FORCE_INTEGER_PPEM = 0x0008,
+    /// Bit 4: Instructions may alter advance width (the advance widths
+    /// might not scale linearly).
+    INSTRUCTIONS_ALTER_ADVANCE_WIDTH = 0x0010,
+    /// Bit 11: Font data is “lossless” as a result of having been
+    /// subjected to optimizing transformation and/or compression (such
+    /// as compression mechanisms defined by ISO/IEC 14496-18,
+    /// MicroType® Express, WOFF 2.0, or similar) where the original
+    /// font functionality and features are retained but the binary
+    /// compatibility between input and output font files is not
+    /// guaranteed. As a result of the applied transform, the DSIG
+    /// table may also be invalidated.
+    LOSSLESS = 0x0800,
+    /// Bit 12: Font converted (produce compatible metrics).
+    CONVERTED = 0x1000,
+    /// Bit 13: Font optimized for ClearType™. Note, fonts that rely on
+    /// embedded bitmaps (EBDT) for rendering should not be considered
+    /// optimized for ClearType, and therefore should keep this bit
+    /// cleared.
+    CLEARTYPE_OPTIMIZED = 0x2000,
+    /// Bit 14: Last Resort font. If set, indicates that the glyphs
+    /// encoded in the 'cmap' subtables are simply generic symbolic
+    /// representations of code point ranges and do not truly represent
+    /// support for those code points. If unset, indicates that the
+    /// glyphs encoded in the 'cmap' subtables represent proper support
+    /// for those code points.
+    LAST_RESORT = 0x4000,
+}
+
+/// The `macStyle` field of the [Head] table
+#[flags(u16)]
+MacStyle {
+    /// Bit 0: Bold (if set to 1)
+    BOLD = 0x0001,
+    /// Bit 1: Italic (if set to 1)
+    ITALIC = 0x0002,
+    /// Bit 2: Underline (if set to 1)
+    UNDERLINE = 0x0004,
+    /// Bit 3: Outline (if set to 1)
+    OUTLINE = 0x0008,
+    /// Bit 4: Shadow (if set to 1)
+    SHADOW = 0x0010,
+    /// Bit 5: Condensed (if set to 1)
+    CONDENSED = 0x0020,
+    /// Bit 6: Extended (if set to 1)
+    EXTENDED = 0x0040,
+}
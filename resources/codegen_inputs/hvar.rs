@@ -0,0 +1,20 @@
+/// The [HVAR (Horizontal Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
+#[offset_host]
+Hvar<'a> {
+    /// Major version number of the horizontal metrics variations table — set to 1.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the horizontal metrics variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table.
+    item_variation_store_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for advance widths (may be NULL).
+    advance_width_mapping_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for left side bearings (may be NULL).
+    lsb_mapping_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for right side bearings (may be NULL).
+    rsb_mapping_offset: BigEndian<Offset32>,
+}
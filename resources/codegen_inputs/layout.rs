@@ -75,6 +75,9 @@ FeatureRecord {
 
 /// [Feature Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#feature-table)
 Feature<'a> {
+    /// Offset from start of Feature table to FeatureParams table, if
+    /// defined for the feature and present, else NULL
+    feature_params_offset: BigEndian<Offset16>,
     /// Number of LookupList indices for this feature
     lookup_index_count: BigEndian<u16>,
     /// Array of indices into the LookupList — zero-based (first
@@ -100,17 +103,38 @@ Lookup<'a> {
     /// Different enumerations for GSUB and GPOS
     lookup_type: BigEndian<u16>,
     /// Lookup qualifiers
-    lookup_flag: BigEndian<u16>,
+    lookup_flag: BigEndian<LookupFlag>,
     /// Number of subtables for this lookup
     sub_table_count: BigEndian<u16>,
     /// Array of offsets to lookup subtables, from beginning of Lookup
     /// table
     #[count(sub_table_count)]
     subtable_offsets: [BigEndian<Offset16>],
-    /// Index (base 0) into GDEF mark glyph sets structure. This field
-    /// is only present if the USE_MARK_FILTERING_SET lookup flag is
-    /// set.
-    mark_filtering_set: BigEndian<u16>,
+    // the markFilteringSet field is only present if the
+    // USE_MARK_FILTERING_SET flag is set, and is handled manually.
+}
+
+/// [Lookup Flags](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#lookupFlags)
+#[flags(u16)]
+LookupFlag {
+    /// This bit relates only to the correct processing of the cursive
+    /// attachment lookup type (GPOS lookup type 3). When this bit is
+    /// set, the last glyph in a given sequence to which the cursive
+    /// attachment lookup is applied, will be positioned on the baseline.
+    RIGHT_TO_LEFT = 0x0001,
+    /// If set, skips over base glyphs
+    IGNORE_BASE_GLYPHS = 0x0002,
+    /// If set, skips over ligatures
+    IGNORE_LIGATURES = 0x0004,
+    /// If set, skips over all combining marks
+    IGNORE_MARKS = 0x0008,
+    /// If set, indicates that the lookup table structure is followed by
+    /// a MarkFilteringSet field. The layout engine skips over all mark
+    /// glyphs not in the mark filtering set indicated.
+    USE_MARK_FILTERING_SET = 0x0010,
+    /// If not zero, skips over all marks of attachment type different
+    /// from specified.
+    MARK_ATTACHMENT_TYPE_MASK = 0xFF00,
 }
 
 /// [Coverage Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#coverage-format-1)
@@ -269,6 +293,7 @@ SequenceContextFormat2<'a> {
 }
 
 /// Part of [SequenceContextFormat2]
+#[offset_host]
 ClassSequenceRuleSet<'a> {
     /// Number of ClassSequenceRule tables
     class_seq_rule_count: BigEndian<u16>,
@@ -311,6 +336,17 @@ SequenceContextFormat3<'a> {
     seq_lookup_records: [SequenceLookupRecord],
 }
 
+/// [Sequence Context](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#sequence-context-format-1-simple-glyph-contexts)
+#[format(u16)]
+enum SequenceContext<'a> {
+    #[version(1)]
+    Format1(SequenceContextFormat1<'a>),
+    #[version(2)]
+    Format2(SequenceContextFormat2<'a>),
+    #[version(3)]
+    Format3(SequenceContextFormat3<'a>),
+}
+
 /// [Chained Sequence Context Format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#chained-sequence-context-format-1-simple-glyph-contexts)
 #[offset_host]
 ChainedSequenceContextFormat1<'a> {
@@ -450,6 +486,17 @@ ChainedSequenceContextFormat3<'a> {
     seq_lookup_records: [SequenceLookupRecord],
 }
 
+/// [Chained Sequence Context](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#chained-sequence-context-format-1-simple-glyph-contexts)
+#[format(u16)]
+enum ChainedSequenceContext<'a> {
+    #[version(1)]
+    Format1(ChainedSequenceContextFormat1<'a>),
+    #[version(2)]
+    Format2(ChainedSequenceContextFormat2<'a>),
+    #[version(3)]
+    Format3(ChainedSequenceContextFormat3<'a>),
+}
+
 /// [Device Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#device-and-variationindex-tables)
 Device<'a> {
     /// Smallest size to correct, in ppem
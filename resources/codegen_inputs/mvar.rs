@@ -0,0 +1,32 @@
+/// The [MVAR (Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar) table
+#[offset_host]
+Mvar<'a> {
+    /// Major version number of the metrics variations table — set to 1.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the metrics variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// Not used; set to 0.
+    #[hidden]
+    reserved: BigEndian<u16>,
+    /// The size in bytes of each value record — must be greater than
+    /// zero.
+    value_record_size: BigEndian<u16>,
+    /// The number of value records — may be zero.
+    value_record_count: BigEndian<u16>,
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table. This is zero if there are no value
+    /// records.
+    item_variation_store_offset: BigEndian<Offset16>,
+}
+
+/// [ValueRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar#table-formats) metrics variation record
+ValueRecord {
+    /// Four-byte tag identifying a font-wide measure.
+    value_tag: BigEndian<Tag>,
+    /// A delta-set outer index — used to select an item variation
+    /// data subtable within the item variation store.
+    delta_set_outer_index: BigEndian<u16>,
+    /// A delta-set inner index — used to select a delta-set row
+    /// within an item variation data subtable.
+    delta_set_inner_index: BigEndian<u16>,
+}
@@ -0,0 +1,116 @@
+/// The [OS/2 (OS/2 and Windows Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/os2) table
+///
+/// These are the fields common to all versions; later fields are
+/// accessed by hand.
+#[offset_host]
+Os2<'a> {
+    /// The version of this table.
+    version: BigEndian<u16>,
+    /// The average advance width of all non-zero width glyphs in the
+    /// font.
+    x_avg_char_width: BigEndian<i16>,
+    /// Indicates the visual weight (degree of blackness or thickness
+    /// of strokes) of the characters in the font. Values from 1 to
+    /// 1000 are valid.
+    us_weight_class: BigEndian<u16>,
+    /// Indicates a relative change from the normal aspect ratio
+    /// (width to height ratio) as specified by a font designer for the
+    /// glyphs in a font.
+    us_width_class: BigEndian<u16>,
+    /// Indicates font embedding licensing rights for the font.
+    fs_type: BigEndian<u16>,
+    /// The recommended horizontal size in font design units for
+    /// subscripts for this font.
+    y_subscript_x_size: BigEndian<i16>,
+    /// The recommended vertical size in font design units for
+    /// subscripts for this font.
+    y_subscript_y_size: BigEndian<i16>,
+    /// The recommended horizontal offset in font design units for
+    /// subscripts for this font.
+    y_subscript_x_offset: BigEndian<i16>,
+    /// The recommended vertical offset in font design units for
+    /// subscripts for this font.
+    y_subscript_y_offset: BigEndian<i16>,
+    /// The recommended horizontal size in font design units for
+    /// superscripts for this font.
+    y_superscript_x_size: BigEndian<i16>,
+    /// The recommended vertical size in font design units for
+    /// superscripts for this font.
+    y_superscript_y_size: BigEndian<i16>,
+    /// The recommended horizontal offset in font design units for
+    /// superscripts for this font.
+    y_superscript_x_offset: BigEndian<i16>,
+    /// The recommended vertical offset in font design units for
+    /// superscripts for this font.
+    y_superscript_y_offset: BigEndian<i16>,
+    /// Thickness of the strikeout stroke in font design units.
+    y_strikeout_size: BigEndian<i16>,
+    /// The position of the top of the strikeout stroke relative to the
+    /// baseline in font design units.
+    y_strikeout_position: BigEndian<i16>,
+    /// This parameter is a classification of font-family design.
+    s_family_class: BigEndian<i16>,
+    /// This 10-byte series of numbers is used to describe the visual
+    /// characteristics of a given typeface.
+    #[count(10)]
+    panose_10: [BigEndian<u8>],
+    /// Unicode Character Range (bits 0–31).
+    ul_unicode_range_1: BigEndian<u32>,
+    /// Unicode Character Range (bits 32–63).
+    ul_unicode_range_2: BigEndian<u32>,
+    /// Unicode Character Range (bits 64–95).
+    ul_unicode_range_3: BigEndian<u32>,
+    /// Unicode Character Range (bits 96–127).
+    ul_unicode_range_4: BigEndian<u32>,
+    /// The four-character identifier for the vendor of the given type
+    /// face.
+    ach_vend_id: BigEndian<Tag>,
+    /// Contains information concerning the nature of the font patterns.
+    fs_selection: BigEndian<SelectionFlags>,
+    /// The minimum Unicode index (character code) in this font.
+    us_first_char_index: BigEndian<u16>,
+    /// The maximum Unicode index (character code) in this font.
+    us_last_char_index: BigEndian<u16>,
+    /// The typographic ascender for this font.
+    s_typo_ascender: BigEndian<i16>,
+    /// The typographic descender for this font.
+    s_typo_descender: BigEndian<i16>,
+    /// The typographic line gap for this font.
+    s_typo_line_gap: BigEndian<i16>,
+    /// The “Windows ascender” metric. This should be used to specify
+    /// the height above the baseline for a clipping region.
+    us_win_ascent: BigEndian<u16>,
+    /// The “Windows descender” metric. This should be used to specify
+    /// the vertical extent below the baseline for a clipping region.
+    us_win_descent: BigEndian<u16>,
+}
+
+/// The `fsSelection` field of the [Os2] table.
+#[flags(u16)]
+SelectionFlags {
+    /// Font contains italic or oblique glyphs, otherwise they are
+    /// upright.
+    ITALIC = 0x0001,
+    /// Glyphs are underscored.
+    UNDERSCORE = 0x0002,
+    /// Glyphs have their foreground and background reversed.
+    NEGATIVE = 0x0004,
+    /// Outline (hollow) glyphs, otherwise they are solid.
+    OUTLINED = 0x0008,
+    /// Glyphs are overstruck.
+    STRIKEOUT = 0x0010,
+    /// Glyphs are emboldened.
+    BOLD = 0x0020,
+    /// Glyphs are in the standard weight/style for the font.
+    REGULAR = 0x0040,
+    /// If set, it is strongly recommended that applications use
+    /// OS/2.sTypoAscender - OS/2.sTypoDescender + OS/2.sTypoLineGap as
+    /// the default line spacing for this font.
+    USE_TYPO_METRICS = 0x0080,
+    /// The font has 'name' table strings consistent with a
+    /// weight/width/slope family without requiring use of name IDs 21
+    /// and 22.
+    WWS = 0x0100,
+    /// Font contains oblique glyphs.
+    OBLIQUE = 0x0200,
+}
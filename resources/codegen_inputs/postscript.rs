@@ -0,0 +1,15 @@
+/// A range in a format 3 [FDSelect](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect-format-3)
+FdSelectRange3 {
+    /// First glyph index in range
+    first: BigEndian<u16>,
+    /// FD index for all glyphs in range
+    fd: BigEndian<u8>,
+}
+
+/// A range in a format 4 [FDSelect](https://docs.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect-format-4)
+FdSelectRange4 {
+    /// First glyph index in range
+    first: BigEndian<u32>,
+    /// FD index for all glyphs in range
+    fd: BigEndian<u16>,
+}
@@ -0,0 +1,115 @@
+/// [Item Variation Store](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+#[offset_host]
+ItemVariationStore<'a> {
+    /// Format — set to 1
+    format: BigEndian<u16>,
+    /// Offset in bytes from the start of the item variation store to
+    /// the variation region list.
+    variation_region_list_offset: BigEndian<Offset32>,
+    /// The number of item variation data subtables.
+    item_variation_data_count: BigEndian<u16>,
+    /// Offsets in bytes from the start of the item variation store to
+    /// each item variation data subtable.
+    #[count(item_variation_data_count)]
+    item_variation_data_offsets: [BigEndian<Offset32>],
+}
+
+/// [Variation Region List](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions)
+VariationRegionList<'a> {
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    axis_count: BigEndian<u16>,
+    /// The number of variation region tables in the variation region
+    /// list. Must be less than 32,768.
+    region_count: BigEndian<u16>,
+    /// Array of variation regions, each with one RegionAxisCoordinates
+    /// record per axis.
+    #[count_with(regions_len, axis_count, region_count)]
+    variation_regions: [RegionAxisCoordinates],
+}
+
+/// Part of a [VariationRegionList]
+RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    start_coord: BigEndian<F2Dot14>,
+    /// The region peak coordinate value for the current axis.
+    peak_coord: BigEndian<F2Dot14>,
+    /// The region end coordinate value for the current axis.
+    end_coord: BigEndian<F2Dot14>,
+}
+
+/// [Item Variation Data](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+ItemVariationData<'a> {
+    /// The number of delta sets for distinct items.
+    item_count: BigEndian<u16>,
+    /// A packed field: the high bit is a flag; see
+    /// [`DeltaSetFormat`] for details.
+    word_delta_count: BigEndian<DeltaSetFormat>,
+    /// The number of variation regions referenced.
+    region_index_count: BigEndian<u16>,
+    /// Array of indices into the variation region list for the regions
+    /// referenced by this item variation data table.
+    #[count(region_index_count)]
+    region_indexes: [BigEndian<u16>],
+    /// Delta-set rows.
+    #[count_with(delta_sets_len, item_count, word_delta_count, region_index_count)]
+    delta_sets: [u8],
+}
+
+/// The `wordDeltaCount` field of an [ItemVariationData] table.
+#[flags(u16)]
+DeltaSetFormat {
+    /// Flag indicating that “word” deltas are long (int32)
+    LONG_WORDS = 0x8000,
+    /// Count of “word” deltas
+    WORD_DELTA_COUNT_MASK = 0x7FFF,
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data)
+#[format(u8)]
+enum DeltaSetIndexMap<'a> {
+    #[version(0)]
+    Format0(DeltaSetIndexMapFormat0<'a>),
+    #[version(1)]
+    Format1(DeltaSetIndexMapFormat1<'a>),
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) format 0
+DeltaSetIndexMapFormat0<'a> {
+    /// DeltaSetIndexMap format: set to 0.
+    format: BigEndian<u8>,
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    entry_format: BigEndian<u8>,
+    /// The number of mapping entries.
+    map_count: BigEndian<u16>,
+    /// The delta-set index mapping data.
+    #[count_with(map_data_len, entry_format, map_count)]
+    map_data: [u8],
+}
+
+/// [DeltaSetIndexMap](https://docs.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) format 1
+DeltaSetIndexMapFormat1<'a> {
+    /// DeltaSetIndexMap format: set to 1.
+    format: BigEndian<u8>,
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    entry_format: BigEndian<u8>,
+    /// The number of mapping entries.
+    map_count: BigEndian<u32>,
+    /// The delta-set index mapping data.
+    #[count_with(map_data_len, entry_format, map_count)]
+    map_data: [u8],
+}
+
+fn regions_len(axis_count: u16, region_count: u16) -> usize {
+    axis_count as usize * region_count as usize
+}
+
+fn delta_sets_len(item_count: u16, format: DeltaSetFormat, region_index_count: u16) -> usize {
+    item_count as usize * format.row_byte_len(region_index_count)
+}
+
+fn map_data_len(entry_format: u8, map_count: impl Into<u32>) -> usize {
+    crate::variations::map_entry_size(entry_format) * map_count.into() as usize
+}
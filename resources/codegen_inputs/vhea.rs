@@ -0,0 +1,50 @@
+/// [vhea](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea) Vertical Header Table
+Vhea {
+    /// Version number of the vertical header table; 0x00010000 or
+    /// 0x00011000.
+    version: BigEndian<Version16Dot16>,
+    /// Typographic ascent: the distance from the vertical centerline to
+    /// the previous line's descent.
+    ascender: BigEndian<FWord>,
+    /// Typographic descent: the distance from the vertical centerline to
+    /// the next line's ascent.
+    descender: BigEndian<FWord>,
+    /// Typographic line gap. Set to 0 in version 1.1.
+    line_gap: BigEndian<FWord>,
+    /// Maximum advance height value in 'vmtx' table.
+    advance_height_max: BigEndian<UfWord>,
+    /// Minimum top sidebearing value in 'vmtx' table for glyphs with
+    /// contours (empty glyphs should be ignored).
+    min_top_side_bearing: BigEndian<FWord>,
+    /// Minimum bottom sidebearing value; calculated as min(ah - (tsb +
+    /// yMax - yMin)) for glyphs with contours (empty glyphs should be
+    /// ignored).
+    min_bottom_side_bearing: BigEndian<FWord>,
+    /// Max(tsb + (yMax - yMin)).
+    y_max_extent: BigEndian<FWord>,
+    /// Used to calculate the slope of the cursor (rise/run); 0 for
+    /// vertical.
+    caret_slope_rise: BigEndian<i16>,
+    /// 1 for vertical.
+    caret_slope_run: BigEndian<i16>,
+    /// The amount by which a slanted highlight on a glyph needs to be
+    /// shifted to produce the best appearance. Set to 0 for
+    /// non-slanted fonts
+    caret_offset: BigEndian<i16>,
+    /// set to 0
+    #[hidden]
+    reserved1: BigEndian<i16>,
+    /// set to 0
+    #[hidden]
+    reserved2: BigEndian<i16>,
+    /// set to 0
+    #[hidden]
+    reserved3: BigEndian<i16>,
+    /// set to 0
+    #[hidden]
+    reserved4: BigEndian<i16>,
+    /// 0 for current format.
+    metric_data_format: BigEndian<i16>,
+    /// Number of advance heights in the 'vmtx' table
+    number_of_long_ver_metrics: BigEndian<u16>,
+}
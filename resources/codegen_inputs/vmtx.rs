@@ -0,0 +1,23 @@
+/// The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+#[init(number_of_long_ver_metrics, num_glyphs)]
+Vmtx<'a> {
+    /// Paired advance height and top side bearing values for each
+    /// glyph. Records are indexed by glyph ID.
+    #[count(number_of_long_ver_metrics)]
+    v_metrics: [longVerMetric],
+    /// Top side bearings for glyph IDs greater than or equal to
+    /// numOfLongVerMetrics.
+    #[count_with(n_glyphs_less_n_metrics, num_glyphs, number_of_long_ver_metrics)]
+    top_side_bearings: [BigEndian<i16>],
+}
+
+longVerMetric {
+    /// Advance height, in font design units.
+    advance_height: BigEndian<u16>,
+    /// Glyph top side bearing, in font design units.
+    tsb: BigEndian<i16>,
+}
+
+fn n_glyphs_less_n_metrics(num_glyphs: usize, num_metrics: usize) -> usize {
+    num_glyphs.saturating_sub(num_metrics)
+}
@@ -0,0 +1,24 @@
+/// The [VVAR (Vertical Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
+#[offset_host]
+Vvar<'a> {
+    /// Major version number of the vertical metrics variations table — set to 1.
+    major_version: BigEndian<u16>,
+    /// Minor version number of the vertical metrics variations table — set to 0.
+    minor_version: BigEndian<u16>,
+    /// Offset in bytes from the start of this table to the item
+    /// variation store table.
+    item_variation_store_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for advance heights (may be NULL).
+    advance_height_mapping_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for top side bearings (may be NULL).
+    tsb_mapping_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for bottom side bearings (may be NULL).
+    bsb_mapping_offset: BigEndian<Offset32>,
+    /// Offset in bytes from the start of this table to the delta-set
+    /// index mapping for Y coordinates of vertical origins (may be
+    /// NULL).
+    v_org_mapping_offset: BigEndian<Offset32>,
+}